@@ -43,6 +43,7 @@ pub struct Model {
     pub verified_by: Option<i64>,
     pub created_at: Option<DateTimeWithTimeZone>,
     pub updated_at: Option<DateTimeWithTimeZone>,
+    pub customer_id: Option<i64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -83,6 +84,14 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Branches,
+    #[sea_orm(
+        belongs_to = "super::customers::Entity",
+        from = "Column::CustomerId",
+        to = "super::customers::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Customers,
     #[sea_orm(
         belongs_to = "super::institutions::Entity",
         from = "Column::InstitutionId",
@@ -145,6 +154,12 @@ impl Related<super::branches::Entity> for Entity {
     }
 }
 
+impl Related<super::customers::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Customers.def()
+    }
+}
+
 impl Related<super::institutions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Institutions.def()
@@ -30,6 +30,9 @@ pub struct Model {
     pub deleted_at: Option<DateTimeWithTimeZone>,
     pub created_at: Option<DateTimeWithTimeZone>,
     pub updated_at: Option<DateTimeWithTimeZone>,
+    pub agent_geofence_action: Option<String>,
+    pub agent_commission_expense_gl_account_id: Option<i64>,
+    pub agent_settlement_gl_account_id: Option<i64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -144,6 +147,8 @@ pub enum Relation {
     LedgerLockPeriods,
     #[sea_orm(has_many = "super::loan_applications::Entity")]
     LoanApplications,
+    #[sea_orm(has_many = "super::loan_classification_rules::Entity")]
+    LoanClassificationRules,
     #[sea_orm(has_many = "super::loan_product_types::Entity")]
     LoanProductTypes,
     #[sea_orm(has_many = "super::loan_products::Entity")]
@@ -528,6 +533,12 @@ impl Related<super::loan_applications::Entity> for Entity {
     }
 }
 
+impl Related<super::loan_classification_rules::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LoanClassificationRules.def()
+    }
+}
+
 impl Related<super::loan_product_types::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::LoanProductTypes.def()
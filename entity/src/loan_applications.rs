@@ -51,6 +51,9 @@ pub struct Model {
     pub rejected_by: Option<i64>,
     pub created_at: Option<DateTimeWithTimeZone>,
     pub updated_at: Option<DateTimeWithTimeZone>,
+    pub refinanced_loan_id: Option<i64>,
+    pub branch_id: Option<i64>,
+    pub assigned_at: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -66,6 +66,12 @@ pub struct Model {
     pub created_by: Option<i64>,
     pub created_at: Option<DateTimeWithTimeZone>,
     pub updated_at: Option<DateTimeWithTimeZone>,
+    pub recovery_gl_account_id: Option<i64>,
+    #[sea_orm(column_type = "Decimal(Some((10, 6)))", nullable)]
+    pub early_settlement_fee_rate: Option<Decimal>,
+    pub early_settlement_fee_flat: Option<i64>,
+    pub fee_income_gl_account_id: Option<i64>,
+    pub interest_receivable_gl_account_id: Option<i64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
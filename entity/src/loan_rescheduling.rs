@@ -24,13 +24,16 @@ pub struct Model {
     pub approved_by: Option<i64>,
     pub created_at: Option<DateTimeWithTimeZone>,
     pub updated_at: Option<DateTimeWithTimeZone>,
+    pub new_tenure_days: Option<i32>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub review_notes: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(
         belongs_to = "super::loans::Entity",
-        from = "Column::Id",
+        from = "Column::LoanId",
         to = "super::loans::Column::Id",
         on_update = "NoAction",
         on_delete = "Cascade"
@@ -19,10 +19,13 @@ pub struct Model {
     pub written_off_by: Option<i64>,
     pub created_at: Option<DateTimeWithTimeZone>,
     pub updated_at: Option<DateTimeWithTimeZone>,
+    pub recovered_amount: Option<i64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::loan_write_off_recoveries::Entity")]
+    LoanWriteOffRecoveries,
     #[sea_orm(
         belongs_to = "super::loans::Entity",
         from = "Column::LoanId",
@@ -41,6 +44,12 @@ pub enum Relation {
     Staff,
 }
 
+impl Related<super::loan_write_off_recoveries::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LoanWriteOffRecoveries.def()
+    }
+}
+
 impl Related<super::loans::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Loans.def()
@@ -1,6 +1,7 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
 use super::sea_orm_active_enums::LoanApplicationStatus;
+use super::sea_orm_active_enums::LoanClassification;
 use super::sea_orm_active_enums::LoanRepaymentFreq;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -46,6 +47,8 @@ pub struct Model {
     pub created_by: Option<i64>,
     pub created_at: Option<DateTimeWithTimeZone>,
     pub updated_at: Option<DateTimeWithTimeZone>,
+    pub classification: Option<LoanClassification>,
+    pub refinanced_loan_id: Option<i64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -86,6 +89,8 @@ pub enum Relation {
     LoanCollaterals,
     #[sea_orm(has_many = "super::loan_guarantors::Entity")]
     LoanGuarantors,
+    #[sea_orm(has_many = "super::loan_payoff_quotes::Entity")]
+    LoanPayoffQuotes,
     #[sea_orm(has_many = "super::loan_penalties::Entity")]
     LoanPenalties,
     #[sea_orm(
@@ -102,8 +107,10 @@ pub enum Relation {
     LoanRepaymentSchedules,
     #[sea_orm(has_many = "super::loan_repayments::Entity")]
     LoanRepayments,
-    #[sea_orm(has_one = "super::loan_rescheduling::Entity")]
+    #[sea_orm(has_many = "super::loan_rescheduling::Entity")]
     LoanRescheduling,
+    #[sea_orm(has_many = "super::loan_write_off_recoveries::Entity")]
+    LoanWriteOffRecoveries,
     #[sea_orm(has_many = "super::loan_write_offs::Entity")]
     LoanWriteOffs,
     #[sea_orm(
@@ -152,6 +159,12 @@ impl Related<super::loan_guarantors::Entity> for Entity {
     }
 }
 
+impl Related<super::loan_payoff_quotes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LoanPayoffQuotes.def()
+    }
+}
+
 impl Related<super::loan_penalties::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::LoanPenalties.def()
@@ -188,6 +201,12 @@ impl Related<super::loan_rescheduling::Entity> for Entity {
     }
 }
 
+impl Related<super::loan_write_off_recoveries::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LoanWriteOffRecoveries.def()
+    }
+}
+
 impl Related<super::loan_write_offs::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::LoanWriteOffs.def()
@@ -66,18 +66,25 @@ pub mod integration_providers;
 pub mod integration_webhooks;
 pub mod kyc_provider_checks;
 pub mod ledger_lock_periods;
+pub mod loan_application_approvals;
+pub mod loan_application_documents;
 pub mod loan_application_status_history;
 pub mod loan_applications;
+pub mod loan_approval_levels;
+pub mod loan_classification_rules;
 pub mod loan_collateral_valuations;
 pub mod loan_collaterals;
 pub mod loan_guarantors;
+pub mod loan_payoff_quotes;
 pub mod loan_penalties;
+pub mod loan_product_documents;
 pub mod loan_product_types;
 pub mod loan_products;
 pub mod loan_provisioning;
 pub mod loan_repayment_schedules;
 pub mod loan_repayments;
 pub mod loan_rescheduling;
+pub mod loan_write_off_recoveries;
 pub mod loan_write_offs;
 pub mod loans;
 pub mod maker_checker_workflows;
@@ -91,6 +98,7 @@ pub mod regulatory_reporting_exports;
 pub mod regulatory_reports;
 pub mod report_analytics_cache;
 pub mod report_schedules;
+pub mod saving_goal_withdrawals;
 pub mod saving_goals;
 pub mod savings_products;
 pub mod sea_orm_active_enums;
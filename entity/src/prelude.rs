@@ -64,18 +64,25 @@ pub use super::integration_providers::Entity as IntegrationProviders;
 pub use super::integration_webhooks::Entity as IntegrationWebhooks;
 pub use super::kyc_provider_checks::Entity as KycProviderChecks;
 pub use super::ledger_lock_periods::Entity as LedgerLockPeriods;
+pub use super::loan_application_approvals::Entity as LoanApplicationApprovals;
+pub use super::loan_application_documents::Entity as LoanApplicationDocuments;
 pub use super::loan_application_status_history::Entity as LoanApplicationStatusHistory;
 pub use super::loan_applications::Entity as LoanApplications;
+pub use super::loan_approval_levels::Entity as LoanApprovalLevels;
+pub use super::loan_classification_rules::Entity as LoanClassificationRules;
 pub use super::loan_collateral_valuations::Entity as LoanCollateralValuations;
 pub use super::loan_collaterals::Entity as LoanCollaterals;
 pub use super::loan_guarantors::Entity as LoanGuarantors;
+pub use super::loan_payoff_quotes::Entity as LoanPayoffQuotes;
 pub use super::loan_penalties::Entity as LoanPenalties;
+pub use super::loan_product_documents::Entity as LoanProductDocuments;
 pub use super::loan_product_types::Entity as LoanProductTypes;
 pub use super::loan_products::Entity as LoanProducts;
 pub use super::loan_provisioning::Entity as LoanProvisioning;
 pub use super::loan_repayment_schedules::Entity as LoanRepaymentSchedules;
 pub use super::loan_repayments::Entity as LoanRepayments;
 pub use super::loan_rescheduling::Entity as LoanRescheduling;
+pub use super::loan_write_off_recoveries::Entity as LoanWriteOffRecoveries;
 pub use super::loan_write_offs::Entity as LoanWriteOffs;
 pub use super::loans::Entity as Loans;
 pub use super::maker_checker_workflows::Entity as MakerCheckerWorkflows;
@@ -89,6 +96,7 @@ pub use super::regulatory_reporting_exports::Entity as RegulatoryReportingExport
 pub use super::regulatory_reports::Entity as RegulatoryReports;
 pub use super::report_analytics_cache::Entity as ReportAnalyticsCache;
 pub use super::report_schedules::Entity as ReportSchedules;
+pub use super::saving_goal_withdrawals::Entity as SavingGoalWithdrawals;
 pub use super::saving_goals::Entity as SavingGoals;
 pub use super::savings_products::Entity as SavingsProducts;
 pub use super::sla_configurations::Entity as SlaConfigurations;
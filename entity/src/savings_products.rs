@@ -47,6 +47,8 @@ pub struct Model {
     pub gl_account_id: Option<i64>,
     pub created_at: Option<DateTimeWithTimeZone>,
     pub updated_at: Option<DateTimeWithTimeZone>,
+    pub penalty_income_gl_account_id: Option<i64>,
+    pub deposit_gl_account_id: Option<i64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -978,6 +978,24 @@ pub enum LoanApplicationStatus {
     Refinanced,
 }
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "loan_classification"
+)]
+pub enum LoanClassification {
+    #[sea_orm(string_value = "CURRENT")]
+    Current,
+    #[sea_orm(string_value = "WATCH")]
+    Watch,
+    #[sea_orm(string_value = "SUBSTANDARD")]
+    Substandard,
+    #[sea_orm(string_value = "DOUBTFUL")]
+    Doubtful,
+    #[sea_orm(string_value = "LOSS")]
+    Loss,
+}
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
 #[sea_orm(
     rs_type = "String",
     db_type = "Enum",
@@ -1010,6 +1028,8 @@ pub enum LoanPenaltyStatus {
     Paid,
     #[sea_orm(string_value = "PROCESSING")]
     Processing,
+    #[sea_orm(string_value = "WAIVED")]
+    Waived,
 }
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "loan_penalty_type")]
@@ -1104,6 +1124,8 @@ pub enum LoanRepaymentScheduleStatus {
     Paid,
     #[sea_orm(string_value = "OVERDUE")]
     Overdue,
+    #[sea_orm(string_value = "SUPERSEDED")]
+    Superseded,
 }
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
 #[sea_orm(
@@ -1136,6 +1158,8 @@ pub enum LoanReschedulingStatus {
     Ongoing,
     #[sea_orm(string_value = "COMPLETED")]
     Completed,
+    #[sea_orm(string_value = "REJECTED")]
+    Rejected,
 }
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "loan_risk_rating")]
@@ -1192,6 +1216,8 @@ pub enum MakerCheckerReferenceType {
     LoanApproval,
     #[sea_orm(string_value = "PRODUCT_CHANGE")]
     ProductChange,
+    #[sea_orm(string_value = "LOAN_WRITE_OFF")]
+    LoanWriteOff,
 }
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
 #[sea_orm(
@@ -120,6 +120,8 @@ pub enum Relation {
     IntegrationWebhooks,
     #[sea_orm(has_many = "super::loan_application_status_history::Entity")]
     LoanApplicationStatusHistory,
+    #[sea_orm(has_many = "super::loan_classification_rules::Entity")]
+    LoanClassificationRules,
     #[sea_orm(has_many = "super::loan_collaterals::Entity")]
     LoanCollaterals,
     #[sea_orm(has_many = "super::loan_penalties::Entity")]
@@ -130,6 +132,8 @@ pub enum Relation {
     LoanProvisioning,
     #[sea_orm(has_many = "super::loan_rescheduling::Entity")]
     LoanRescheduling,
+    #[sea_orm(has_many = "super::loan_write_off_recoveries::Entity")]
+    LoanWriteOffRecoveries,
     #[sea_orm(has_many = "super::loan_write_offs::Entity")]
     LoanWriteOffs,
     #[sea_orm(has_many = "super::loans::Entity")]
@@ -328,6 +332,12 @@ impl Related<super::loan_application_status_history::Entity> for Entity {
     }
 }
 
+impl Related<super::loan_classification_rules::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LoanClassificationRules.def()
+    }
+}
+
 impl Related<super::loan_collaterals::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::LoanCollaterals.def()
@@ -358,6 +368,12 @@ impl Related<super::loan_rescheduling::Entity> for Entity {
     }
 }
 
+impl Related<super::loan_write_off_recoveries::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LoanWriteOffRecoveries.def()
+    }
+}
+
 impl Related<super::loan_write_offs::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::LoanWriteOffs.def()
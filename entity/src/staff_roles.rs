@@ -21,6 +21,8 @@ pub struct Model {
     pub created_at: Option<DateTimeWithTimeZone>,
     pub updated_at: Option<DateTimeWithTimeZone>,
     pub created_by: Option<i64>,
+    pub loan_approval_limit: Option<i64>,
+    pub has_unlimited_loan_approval: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -113,6 +113,23 @@ mod m20251212_210040_create_health_n_maintenance;
 mod m20251212_210457_create_data_backups;
 mod m20251212_210901_create_regulatory_reporting_exports;
 mod m20260304_182449_create_currencies;
+mod m20261019_090000_create_loan_classification_rules;
+mod m20261019_100000_alter_loan_rescheduling;
+mod m20261019_110000_create_loan_write_off_recoveries;
+mod m20261019_120000_create_loan_payoff_quotes;
+mod m20261019_130000_alter_loan_products_interest_receivable;
+mod m20261019_140000_alter_loans_refinancing;
+mod m20261019_150000_create_loan_approval_authority;
+mod m20261019_160000_create_loan_documents;
+mod m20261019_170000_alter_loan_applications_assignment;
+mod m20261019_180000_create_saving_goal_withdrawals;
+mod m20261019_190000_alter_saving_goal_withdrawals_member;
+mod m20261019_200000_alter_agents_customer;
+mod m20261019_210000_alter_institutions_agent_geofence;
+mod m20261019_220000_alter_loan_penalty_status_waived;
+mod m20261019_230000_alter_savings_products_penalty_gl;
+mod m20261019_240000_alter_savings_products_deposit_gl;
+mod m20261019_250000_alter_institutions_agent_settlement_gl;
 
 pub struct Migrator;
 
@@ -233,6 +250,23 @@ impl MigratorTrait for Migrator {
             Box::new(m20251212_210457_create_data_backups::Migration),
             Box::new(m20251212_210901_create_regulatory_reporting_exports::Migration),
             Box::new(m20260304_182449_create_currencies::Migration),
+            Box::new(m20261019_090000_create_loan_classification_rules::Migration),
+            Box::new(m20261019_100000_alter_loan_rescheduling::Migration),
+            Box::new(m20261019_110000_create_loan_write_off_recoveries::Migration),
+            Box::new(m20261019_120000_create_loan_payoff_quotes::Migration),
+            Box::new(m20261019_130000_alter_loan_products_interest_receivable::Migration),
+            Box::new(m20261019_140000_alter_loans_refinancing::Migration),
+            Box::new(m20261019_150000_create_loan_approval_authority::Migration),
+            Box::new(m20261019_160000_create_loan_documents::Migration),
+            Box::new(m20261019_170000_alter_loan_applications_assignment::Migration),
+            Box::new(m20261019_180000_create_saving_goal_withdrawals::Migration),
+            Box::new(m20261019_190000_alter_saving_goal_withdrawals_member::Migration),
+            Box::new(m20261019_200000_alter_agents_customer::Migration),
+            Box::new(m20261019_210000_alter_institutions_agent_geofence::Migration),
+            Box::new(m20261019_220000_alter_loan_penalty_status_waived::Migration),
+            Box::new(m20261019_230000_alter_savings_products_penalty_gl::Migration),
+            Box::new(m20261019_240000_alter_savings_products_deposit_gl::Migration),
+            Box::new(m20261019_250000_alter_institutions_agent_settlement_gl::Migration),
         ]
     }
 }
@@ -0,0 +1,183 @@
+use sea_orm_migration::{prelude::*, sea_orm::Statement};
+
+use crate::{
+    m20251204_112805_create_institutions::Institutions, m20251204_150208_create_branches::Staff,
+    m20251204_151411_create_chart_of_accounts::ChartOfAccounts,
+    m20251206_150936_create_loans::Loans,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute(Statement::from_string(
+                manager.get_database_backend(),
+                "CREATE TYPE loan_classification AS ENUM ('CURRENT', 'WATCH', 'SUBSTANDARD', 'DOUBTFUL', 'LOSS')"
+                    .to_string(),
+            ))
+            .await?;
+
+        let rules = Table::create()
+            .table(LoanClassificationRules::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(LoanClassificationRules::Id)
+                    .big_integer()
+                    .not_null()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(LoanClassificationRules::InstitutionId)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanClassificationRules::Classification)
+                    .custom("loan_classification")
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanClassificationRules::MinDaysInArrears)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(LoanClassificationRules::ProvisionRate)
+                    .decimal_len(10, 6)
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(LoanClassificationRules::IsNpa)
+                    .boolean()
+                    .not_null()
+                    .default(false),
+            )
+            .col(ColumnDef::new(LoanClassificationRules::ProvisionExpenseGlAccountId).big_integer())
+            .col(ColumnDef::new(LoanClassificationRules::ProvisionReserveGlAccountId).big_integer())
+            .col(
+                ColumnDef::new(LoanClassificationRules::IsActive)
+                    .boolean()
+                    .default(true),
+            )
+            .col(ColumnDef::new(LoanClassificationRules::CreatedBy).big_integer())
+            .col(
+                ColumnDef::new(LoanClassificationRules::CreatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(LoanClassificationRules::UpdatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanClassificationRules::Table,
+                        LoanClassificationRules::InstitutionId,
+                    )
+                    .to(Institutions::Table, Institutions::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanClassificationRules::Table,
+                        LoanClassificationRules::ProvisionExpenseGlAccountId,
+                    )
+                    .to(ChartOfAccounts::Table, ChartOfAccounts::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanClassificationRules::Table,
+                        LoanClassificationRules::ProvisionReserveGlAccountId,
+                    )
+                    .to(ChartOfAccounts::Table, ChartOfAccounts::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanClassificationRules::Table,
+                        LoanClassificationRules::CreatedBy,
+                    )
+                    .to(Staff::Table, Staff::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .index(
+                Index::create()
+                    .name("idx_loan_classification_institution")
+                    .table(LoanClassificationRules::Table)
+                    .col(LoanClassificationRules::InstitutionId)
+                    .col(LoanClassificationRules::Classification)
+                    .unique(),
+            )
+            .to_owned();
+
+        manager.create_table(rules).await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Loans::Table)
+                    .add_column(
+                        ColumnDef::new(Alias::new("classification")).custom("loan_classification"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Loans::Table)
+                    .drop_column(Alias::new("classification"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(LoanClassificationRules::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("DROP TYPE IF EXISTS loan_classification")
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum LoanClassificationRules {
+    Table,
+    Id,
+    InstitutionId,
+    Classification,
+    MinDaysInArrears,
+    ProvisionRate,
+    IsNpa,
+    ProvisionExpenseGlAccountId,
+    ProvisionReserveGlAccountId,
+    IsActive,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
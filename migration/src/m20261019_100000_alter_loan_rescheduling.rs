@@ -0,0 +1,71 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20251206_150936_create_loans::Loans,
+    m20251206_175121_create_loan_rescheduling::LoanRescheduling,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TYPE loan_repayment_schedule_status ADD VALUE IF NOT EXISTS 'SUPERSEDED'",
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TYPE loan_rescheduling_status ADD VALUE IF NOT EXISTS 'REJECTED'",
+            )
+            .await?;
+
+        // The original key tied the rescheduling id to the loan id, allowing
+        // a single request per loan with a borrowed primary key
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE loan_rescheduling DROP CONSTRAINT IF EXISTS loan_rescheduling_id_fkey",
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanRescheduling::Table)
+                    .add_column(ColumnDef::new(Alias::new("new_tenure_days")).integer())
+                    .add_column(ColumnDef::new(Alias::new("review_notes")).text())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .from_tbl(LoanRescheduling::Table)
+                            .from_col(LoanRescheduling::LoanId)
+                            .to_tbl(Loans::Table)
+                            .to_col(Loans::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanRescheduling::Table)
+                    .drop_column(Alias::new("new_tenure_days"))
+                    .drop_column(Alias::new("review_notes"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20251204_150208_create_branches::Staff,
+    m20251204_151411_create_chart_of_accounts::ChartOfAccounts,
+    m20251205_210647_create_loan_products::LoanProducts, m20251206_150936_create_loans::Loans,
+    m20251206_191556_create_loan_write_offs::LoanWriteOffs,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TYPE maker_checker_reference_type ADD VALUE IF NOT EXISTS 'LOAN_WRITE_OFF'",
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanProducts::Table)
+                    .add_column(ColumnDef::new(Alias::new("recovery_gl_account_id")).big_integer())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .from_tbl(LoanProducts::Table)
+                            .from_col(Alias::new("recovery_gl_account_id"))
+                            .to_tbl(ChartOfAccounts::Table)
+                            .to_col(ChartOfAccounts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanWriteOffs::Table)
+                    .add_column(
+                        ColumnDef::new(Alias::new("recovered_amount"))
+                            .big_integer()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let recoveries = Table::create()
+            .table(LoanWriteOffRecoveries::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(LoanWriteOffRecoveries::Id)
+                    .big_integer()
+                    .not_null()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(LoanWriteOffRecoveries::LoanWriteOffId)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanWriteOffRecoveries::LoanId)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanWriteOffRecoveries::Amount)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanWriteOffRecoveries::DebitGlAccountId)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanWriteOffRecoveries::CreditGlAccountId)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(LoanWriteOffRecoveries::ReferenceNumber).string())
+            .col(ColumnDef::new(LoanWriteOffRecoveries::Notes).text())
+            .col(
+                ColumnDef::new(LoanWriteOffRecoveries::RecoveredAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(ColumnDef::new(LoanWriteOffRecoveries::RecordedBy).big_integer())
+            .col(
+                ColumnDef::new(LoanWriteOffRecoveries::CreatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(LoanWriteOffRecoveries::UpdatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanWriteOffRecoveries::Table,
+                        LoanWriteOffRecoveries::LoanWriteOffId,
+                    )
+                    .to(LoanWriteOffs::Table, LoanWriteOffs::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanWriteOffRecoveries::Table,
+                        LoanWriteOffRecoveries::LoanId,
+                    )
+                    .to(Loans::Table, Loans::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanWriteOffRecoveries::Table,
+                        LoanWriteOffRecoveries::DebitGlAccountId,
+                    )
+                    .to(ChartOfAccounts::Table, ChartOfAccounts::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanWriteOffRecoveries::Table,
+                        LoanWriteOffRecoveries::CreditGlAccountId,
+                    )
+                    .to(ChartOfAccounts::Table, ChartOfAccounts::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanWriteOffRecoveries::Table,
+                        LoanWriteOffRecoveries::RecordedBy,
+                    )
+                    .to(Staff::Table, Staff::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+
+        manager.create_table(recoveries).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(LoanWriteOffRecoveries::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanWriteOffs::Table)
+                    .drop_column(Alias::new("recovered_amount"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanProducts::Table)
+                    .drop_column(Alias::new("recovery_gl_account_id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum LoanWriteOffRecoveries {
+    Table,
+    Id,
+    LoanWriteOffId,
+    LoanId,
+    Amount,
+    DebitGlAccountId,
+    CreditGlAccountId,
+    ReferenceNumber,
+    Notes,
+    RecoveredAt,
+    RecordedBy,
+    CreatedAt,
+    UpdatedAt,
+}
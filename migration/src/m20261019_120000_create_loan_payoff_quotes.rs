@@ -0,0 +1,195 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20251204_112805_create_institutions::Institutions, m20251204_150208_create_branches::Staff,
+    m20251204_151411_create_chart_of_accounts::ChartOfAccounts,
+    m20251205_210647_create_loan_products::LoanProducts, m20251206_150936_create_loans::Loans,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanProducts::Table)
+                    .add_column(
+                        ColumnDef::new(Alias::new("early_settlement_fee_rate")).decimal_len(10, 6),
+                    )
+                    .add_column(
+                        ColumnDef::new(Alias::new("early_settlement_fee_flat")).big_integer(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Alias::new("fee_income_gl_account_id")).big_integer(),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .from_tbl(LoanProducts::Table)
+                            .from_col(Alias::new("fee_income_gl_account_id"))
+                            .to_tbl(ChartOfAccounts::Table)
+                            .to_col(ChartOfAccounts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let quotes = Table::create()
+            .table(LoanPayoffQuotes::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(LoanPayoffQuotes::Id)
+                    .big_integer()
+                    .not_null()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(LoanPayoffQuotes::InstitutionId)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanPayoffQuotes::LoanId)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanPayoffQuotes::QuoteNumber)
+                    .string()
+                    .not_null()
+                    .unique_key(),
+            )
+            .col(ColumnDef::new(LoanPayoffQuotes::AsOfDate).date().not_null())
+            .col(
+                ColumnDef::new(LoanPayoffQuotes::ExpiresOn)
+                    .date()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanPayoffQuotes::OutstandingPrincipal)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanPayoffQuotes::AccruedInterest)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanPayoffQuotes::OutstandingPenalty)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanPayoffQuotes::SettlementFee)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanPayoffQuotes::TotalAmount)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanPayoffQuotes::Status)
+                    .string()
+                    .default("ACTIVE"),
+            )
+            .col(ColumnDef::new(LoanPayoffQuotes::DebitGlAccountId).big_integer())
+            .col(ColumnDef::new(LoanPayoffQuotes::SettlementReference).string())
+            .col(ColumnDef::new(LoanPayoffQuotes::SettledAt).timestamp_with_time_zone())
+            .col(ColumnDef::new(LoanPayoffQuotes::SettledBy).big_integer())
+            .col(ColumnDef::new(LoanPayoffQuotes::CreatedBy).big_integer())
+            .col(
+                ColumnDef::new(LoanPayoffQuotes::CreatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(LoanPayoffQuotes::UpdatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(LoanPayoffQuotes::Table, LoanPayoffQuotes::InstitutionId)
+                    .to(Institutions::Table, Institutions::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(LoanPayoffQuotes::Table, LoanPayoffQuotes::LoanId)
+                    .to(Loans::Table, Loans::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(LoanPayoffQuotes::Table, LoanPayoffQuotes::DebitGlAccountId)
+                    .to(ChartOfAccounts::Table, ChartOfAccounts::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(LoanPayoffQuotes::Table, LoanPayoffQuotes::SettledBy)
+                    .to(Staff::Table, Staff::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(LoanPayoffQuotes::Table, LoanPayoffQuotes::CreatedBy)
+                    .to(Staff::Table, Staff::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+
+        manager.create_table(quotes).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(LoanPayoffQuotes::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanProducts::Table)
+                    .drop_column(Alias::new("early_settlement_fee_rate"))
+                    .drop_column(Alias::new("early_settlement_fee_flat"))
+                    .drop_column(Alias::new("fee_income_gl_account_id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum LoanPayoffQuotes {
+    Table,
+    Id,
+    InstitutionId,
+    LoanId,
+    QuoteNumber,
+    AsOfDate,
+    ExpiresOn,
+    OutstandingPrincipal,
+    AccruedInterest,
+    OutstandingPenalty,
+    SettlementFee,
+    TotalAmount,
+    Status,
+    DebitGlAccountId,
+    SettlementReference,
+    SettledAt,
+    SettledBy,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
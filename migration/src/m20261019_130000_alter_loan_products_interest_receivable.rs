@@ -0,0 +1,49 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20251204_151411_create_chart_of_accounts::ChartOfAccounts,
+    m20251205_210647_create_loan_products::LoanProducts,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanProducts::Table)
+                    .add_column(
+                        ColumnDef::new(Alias::new("interest_receivable_gl_account_id"))
+                            .big_integer(),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .from_tbl(LoanProducts::Table)
+                            .from_col(Alias::new("interest_receivable_gl_account_id"))
+                            .to_tbl(ChartOfAccounts::Table)
+                            .to_col(ChartOfAccounts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanProducts::Table)
+                    .drop_column(Alias::new("interest_receivable_gl_account_id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20251206_143556_create_loan_applications::LoanApplications,
+    m20251206_150936_create_loans::Loans,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanApplications::Table)
+                    .add_column(ColumnDef::new(Alias::new("refinanced_loan_id")).big_integer())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .from_tbl(LoanApplications::Table)
+                            .from_col(Alias::new("refinanced_loan_id"))
+                            .to_tbl(Loans::Table)
+                            .to_col(Loans::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Loans::Table)
+                    .add_column(ColumnDef::new(Alias::new("refinanced_loan_id")).big_integer())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .from_tbl(Loans::Table)
+                            .from_col(Alias::new("refinanced_loan_id"))
+                            .to_tbl(Loans::Table)
+                            .to_col(Loans::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Loans::Table)
+                    .drop_column(Alias::new("refinanced_loan_id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanApplications::Table)
+                    .drop_column(Alias::new("refinanced_loan_id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,248 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20251204_112805_create_institutions::Institutions,
+    m20251204_150208_create_branches::{Staff, StaffRoles},
+    m20251206_143556_create_loan_applications::LoanApplications,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StaffRoles::Table)
+                    .add_column(ColumnDef::new(Alias::new("loan_approval_limit")).big_integer())
+                    .add_column(
+                        ColumnDef::new(Alias::new("has_unlimited_loan_approval"))
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let levels = Table::create()
+            .table(LoanApprovalLevels::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(LoanApprovalLevels::Id)
+                    .big_integer()
+                    .not_null()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(LoanApprovalLevels::InstitutionId)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanApprovalLevels::MinPrincipal)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanApprovalLevels::RequiredApprovals)
+                    .integer()
+                    .not_null()
+                    .default(1),
+            )
+            .col(ColumnDef::new(LoanApprovalLevels::Description).string())
+            .col(
+                ColumnDef::new(LoanApprovalLevels::IsActive)
+                    .boolean()
+                    .default(true),
+            )
+            .col(ColumnDef::new(LoanApprovalLevels::CreatedBy).big_integer())
+            .col(
+                ColumnDef::new(LoanApprovalLevels::CreatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(LoanApprovalLevels::UpdatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(LoanApprovalLevels::Table, LoanApprovalLevels::InstitutionId)
+                    .to(Institutions::Table, Institutions::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(LoanApprovalLevels::Table, LoanApprovalLevels::CreatedBy)
+                    .to(Staff::Table, Staff::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+
+        manager.create_table(levels).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_loan_approval_levels_institution_min_principal")
+                    .table(LoanApprovalLevels::Table)
+                    .col(LoanApprovalLevels::InstitutionId)
+                    .col(LoanApprovalLevels::MinPrincipal)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        let approvals = Table::create()
+            .table(LoanApplicationApprovals::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(LoanApplicationApprovals::Id)
+                    .big_integer()
+                    .not_null()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(LoanApplicationApprovals::LoanApplicationId)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanApplicationApprovals::ApprovedBy)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(LoanApplicationApprovals::RoleId).big_integer())
+            .col(ColumnDef::new(LoanApplicationApprovals::ApprovalLimit).big_integer())
+            .col(
+                ColumnDef::new(LoanApplicationApprovals::ApprovedPrincipal)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanApplicationApprovals::ApprovedTenureDays)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanApplicationApprovals::ApprovedInterestRate)
+                    .decimal_len(10, 6)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanApplicationApprovals::ApprovalLevel)
+                    .integer()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(LoanApplicationApprovals::Conditions).json_binary())
+            .col(
+                ColumnDef::new(LoanApplicationApprovals::CreatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanApplicationApprovals::Table,
+                        LoanApplicationApprovals::LoanApplicationId,
+                    )
+                    .to(LoanApplications::Table, LoanApplications::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanApplicationApprovals::Table,
+                        LoanApplicationApprovals::ApprovedBy,
+                    )
+                    .to(Staff::Table, Staff::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanApplicationApprovals::Table,
+                        LoanApplicationApprovals::RoleId,
+                    )
+                    .to(StaffRoles::Table, StaffRoles::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+
+        manager.create_table(approvals).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_loan_application_approvals_application_approver")
+                    .table(LoanApplicationApprovals::Table)
+                    .col(LoanApplicationApprovals::LoanApplicationId)
+                    .col(LoanApplicationApprovals::ApprovedBy)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(LoanApplicationApprovals::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(LoanApprovalLevels::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StaffRoles::Table)
+                    .drop_column(Alias::new("loan_approval_limit"))
+                    .drop_column(Alias::new("has_unlimited_loan_approval"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum LoanApprovalLevels {
+    Table,
+    Id,
+    InstitutionId,
+    MinPrincipal,
+    RequiredApprovals,
+    Description,
+    IsActive,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum LoanApplicationApprovals {
+    Table,
+    Id,
+    LoanApplicationId,
+    ApprovedBy,
+    RoleId,
+    ApprovalLimit,
+    ApprovedPrincipal,
+    ApprovedTenureDays,
+    ApprovedInterestRate,
+    ApprovalLevel,
+    Conditions,
+    CreatedAt,
+}
@@ -0,0 +1,208 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20251204_150208_create_branches::Staff, m20251205_210647_create_loan_products::LoanProducts,
+    m20251206_143556_create_loan_applications::LoanApplications,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let checklist = Table::create()
+            .table(LoanProductDocuments::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(LoanProductDocuments::Id)
+                    .big_integer()
+                    .not_null()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(LoanProductDocuments::LoanProductId)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanProductDocuments::DocumentCode)
+                    .string()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanProductDocuments::DocumentName)
+                    .string()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(LoanProductDocuments::Description).string())
+            .col(
+                ColumnDef::new(LoanProductDocuments::IsMandatory)
+                    .boolean()
+                    .not_null()
+                    .default(true),
+            )
+            .col(
+                ColumnDef::new(LoanProductDocuments::IsActive)
+                    .boolean()
+                    .default(true),
+            )
+            .col(ColumnDef::new(LoanProductDocuments::CreatedBy).big_integer())
+            .col(
+                ColumnDef::new(LoanProductDocuments::CreatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(LoanProductDocuments::UpdatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanProductDocuments::Table,
+                        LoanProductDocuments::LoanProductId,
+                    )
+                    .to(LoanProducts::Table, LoanProducts::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(LoanProductDocuments::Table, LoanProductDocuments::CreatedBy)
+                    .to(Staff::Table, Staff::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+
+        manager.create_table(checklist).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_loan_product_documents_product_code")
+                    .table(LoanProductDocuments::Table)
+                    .col(LoanProductDocuments::LoanProductId)
+                    .col(LoanProductDocuments::DocumentCode)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        let documents = Table::create()
+            .table(LoanApplicationDocuments::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(LoanApplicationDocuments::Id)
+                    .big_integer()
+                    .not_null()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(LoanApplicationDocuments::LoanApplicationId)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanApplicationDocuments::DocumentCode)
+                    .string()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(LoanApplicationDocuments::DocumentUrl)
+                    .string()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(LoanApplicationDocuments::FileName).string())
+            .col(ColumnDef::new(LoanApplicationDocuments::UploadedBy).big_integer())
+            .col(
+                ColumnDef::new(LoanApplicationDocuments::CreatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(LoanApplicationDocuments::UpdatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanApplicationDocuments::Table,
+                        LoanApplicationDocuments::LoanApplicationId,
+                    )
+                    .to(LoanApplications::Table, LoanApplications::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        LoanApplicationDocuments::Table,
+                        LoanApplicationDocuments::UploadedBy,
+                    )
+                    .to(Staff::Table, Staff::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+
+        manager.create_table(documents).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_loan_application_documents_application_code")
+                    .table(LoanApplicationDocuments::Table)
+                    .col(LoanApplicationDocuments::LoanApplicationId)
+                    .col(LoanApplicationDocuments::DocumentCode)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(LoanApplicationDocuments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(LoanProductDocuments::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum LoanProductDocuments {
+    Table,
+    Id,
+    LoanProductId,
+    DocumentCode,
+    DocumentName,
+    Description,
+    IsMandatory,
+    IsActive,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum LoanApplicationDocuments {
+    Table,
+    Id,
+    LoanApplicationId,
+    DocumentCode,
+    DocumentUrl,
+    FileName,
+    UploadedBy,
+    CreatedAt,
+    UpdatedAt,
+}
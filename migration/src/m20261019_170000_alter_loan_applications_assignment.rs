@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20251204_150208_create_branches::Branches,
+    m20251206_143556_create_loan_applications::LoanApplications,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanApplications::Table)
+                    .add_column(ColumnDef::new(Alias::new("branch_id")).big_integer())
+                    .add_column(
+                        ColumnDef::new(Alias::new("assigned_at")).timestamp_with_time_zone(),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .from_tbl(LoanApplications::Table)
+                            .from_col(Alias::new("branch_id"))
+                            .to_tbl(Branches::Table)
+                            .to_col(Branches::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_loan_applications_assigned_officer")
+                    .table(LoanApplications::Table)
+                    .col(LoanApplications::AssignedOfficer)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_loan_applications_assigned_officer")
+                    .table(LoanApplications::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(LoanApplications::Table)
+                    .drop_column(Alias::new("branch_id"))
+                    .drop_column(Alias::new("assigned_at"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20251204_150208_create_branches::Staff, m20251205_154503_create_accounts::Accounts,
+    m20251207_103023_create_saving_goals::SavingGoals,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let withdrawals = Table::create()
+            .table(SavingGoalWithdrawals::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(SavingGoalWithdrawals::Id)
+                    .big_integer()
+                    .not_null()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(SavingGoalWithdrawals::SavingGoalId)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(SavingGoalWithdrawals::AccountId)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(SavingGoalWithdrawals::Amount)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(SavingGoalWithdrawals::PenaltyAmount)
+                    .big_integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(SavingGoalWithdrawals::NetAmount)
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(SavingGoalWithdrawals::IsEarlyWithdrawal)
+                    .boolean()
+                    .not_null()
+                    .default(false),
+            )
+            .col(
+                ColumnDef::new(SavingGoalWithdrawals::ReferenceNumber)
+                    .string()
+                    .not_null()
+                    .unique_key(),
+            )
+            .col(ColumnDef::new(SavingGoalWithdrawals::WithdrawnBy).big_integer())
+            .col(
+                ColumnDef::new(SavingGoalWithdrawals::CreatedAt)
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        SavingGoalWithdrawals::Table,
+                        SavingGoalWithdrawals::SavingGoalId,
+                    )
+                    .to(SavingGoals::Table, SavingGoals::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(SavingGoalWithdrawals::Table, SavingGoalWithdrawals::AccountId)
+                    .to(Accounts::Table, Accounts::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from(
+                        SavingGoalWithdrawals::Table,
+                        SavingGoalWithdrawals::WithdrawnBy,
+                    )
+                    .to(Staff::Table, Staff::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+
+        manager.create_table(withdrawals).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SavingGoalWithdrawals::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SavingGoalWithdrawals {
+    Table,
+    Id,
+    SavingGoalId,
+    AccountId,
+    Amount,
+    PenaltyAmount,
+    NetAmount,
+    IsEarlyWithdrawal,
+    ReferenceNumber,
+    WithdrawnBy,
+    CreatedAt,
+}
@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{m20251204_152312_create_customers::Customers, m20251208_154224_create_agents::Agents};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Agents::Table)
+                    .add_column(ColumnDef::new(Alias::new("customer_id")).big_integer())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .from_tbl(Agents::Table)
+                            .from_col(Alias::new("customer_id"))
+                            .to_tbl(Customers::Table)
+                            .to_col(Customers::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Agents::Table)
+                    .drop_column(Alias::new("customer_id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20251204_112805_create_institutions::Institutions;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Institutions::Table)
+                    .add_column(
+                        ColumnDef::new(Alias::new("agent_geofence_action"))
+                            .string()
+                            .default("FLAG"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Institutions::Table)
+                    .drop_column(Alias::new("agent_geofence_action"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use sea_orm_migration::{prelude::*, sea_orm::Statement};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute(Statement::from_string(
+                manager.get_database_backend(),
+                "ALTER TYPE loan_penalty_status ADD VALUE IF NOT EXISTS 'WAIVED'".to_string(),
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    // Postgres cannot drop an enum value, so waived penalties fall back to
+    // PAID and the value is left in place
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute(Statement::from_string(
+                manager.get_database_backend(),
+                "UPDATE loan_penalties SET status = 'PAID' WHERE status = 'WAIVED'".to_string(),
+            ))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20251204_151411_create_chart_of_accounts::ChartOfAccounts,
+    m20251206_193123_create_savings_products::SavingsProducts,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SavingsProducts::Table)
+                    .add_column(
+                        ColumnDef::new(Alias::new("penalty_income_gl_account_id")).big_integer(),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .from_tbl(SavingsProducts::Table)
+                            .from_col(Alias::new("penalty_income_gl_account_id"))
+                            .to_tbl(ChartOfAccounts::Table)
+                            .to_col(ChartOfAccounts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SavingsProducts::Table)
+                    .drop_column(Alias::new("penalty_income_gl_account_id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
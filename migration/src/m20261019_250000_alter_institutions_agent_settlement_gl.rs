@@ -0,0 +1,61 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20251204_112805_create_institutions::Institutions,
+    m20251204_151411_create_chart_of_accounts::ChartOfAccounts,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Institutions::Table)
+                    .add_column(
+                        ColumnDef::new(Alias::new("agent_commission_expense_gl_account_id"))
+                            .big_integer(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Alias::new("agent_settlement_gl_account_id")).big_integer(),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .from_tbl(Institutions::Table)
+                            .from_col(Alias::new("agent_commission_expense_gl_account_id"))
+                            .to_tbl(ChartOfAccounts::Table)
+                            .to_col(ChartOfAccounts::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .from_tbl(Institutions::Table)
+                            .from_col(Alias::new("agent_settlement_gl_account_id"))
+                            .to_tbl(ChartOfAccounts::Table)
+                            .to_col(ChartOfAccounts::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Institutions::Table)
+                    .drop_column(Alias::new("agent_commission_expense_gl_account_id"))
+                    .drop_column(Alias::new("agent_settlement_gl_account_id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
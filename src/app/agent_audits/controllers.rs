@@ -0,0 +1,172 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::agent_audits::{
+        models::{
+            CloseFollowUpParams, RecordAuditModel, RecordAuditParams, ScheduleAuditModel,
+            ScheduleAuditParams,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn schedule_audit(
+    req: HttpRequest,
+    payload: web::Json<ScheduleAuditParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "AGENT_AUDIT_SCHEDULE", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let auditor_id = match data.auditor_id {
+        Some(auditor_id) => id_parser(&auditor_id, "Auditor Id").await?,
+        None => staff.id,
+    };
+
+    let audit = ScheduleAuditModel {
+        agent_id: id_parser(&data.agent_id, "Agent Id").await?,
+        audit_date: data.audit_date,
+        auditor_id,
+    };
+
+    match services::schedule_audit(&audit, &state).await {
+        Ok(audit) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Audit Scheduled",
+            audit,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn record_audit(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<RecordAuditParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_AUDIT_RECORD", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Audit Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let audit = RecordAuditModel {
+        actual_float_balance: data.actual_float_balance,
+        findings: data.findings,
+        compliance_score: data.compliance_score,
+        risk_level: data.risk_level,
+        actions_taken: data.actions_taken,
+        is_follow_up_required: data.is_follow_up_required.unwrap_or(false),
+    };
+
+    match services::record_audit(&id, &audit, &state).await {
+        Ok(audit) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Audit Recorded",
+            audit,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn close_follow_up(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<CloseFollowUpParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_AUDIT_CLOSE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Audit Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    match services::close_follow_up(&id, &data.actions_taken, &state).await {
+        Ok(audit) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Audit Follow-up Closed",
+            audit,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn audit_details(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Audit Id").await?;
+
+    match services::get_audit(&id, &state).await {
+        Ok(audit) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            audit,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn agent_audits(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Agent Id").await?;
+
+    match services::get_agent_audits(&id, &state).await {
+        Ok(audits) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            audits,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
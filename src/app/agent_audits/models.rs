@@ -0,0 +1,100 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use entity::sea_orm_active_enums::RiskLevelEnum;
+use sea_orm::FromQueryResult;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[derive(Debug, Clone)]
+pub struct ScheduleAuditModel {
+    pub agent_id: i64,
+    pub audit_date: NaiveDate,
+    pub auditor_id: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordAuditModel {
+    pub actual_float_balance: i64,
+    pub findings: Option<Value>,
+    pub compliance_score: Option<Decimal>,
+    pub risk_level: RiskLevelEnum,
+    pub actions_taken: Option<String>,
+    pub is_follow_up_required: bool,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ScheduleAuditParams {
+    #[serde(rename = "agentId")]
+    pub agent_id: String,
+    #[serde(rename = "auditDate")]
+    pub audit_date: NaiveDate,
+    #[serde(rename = "auditorId")]
+    pub auditor_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RecordAuditParams {
+    #[validate(range(min = 0, message = "actualFloatBalance cannot be negative"))]
+    #[serde(rename = "actualFloatBalance")]
+    pub actual_float_balance: i64,
+    pub findings: Option<Value>,
+    #[serde(rename = "complianceScore")]
+    pub compliance_score: Option<Decimal>,
+    #[serde(rename = "riskLevel")]
+    pub risk_level: RiskLevelEnum,
+    #[validate(length(min = 2, max = 500, message = "actionsTaken cannot be < 2 and > 500"))]
+    #[serde(rename = "actionsTaken")]
+    pub actions_taken: Option<String>,
+    #[serde(rename = "isFollowUpRequired")]
+    pub is_follow_up_required: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CloseFollowUpParams {
+    #[validate(length(min = 2, max = 500, message = "actionsTaken cannot be < 2 and > 500"))]
+    #[serde(rename = "actionsTaken")]
+    pub actions_taken: String,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::agent_audits::Entity")]
+pub struct AuditResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "agent_id")]
+    pub agent_id: i64,
+    #[sea_orm(from_col = "audit_date")]
+    pub audit_date: NaiveDate,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "auditor_id")]
+    pub auditor_id: i64,
+    #[sea_orm(from_col = "expected_float_balance")]
+    pub expected_float_balance: Option<i64>,
+    #[sea_orm(from_col = "actual_float_balance")]
+    pub actual_float_balance: Option<i64>,
+    #[sea_orm(from_col = "variance")]
+    pub variance: Option<i64>,
+    #[sea_orm(from_col = "findings")]
+    pub findings: Option<Value>,
+    #[sea_orm(from_col = "compliance_score")]
+    pub compliance_score: Option<Decimal>,
+    #[sea_orm(from_col = "risk_level")]
+    pub risk_level: Option<RiskLevelEnum>,
+    #[sea_orm(from_col = "actions_taken")]
+    pub actions_taken: Option<String>,
+    #[sea_orm(from_col = "is_follow_up_required")]
+    pub is_follow_up_required: Option<bool>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "updated_at")]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
@@ -0,0 +1,39 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::agent_audits::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/agent-audits")
+            .route(
+                "",
+                web::post()
+                    .to(controllers::schedule_audit)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}",
+                web::get()
+                    .to(controllers::audit_details)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/record",
+                web::put()
+                    .to(controllers::record_audit)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/close-follow-up",
+                web::put()
+                    .to(controllers::close_follow_up)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/agents/{id}",
+                web::get()
+                    .to(controllers::agent_audits)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
@@ -0,0 +1,272 @@
+use actix_web::web;
+use entity::sea_orm_active_enums::{AgentStatus, RiskLevelEnum};
+use sea_orm::{
+    ActiveEnum, ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait, prelude::Decimal,
+};
+
+use crate::{
+    AppState,
+    app::{
+        agent_audits::models::{AuditResponseModel, RecordAuditModel, ScheduleAuditModel},
+        agent_transactions::services::float_wallet,
+        agents::services::find_agent,
+    },
+    utils::gen_snow_ids::gen_snowflake_slug,
+};
+
+// Prefix of the suspension reason set by an audit, so closing the audit's
+// follow-up only lifts suspensions that audits imposed
+const AUDIT_SUSPENSION: &str = "Suspended pending audit follow-up";
+
+fn risk_rank(risk_level: &RiskLevelEnum) -> u8 {
+    match risk_level {
+        RiskLevelEnum::Low => 1,
+        RiskLevelEnum::Medium => 2,
+        RiskLevelEnum::High => 3,
+    }
+}
+
+// Reasons a recorded audit should suspend the agent; empty when it is
+// within the configured float variance and risk thresholds
+fn suspension_reasons(
+    state: &web::Data<AppState>,
+    variance: i64,
+    risk_level: &RiskLevelEnum,
+) -> Vec<String> {
+    let max_variance = state
+        .config
+        .get::<i64>("agent_audits.max_float_variance")
+        .unwrap_or(100_000);
+
+    let suspend_risk_level = state
+        .config
+        .get::<String>("agent_audits.suspend_risk_level")
+        .ok()
+        .and_then(|level| RiskLevelEnum::try_from_value(&level.to_uppercase()).ok())
+        .unwrap_or(RiskLevelEnum::High);
+
+    let mut reasons = Vec::new();
+
+    if variance.abs() > max_variance {
+        reasons.push(format!(
+            "float variance of {} exceeds {}",
+            variance, max_variance
+        ));
+    }
+
+    if risk_rank(risk_level) >= risk_rank(&suspend_risk_level) {
+        reasons.push(format!("risk level {}", risk_level.to_value()));
+    }
+
+    reasons
+}
+
+pub async fn schedule_audit(
+    model: &ScheduleAuditModel,
+    state: &web::Data<AppState>,
+) -> Result<AuditResponseModel, DbErr> {
+    let data = model.clone();
+
+    if data.audit_date < chrono::Utc::now().date_naive() {
+        return Err(DbErr::Custom("auditDate cannot be in the past".to_string()));
+    }
+
+    let agent = find_agent(state.pgdb.get_ref(), data.agent_id).await?;
+
+    if agent.status == Some(AgentStatus::Terminated) {
+        return Err(DbErr::Custom("Agent has been terminated".to_string()));
+    }
+
+    entity::staff::Entity::find_by_id(data.auditor_id)
+        .filter(entity::staff::Column::InstitutionId.eq(agent.institution_id))
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Auditor not found".into()))?;
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::agent_audits::ActiveModel {
+        id: Set(id),
+        institution_id: Set(agent.institution_id),
+        agent_id: Set(agent.id),
+        audit_date: Set(data.audit_date),
+        auditor_id: Set(data.auditor_id),
+        is_follow_up_required: Set(Some(false)),
+        ..Default::default()
+    }
+    .insert(state.pgdb.get_ref())
+    .await?;
+
+    get_audit(&id, state).await
+}
+
+// Expected float is the float wallet balance when the audit is recorded;
+// an agent over the variance or risk threshold is suspended until the
+// audit's follow-up is closed
+pub async fn record_audit(
+    audit_id: &i64,
+    model: &RecordAuditModel,
+    state: &web::Data<AppState>,
+) -> Result<AuditResponseModel, DbErr> {
+    let data = model.clone();
+
+    if let Some(score) = data.compliance_score
+        && (score < Decimal::ZERO || score > Decimal::ONE_HUNDRED)
+    {
+        return Err(DbErr::Custom(
+            "complianceScore cannot be < 0 and > 100".to_string(),
+        ));
+    }
+
+    let txn = state.pgdb.begin().await?;
+
+    let audit = entity::agent_audits::Entity::find_by_id(*audit_id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Audit not found".into()))?;
+
+    if audit.actual_float_balance.is_some() {
+        return Err(DbErr::Custom("Audit has already been recorded".to_string()));
+    }
+
+    if audit.audit_date > chrono::Utc::now().date_naive() {
+        return Err(DbErr::Custom("Audit is not due yet".to_string()));
+    }
+
+    let agent = find_agent(&txn, audit.agent_id).await?;
+
+    let wallet = float_wallet(&txn, agent.id).await?;
+
+    let expected = wallet.current_balance.unwrap_or(0);
+    let variance = data.actual_float_balance - expected;
+
+    let reasons = suspension_reasons(state, variance, &data.risk_level);
+
+    let mut active_audit: entity::agent_audits::ActiveModel = audit.into();
+
+    active_audit.expected_float_balance = Set(Some(expected));
+    active_audit.actual_float_balance = Set(Some(data.actual_float_balance));
+    active_audit.variance = Set(Some(variance));
+    active_audit.findings = Set(data.findings);
+    active_audit.compliance_score = Set(data.compliance_score);
+    active_audit.risk_level = Set(Some(data.risk_level));
+    active_audit.actions_taken = Set(data.actions_taken);
+    active_audit.is_follow_up_required =
+        Set(Some(data.is_follow_up_required || !reasons.is_empty()));
+    active_audit.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_audit, &txn).await?;
+
+    if !reasons.is_empty() && agent.status == Some(AgentStatus::Active) {
+        let mut active_agent: entity::agents::ActiveModel = agent.into();
+
+        active_agent.status = Set(Some(AgentStatus::Suspended));
+        active_agent.suspension_reason = Set(Some(format!(
+            "{}: {}",
+            AUDIT_SUSPENSION,
+            reasons.join(", ")
+        )));
+        active_agent.updated_at = Set(Some(chrono::Utc::now().into()));
+
+        ActiveModelTrait::update(active_agent, &txn).await?;
+    }
+
+    txn.commit().await?;
+
+    get_audit(audit_id, state).await
+}
+
+// Reactivates the agent once no audit follow-up remains open, but only if
+// an audit was what suspended it
+pub async fn close_follow_up(
+    audit_id: &i64,
+    actions_taken: &str,
+    state: &web::Data<AppState>,
+) -> Result<AuditResponseModel, DbErr> {
+    let txn = state.pgdb.begin().await?;
+
+    let audit = entity::agent_audits::Entity::find_by_id(*audit_id)
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Audit not found".into()))?;
+
+    if !audit.is_follow_up_required.unwrap_or(false) {
+        return Err(DbErr::Custom("Audit has no open follow-up".to_string()));
+    }
+
+    let agent_id = audit.agent_id;
+
+    let actions = match audit.actions_taken.as_ref() {
+        Some(previous) => format!("{}\n{}", previous, actions_taken),
+        None => actions_taken.to_string(),
+    };
+
+    let mut active_audit: entity::agent_audits::ActiveModel = audit.into();
+
+    active_audit.actions_taken = Set(Some(actions));
+    active_audit.is_follow_up_required = Set(Some(false));
+    active_audit.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_audit, &txn).await?;
+
+    let open_follow_ups = entity::agent_audits::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agent_audits::Column::AgentId.eq(agent_id))
+                .add(entity::agent_audits::Column::IsFollowUpRequired.eq(true)),
+        )
+        .count(&txn)
+        .await?;
+
+    let agent = find_agent(&txn, agent_id).await?;
+
+    let suspended_by_audit = agent.status == Some(AgentStatus::Suspended)
+        && agent
+            .suspension_reason
+            .as_ref()
+            .is_some_and(|reason| reason.starts_with(AUDIT_SUSPENSION));
+
+    if open_follow_ups == 0 && suspended_by_audit {
+        let mut active_agent: entity::agents::ActiveModel = agent.into();
+
+        active_agent.status = Set(Some(AgentStatus::Active));
+        active_agent.suspension_reason = Set(None);
+        active_agent.updated_at = Set(Some(chrono::Utc::now().into()));
+
+        ActiveModelTrait::update(active_agent, &txn).await?;
+    }
+
+    txn.commit().await?;
+
+    get_audit(audit_id, state).await
+}
+
+pub async fn get_audit(
+    audit_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<AuditResponseModel, DbErr> {
+    entity::agent_audits::Entity::find_by_id(*audit_id)
+        .into_model::<AuditResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Audit not found".into()))
+}
+
+pub async fn get_agent_audits(
+    agent_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<AuditResponseModel>, DbErr> {
+    let audits = entity::agent_audits::Entity::find()
+        .filter(entity::agent_audits::Column::AgentId.eq(*agent_id))
+        .order_by_desc(entity::agent_audits::Column::AuditDate)
+        .into_model::<AuditResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(audits)
+}
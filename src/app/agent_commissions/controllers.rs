@@ -0,0 +1,134 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::agent_commissions::{
+        models::{CreateRuleModel, CreateRuleParams},
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn create_rule(
+    req: HttpRequest,
+    payload: web::Json<CreateRuleParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "AGENT_COMMISSION_RULE_CREATE", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let institution_id = id_parser(&data.institution_id, "Institution Id").await?;
+
+    let mut applies_to_agents = Vec::new();
+
+    for agent_id in data.applies_to_agents.unwrap_or_default() {
+        applies_to_agents.push(id_parser(&agent_id, "Agent Id").await?);
+    }
+
+    let rule = CreateRuleModel {
+        institution_id,
+        rule_name: data.rule_name,
+        rule_description: data.rule_description,
+        applies_to_transaction_types: data.applies_to_transaction_types.unwrap_or_default(),
+        applies_to_agents,
+        commission_type: data.commission_type,
+        commission_value: data.commission_value,
+        slabs: data.slabs.unwrap_or_default(),
+        min_commission: data.min_commission,
+        max_commission: data.max_commission,
+        effective_from: data.effective_from,
+        effective_to: data.effective_to,
+        created_by: staff.id,
+    };
+
+    match services::create_rule(&rule, &state).await {
+        Ok(rule) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Commission Rule Created",
+            rule,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn deactivate_rule(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_COMMISSION_RULE_MANAGE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Rule Id").await?;
+
+    match services::deactivate_rule(&id, &state).await {
+        Ok(rule) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Commission Rule Deactivated",
+            rule,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn institution_rules(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Institution Id").await?;
+
+    match services::get_rules(&id, &state).await {
+        Ok(rules) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            rules,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn agent_commissions(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Agent Id").await?;
+
+    match services::get_agent_commissions(&id, &state).await {
+        Ok(commissions) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            commissions,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
@@ -0,0 +1,154 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use entity::sea_orm_active_enums::{
+    AgentCommissionRuleCommType, AgentCommissionsStatus, AgentCommissionsTransType, AgentTransType,
+};
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+// One band of a slabbed rule; an open `max` covers every larger amount and
+// a missing type falls back to the rule's commission type
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CommissionSlabModel {
+    #[validate(range(min = 0, message = "slab min cannot be negative"))]
+    pub min: i64,
+    #[validate(range(min = 1, message = "slab max must be greater than 0"))]
+    pub max: Option<i64>,
+    #[serde(rename = "commissionType")]
+    pub commission_type: Option<AgentCommissionRuleCommType>,
+    #[validate(range(min = 0, message = "slab value cannot be negative"))]
+    pub value: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateRuleModel {
+    pub institution_id: i64,
+    pub rule_name: String,
+    pub rule_description: Option<String>,
+    pub applies_to_transaction_types: Vec<AgentTransType>,
+    pub applies_to_agents: Vec<i64>,
+    pub commission_type: AgentCommissionRuleCommType,
+    pub commission_value: Option<i64>,
+    pub slabs: Vec<CommissionSlabModel>,
+    pub min_commission: Option<i64>,
+    pub max_commission: Option<i64>,
+    pub effective_from: Option<NaiveDate>,
+    pub effective_to: Option<NaiveDate>,
+    pub created_by: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateRuleParams {
+    #[serde(rename = "institutionId")]
+    pub institution_id: String,
+    #[validate(length(min = 2, max = 100, message = "ruleName cannot be < 2 and > 100"))]
+    #[serde(rename = "ruleName")]
+    pub rule_name: String,
+    #[validate(length(
+        min = 2,
+        max = 500,
+        message = "ruleDescription cannot be < 2 and > 500"
+    ))]
+    #[serde(rename = "ruleDescription")]
+    pub rule_description: Option<String>,
+    #[serde(rename = "appliesToTransactionTypes")]
+    pub applies_to_transaction_types: Option<Vec<AgentTransType>>,
+    #[serde(rename = "appliesToAgents")]
+    pub applies_to_agents: Option<Vec<String>>,
+    #[serde(rename = "commissionType")]
+    pub commission_type: AgentCommissionRuleCommType,
+    #[validate(range(min = 0, message = "commissionValue cannot be negative"))]
+    #[serde(rename = "commissionValue")]
+    pub commission_value: Option<i64>,
+    #[validate(nested)]
+    pub slabs: Option<Vec<CommissionSlabModel>>,
+    #[validate(range(min = 0, message = "minCommission cannot be negative"))]
+    #[serde(rename = "minCommission")]
+    pub min_commission: Option<i64>,
+    #[validate(range(min = 1, message = "maxCommission must be greater than 0"))]
+    #[serde(rename = "maxCommission")]
+    pub max_commission: Option<i64>,
+    #[serde(rename = "effectiveFrom")]
+    pub effective_from: Option<NaiveDate>,
+    #[serde(rename = "effectiveTo")]
+    pub effective_to: Option<NaiveDate>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::agent_commission_rules::Entity")]
+pub struct RuleResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "institution_id")]
+    pub institution_id: i64,
+    #[sea_orm(from_col = "rule_name")]
+    pub rule_name: String,
+    #[sea_orm(from_col = "rule_description")]
+    pub rule_description: Option<String>,
+    #[sea_orm(from_col = "applies_to_transaction_types")]
+    pub applies_to_transaction_types: Option<Value>,
+    #[sea_orm(from_col = "applies_to_agents")]
+    pub applies_to_agents: Option<Value>,
+    #[sea_orm(from_col = "commission_type")]
+    pub commission_type: Option<AgentCommissionRuleCommType>,
+    #[sea_orm(from_col = "commission_value")]
+    pub commission_value: Option<i64>,
+    #[sea_orm(from_col = "has_slabs")]
+    pub has_slabs: Option<bool>,
+    #[sea_orm(from_col = "slab_config")]
+    pub slab_config: Option<Value>,
+    #[sea_orm(from_col = "min_commission")]
+    pub min_commission: Option<i64>,
+    #[sea_orm(from_col = "max_commission")]
+    pub max_commission: Option<i64>,
+    #[sea_orm(from_col = "is_active")]
+    pub is_active: Option<bool>,
+    #[sea_orm(from_col = "effective_from")]
+    pub effective_from: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "effective_to")]
+    pub effective_to: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::agent_commissions::Entity")]
+pub struct CommissionResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "agent_id")]
+    pub agent_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "commission_rule_id")]
+    pub commission_rule_id: i64,
+    #[sea_orm(from_col = "transaction_reference")]
+    pub transaction_reference: Option<String>,
+    #[sea_orm(from_col = "transaction_amount")]
+    pub transaction_amount: i64,
+    #[sea_orm(from_col = "commission_rate")]
+    pub commission_rate: Decimal,
+    #[sea_orm(from_col = "commission_amount")]
+    pub commission_amount: i64,
+    #[sea_orm(from_col = "transaction_type")]
+    pub transaction_type: Option<AgentCommissionsTransType>,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<AgentCommissionsStatus>,
+    #[sea_orm(from_col = "paid_at")]
+    pub paid_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "settled_cycle")]
+    pub settled_cycle: Option<String>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
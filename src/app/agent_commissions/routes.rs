@@ -0,0 +1,33 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::agent_commissions::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/agent-commissions")
+            .route(
+                "/rules",
+                web::post()
+                    .to(controllers::create_rule)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/rules/{id}/deactivate",
+                web::put()
+                    .to(controllers::deactivate_rule)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/institutions/{id}/rules",
+                web::get()
+                    .to(controllers::institution_rules)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/agents/{id}",
+                web::get()
+                    .to(controllers::agent_commissions)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
@@ -0,0 +1,358 @@
+use actix_web::web;
+use chrono::{NaiveDate, NaiveTime};
+use entity::sea_orm_active_enums::{
+    AgentCommissionsStatus, AgentCommissionsTransType, AgentTransType,
+};
+use sea_orm::{
+    ActiveEnum, ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, Order, QueryFilter, QueryOrder, prelude::Decimal, sea_query::NullOrdering,
+};
+use serde_json::{Value, json};
+
+use crate::{
+    AppState,
+    app::agent_commissions::models::{
+        CommissionResponseModel, CommissionSlabModel, CreateRuleModel, RuleResponseModel,
+    },
+    utils::{finance::agent_commission, gen_snow_ids::gen_snowflake_slug},
+};
+
+fn ensure_slabs(slabs: &[CommissionSlabModel]) -> Result<(), DbErr> {
+    let mut previous_max: Option<i64> = None;
+
+    for (index, slab) in slabs.iter().enumerate() {
+        if let Some(max) = slab.max
+            && max < slab.min
+        {
+            return Err(DbErr::Custom(
+                "slab max cannot be less than slab min".to_string(),
+            ));
+        }
+
+        if index > 0 {
+            match previous_max {
+                Some(previous) if slab.min > previous => {}
+                _ => {
+                    return Err(DbErr::Custom(
+                        "slabs must be sorted by min and must not overlap".to_string(),
+                    ));
+                }
+            }
+        }
+
+        previous_max = slab.max;
+    }
+
+    Ok(())
+}
+
+fn start_of_day(date: NaiveDate) -> chrono::DateTime<chrono::FixedOffset> {
+    date.and_time(NaiveTime::MIN).and_utc().into()
+}
+
+fn end_of_day(date: NaiveDate) -> chrono::DateTime<chrono::FixedOffset> {
+    date.and_hms_opt(23, 59, 59)
+        .unwrap_or(date.and_time(NaiveTime::MIN))
+        .and_utc()
+        .into()
+}
+
+pub async fn create_rule(
+    model: &CreateRuleModel,
+    state: &web::Data<AppState>,
+) -> Result<RuleResponseModel, DbErr> {
+    let data = model.clone();
+
+    let has_slabs = !data.slabs.is_empty();
+
+    if has_slabs {
+        ensure_slabs(&data.slabs)?;
+    } else if data.commission_value.is_none() {
+        return Err(DbErr::Custom(
+            "commissionValue is required when no slabs are given".to_string(),
+        ));
+    }
+
+    if let (Some(min), Some(max)) = (data.min_commission, data.max_commission)
+        && min > max
+    {
+        return Err(DbErr::Custom(
+            "minCommission cannot be greater than maxCommission".to_string(),
+        ));
+    }
+
+    if let (Some(from), Some(to)) = (data.effective_from, data.effective_to)
+        && from > to
+    {
+        return Err(DbErr::Custom(
+            "effectiveFrom cannot be after effectiveTo".to_string(),
+        ));
+    }
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let transaction_types: Vec<String> = data
+        .applies_to_transaction_types
+        .iter()
+        .map(|transaction_type| transaction_type.to_value())
+        .collect();
+
+    let agents: Vec<String> = data
+        .applies_to_agents
+        .iter()
+        .map(|agent_id| agent_id.to_string())
+        .collect();
+
+    entity::agent_commission_rules::ActiveModel {
+        id: Set(id),
+        institution_id: Set(data.institution_id),
+        rule_name: Set(data.rule_name),
+        rule_description: Set(data.rule_description),
+        applies_to_transaction_types: Set(Some(json!(transaction_types))),
+        applies_to_agents: Set(Some(json!(agents))),
+        commission_type: Set(Some(data.commission_type)),
+        commission_value: Set(data.commission_value),
+        has_slabs: Set(Some(has_slabs)),
+        slab_config: Set(has_slabs.then(|| json!(data.slabs))),
+        min_commission: Set(data.min_commission),
+        max_commission: Set(data.max_commission),
+        is_active: Set(Some(true)),
+        effective_from: Set(data.effective_from.map(start_of_day)),
+        effective_to: Set(data.effective_to.map(end_of_day)),
+        created_by: Set(Some(data.created_by)),
+        ..Default::default()
+    }
+    .insert(state.pgdb.get_ref())
+    .await?;
+
+    entity::agent_commission_rules::Entity::find_by_id(id)
+        .into_model::<RuleResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Commission rule not found".into()))
+}
+
+pub async fn get_rules(
+    institution_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<RuleResponseModel>, DbErr> {
+    let rules = entity::agent_commission_rules::Entity::find()
+        .filter(entity::agent_commission_rules::Column::InstitutionId.eq(*institution_id))
+        .order_by_desc(entity::agent_commission_rules::Column::CreatedAt)
+        .into_model::<RuleResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(rules)
+}
+
+pub async fn deactivate_rule(
+    rule_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<RuleResponseModel, DbErr> {
+    let rule = entity::agent_commission_rules::Entity::find_by_id(*rule_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Commission rule not found".into()))?;
+
+    let mut active_rule: entity::agent_commission_rules::ActiveModel = rule.into();
+
+    active_rule.is_active = Set(Some(false));
+    active_rule.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_rule, state.pgdb.get_ref()).await?;
+
+    entity::agent_commission_rules::Entity::find_by_id(*rule_id)
+        .into_model::<RuleResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Commission rule not found".into()))
+}
+
+fn commission_transaction_type(
+    transaction_type: &AgentTransType,
+) -> Option<AgentCommissionsTransType> {
+    match transaction_type {
+        AgentTransType::CashDeposit => Some(AgentCommissionsTransType::Deposit),
+        AgentTransType::CashWithdrawal => Some(AgentCommissionsTransType::Withdrawal),
+        AgentTransType::LoanRepayment => Some(AgentCommissionsTransType::LoanRepayment),
+        AgentTransType::BillPayment => Some(AgentCommissionsTransType::BillPayment),
+        AgentTransType::WalletTopup | AgentTransType::WalletWithdrawal => None,
+    }
+}
+
+// An empty or missing list applies to everything
+fn list_matches(list: &Option<Value>, value: &str) -> bool {
+    match list.as_ref().and_then(|list| list.as_array()) {
+        Some(items) if !items.is_empty() => items.iter().any(|item| item.as_str() == Some(value)),
+        _ => true,
+    }
+}
+
+fn is_agent_specific(rule: &entity::agent_commission_rules::Model) -> bool {
+    rule.applies_to_agents
+        .as_ref()
+        .and_then(|agents| agents.as_array())
+        .is_some_and(|agents| !agents.is_empty())
+}
+
+// Active, in-date rules for the transaction type and agent; rules naming the
+// agent win over general ones, then the most recently effective rule
+async fn applicable_rule<C: ConnectionTrait>(
+    conn: &C,
+    agent: &entity::agents::Model,
+    transaction_type: &AgentTransType,
+) -> Result<Option<entity::agent_commission_rules::Model>, DbErr> {
+    let now = chrono::Utc::now();
+
+    let rules = entity::agent_commission_rules::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agent_commission_rules::Column::InstitutionId.eq(agent.institution_id))
+                .add(entity::agent_commission_rules::Column::IsActive.eq(true))
+                .add(
+                    Condition::any()
+                        .add(entity::agent_commission_rules::Column::EffectiveFrom.is_null())
+                        .add(entity::agent_commission_rules::Column::EffectiveFrom.lte(now)),
+                )
+                .add(
+                    Condition::any()
+                        .add(entity::agent_commission_rules::Column::EffectiveTo.is_null())
+                        .add(entity::agent_commission_rules::Column::EffectiveTo.gte(now)),
+                ),
+        )
+        // Open-ended rules rank after dated ones, which Postgres would
+        // otherwise put first in a descending sort
+        .order_by_with_nulls(
+            entity::agent_commission_rules::Column::EffectiveFrom,
+            Order::Desc,
+            NullOrdering::Last,
+        )
+        .order_by_desc(entity::agent_commission_rules::Column::CreatedAt)
+        .all(conn)
+        .await?;
+
+    let transaction_type = transaction_type.to_value();
+    let agent_id = agent.id.to_string();
+
+    let mut matching = rules.into_iter().filter(|rule| {
+        list_matches(&rule.applies_to_transaction_types, &transaction_type)
+            && list_matches(&rule.applies_to_agents, &agent_id)
+    });
+
+    let first = matching.next();
+
+    if first.as_ref().is_some_and(is_agent_specific) {
+        return Ok(first);
+    }
+
+    Ok(matching.find(is_agent_specific).or(first))
+}
+
+// Commission for `amount` under `rule`; slabbed rules use the band the
+// amount falls in and pay nothing outside every band
+fn rule_commission(rule: &entity::agent_commission_rules::Model, amount: i64) -> i64 {
+    let Some(rule_type) = rule.commission_type.as_ref() else {
+        return 0;
+    };
+
+    let (commission_type, value) = if rule.has_slabs.unwrap_or(false) {
+        let slabs: Vec<CommissionSlabModel> = rule
+            .slab_config
+            .clone()
+            .and_then(|config| serde_json::from_value(config).ok())
+            .unwrap_or_default();
+
+        match slabs
+            .into_iter()
+            .find(|slab| slab.min <= amount && slab.max.is_none_or(|max| amount <= max))
+        {
+            Some(slab) => (
+                slab.commission_type.unwrap_or(rule_type.clone()),
+                slab.value,
+            ),
+            None => return 0,
+        }
+    } else {
+        (rule_type.clone(), rule.commission_value.unwrap_or(0))
+    };
+
+    agent_commission(
+        amount,
+        &commission_type,
+        value,
+        rule.min_commission,
+        rule.max_commission,
+    )
+}
+
+// Runs the commission engine for one agent transaction, recording the
+// earning as a pending commission; returns the amount earned
+pub async fn apply_commission<C: ConnectionTrait>(
+    conn: &C,
+    agent: &entity::agents::Model,
+    agent_transaction_id: i64,
+    transaction_type: &AgentTransType,
+    amount: i64,
+) -> Result<i64, DbErr> {
+    let Some(commission_type) = commission_transaction_type(transaction_type) else {
+        return Ok(0);
+    };
+
+    let Some(rule) = applicable_rule(conn, agent, transaction_type).await? else {
+        return Ok(0);
+    };
+
+    let commission = rule_commission(&rule, amount);
+
+    if commission <= 0 {
+        return Ok(0);
+    }
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    // The effective rate after slabs and clamping, as a percentage
+    let commission_rate = if amount > 0 {
+        (Decimal::from(commission) * Decimal::ONE_HUNDRED / Decimal::from(amount)).round_dp(6)
+    } else {
+        Decimal::ZERO
+    };
+
+    entity::agent_commissions::ActiveModel {
+        id: Set(id),
+        institution_id: Set(agent.institution_id),
+        agent_id: Set(agent.id),
+        commission_rule_id: Set(rule.id),
+        transaction_reference: Set(Some(agent_transaction_id.to_string())),
+        transaction_amount: Set(amount),
+        commission_rate: Set(commission_rate),
+        commission_amount: Set(commission),
+        transaction_type: Set(Some(commission_type)),
+        status: Set(Some(AgentCommissionsStatus::Pending)),
+        ..Default::default()
+    }
+    .insert(conn)
+    .await?;
+
+    Ok(commission)
+}
+
+pub async fn get_agent_commissions(
+    agent_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<CommissionResponseModel>, DbErr> {
+    let commissions = entity::agent_commissions::Entity::find()
+        .filter(entity::agent_commissions::Column::AgentId.eq(*agent_id))
+        .order_by_desc(entity::agent_commissions::Column::CreatedAt)
+        .into_model::<CommissionResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(commissions)
+}
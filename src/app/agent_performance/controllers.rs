@@ -0,0 +1,105 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::agent_performance::{
+        models::{
+            PerformancePeriodModel, PerformancePeriodParams, RunPerformanceModel,
+            RunPerformanceParams,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn run_performance(
+    req: HttpRequest,
+    payload: web::Json<RunPerformanceParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_PERFORMANCE_RUN", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let run = RunPerformanceModel {
+        institution_id: id_parser(&data.institution_id, "Institution Id").await?,
+        report_date: data.report_date,
+    };
+
+    match services::run_performance(&run, &state).await {
+        Ok(performance) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Performance Run Completed",
+            performance,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn branch_rankings(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    query: web::Query<PerformancePeriodParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Branch Id").await?;
+
+    let period = PerformancePeriodModel {
+        from_date: query.from_date,
+        to_date: query.to_date,
+    };
+
+    match services::get_branch_rankings(&id, &period, &state).await {
+        Ok(rankings) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            rankings,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn agent_trend(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    query: web::Query<PerformancePeriodParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Agent Id").await?;
+
+    let period = PerformancePeriodModel {
+        from_date: query.from_date,
+        to_date: query.to_date,
+    };
+
+    match services::get_agent_trend(&id, &period, &state).await {
+        Ok(performance) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            performance,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
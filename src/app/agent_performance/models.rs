@@ -0,0 +1,100 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use sea_orm::FromQueryResult;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[derive(Debug, Clone)]
+pub struct RunPerformanceModel {
+    pub institution_id: i64,
+    pub report_date: NaiveDate,
+}
+
+#[derive(Debug, Clone)]
+pub struct PerformancePeriodModel {
+    pub from_date: Option<NaiveDate>,
+    pub to_date: Option<NaiveDate>,
+}
+
+// Score weights and the daily figures that earn each weight in full
+#[derive(Debug, Clone)]
+pub struct ScoreConfigModel {
+    pub transaction_count_target: i64,
+    pub transaction_count_weight: i64,
+    pub transaction_volume_target: i64,
+    pub transaction_volume_weight: i64,
+    pub active_customer_target: i64,
+    pub active_customer_weight: i64,
+    pub commission_target: i64,
+    pub commission_weight: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RunPerformanceParams {
+    #[serde(rename = "institutionId")]
+    pub institution_id: String,
+    #[serde(rename = "reportDate")]
+    pub report_date: NaiveDate,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PerformancePeriodParams {
+    #[serde(rename = "fromDate")]
+    pub from_date: Option<NaiveDate>,
+    #[serde(rename = "toDate")]
+    pub to_date: Option<NaiveDate>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::agent_performance::Entity")]
+pub struct PerformanceResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "agent_id")]
+    pub agent_id: i64,
+    #[sea_orm(from_col = "report_date")]
+    pub report_date: NaiveDate,
+    #[sea_orm(from_col = "deposit_count")]
+    pub deposit_count: Option<i32>,
+    #[sea_orm(from_col = "deposit_amount")]
+    pub deposit_amount: Option<i64>,
+    #[sea_orm(from_col = "withdrawal_count")]
+    pub withdrawal_count: Option<i32>,
+    #[sea_orm(from_col = "withdrawal_amount")]
+    pub withdrawal_amount: Option<i64>,
+    #[sea_orm(from_col = "loan_repayment_count")]
+    pub loan_repayment_count: Option<i32>,
+    #[sea_orm(from_col = "loan_repayment_amount")]
+    pub loan_repayment_amount: Option<i64>,
+    #[sea_orm(from_col = "commission_earned")]
+    pub commission_earned: Option<i64>,
+    #[sea_orm(from_col = "active_customer_count")]
+    pub active_customer_count: Option<i32>,
+    #[sea_orm(from_col = "performance_score")]
+    pub performance_score: Option<Decimal>,
+    #[sea_orm(from_col = "updated_at")]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+// An agent's totals over a period, ranked by average daily score
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct AgentRankingModel {
+    pub rank: usize,
+    #[serde_as(as = "DisplayFromStr")]
+    pub agent_id: i64,
+    pub agent_number: String,
+    pub agent_name: Option<String>,
+    pub days_reported: i32,
+    pub transaction_count: i64,
+    pub transaction_volume: i64,
+    pub commission_earned: i64,
+    pub average_score: Decimal,
+}
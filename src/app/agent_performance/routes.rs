@@ -0,0 +1,27 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::agent_performance::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/agent-performance")
+            .route(
+                "/runs",
+                web::post()
+                    .to(controllers::run_performance)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/branches/{id}/rankings",
+                web::get()
+                    .to(controllers::branch_rankings)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/agents/{id}/trend",
+                web::get()
+                    .to(controllers::agent_trend)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
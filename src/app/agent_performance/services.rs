@@ -0,0 +1,418 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_web::web;
+use chrono::{Duration, NaiveDate, NaiveTime};
+use entity::sea_orm_active_enums::{AgentStatus, AgentTransStatus, AgentTransType};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait, prelude::Decimal,
+};
+
+use crate::{
+    AppState,
+    app::agent_performance::models::{
+        AgentRankingModel, PerformancePeriodModel, PerformanceResponseModel, RunPerformanceModel,
+        ScoreConfigModel,
+    },
+    utils::{finance::weighted_score, gen_snow_ids::gen_snowflake_slug},
+};
+
+fn score_setting(state: &web::Data<AppState>, key: &str, default: i64) -> i64 {
+    state
+        .config
+        .get::<i64>(&format!("agent_performance.{}", key))
+        .unwrap_or(default)
+}
+
+pub fn score_config(state: &web::Data<AppState>) -> ScoreConfigModel {
+    ScoreConfigModel {
+        transaction_count_target: score_setting(state, "transaction_count_target", 50),
+        transaction_count_weight: score_setting(state, "transaction_count_weight", 30),
+        transaction_volume_target: score_setting(state, "transaction_volume_target", 5_000_000),
+        transaction_volume_weight: score_setting(state, "transaction_volume_weight", 40),
+        active_customer_target: score_setting(state, "active_customer_target", 30),
+        active_customer_weight: score_setting(state, "active_customer_weight", 20),
+        commission_target: score_setting(state, "commission_target", 50_000),
+        commission_weight: score_setting(state, "commission_weight", 10),
+    }
+}
+
+// Customer-facing transactions processed by an institution's agents on the
+// report date, grouped by agent
+async fn day_transactions<C: ConnectionTrait>(
+    conn: &C,
+    institution_id: i64,
+    report_date: NaiveDate,
+) -> Result<HashMap<i64, Vec<entity::agent_transactions::Model>>, DbErr> {
+    let start = report_date.and_time(NaiveTime::MIN).and_utc();
+
+    let transactions = entity::agent_transactions::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agent_transactions::Column::InstitutionId.eq(institution_id))
+                .add(entity::agent_transactions::Column::Status.eq(AgentTransStatus::Processed))
+                .add(
+                    entity::agent_transactions::Column::TransactionType.is_not_in([
+                        AgentTransType::WalletTopup,
+                        AgentTransType::WalletWithdrawal,
+                    ]),
+                )
+                .add(entity::agent_transactions::Column::CreatedAt.gte(start))
+                .add(entity::agent_transactions::Column::CreatedAt.lt(start + Duration::days(1))),
+        )
+        .all(conn)
+        .await?;
+
+    let mut grouped: HashMap<i64, Vec<entity::agent_transactions::Model>> = HashMap::new();
+
+    for transaction in transactions {
+        grouped
+            .entry(transaction.agent_id)
+            .or_default()
+            .push(transaction);
+    }
+
+    Ok(grouped)
+}
+
+// Writes the agent's scorecard for the day, replacing any earlier run
+async fn record_performance<C: ConnectionTrait>(
+    conn: &C,
+    agent: &entity::agents::Model,
+    report_date: NaiveDate,
+    transactions: &[entity::agent_transactions::Model],
+    config: &ScoreConfigModel,
+) -> Result<(), DbErr> {
+    let mut deposits = (0, 0);
+    let mut withdrawals = (0, 0);
+    let mut repayments = (0, 0);
+    let mut commission = 0;
+    let mut customers = HashSet::new();
+
+    for transaction in transactions {
+        let amount = transaction.amount.unwrap_or(0);
+
+        let scored = match transaction.transaction_type {
+            AgentTransType::CashDeposit => {
+                deposits = (deposits.0 + 1, deposits.1 + amount);
+                true
+            }
+            AgentTransType::CashWithdrawal => {
+                withdrawals = (withdrawals.0 + 1, withdrawals.1 + amount);
+                true
+            }
+            AgentTransType::LoanRepayment => {
+                repayments = (repayments.0 + 1, repayments.1 + amount);
+                true
+            }
+            _ => false,
+        };
+
+        // Commission is scored on the same types as count and volume
+        if scored {
+            commission += transaction.commission_earned.unwrap_or(0);
+        }
+
+        if let Some(customer) = transaction
+            .customer_account
+            .as_ref()
+            .or(transaction.customer_phone.as_ref())
+        {
+            customers.insert(customer.clone());
+        }
+    }
+
+    // Count and volume cover only the types the scorecard stores, so the
+    // score agrees with the totals the ranking reads back
+    let count = i64::from(deposits.0 + withdrawals.0 + repayments.0);
+    let volume = deposits.1 + withdrawals.1 + repayments.1;
+
+    let score = weighted_score(&[
+        (
+            count,
+            config.transaction_count_target,
+            config.transaction_count_weight,
+        ),
+        (
+            volume,
+            config.transaction_volume_target,
+            config.transaction_volume_weight,
+        ),
+        (
+            customers.len() as i64,
+            config.active_customer_target,
+            config.active_customer_weight,
+        ),
+        (
+            commission,
+            config.commission_target,
+            config.commission_weight,
+        ),
+    ]);
+
+    let existing = entity::agent_performance::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agent_performance::Column::AgentId.eq(agent.id))
+                .add(entity::agent_performance::Column::ReportDate.eq(report_date)),
+        )
+        .one(conn)
+        .await?;
+
+    let mut performance = entity::agent_performance::ActiveModel {
+        institution_id: Set(agent.institution_id),
+        agent_id: Set(agent.id),
+        report_date: Set(report_date),
+        deposit_count: Set(Some(deposits.0)),
+        deposit_amount: Set(Some(deposits.1)),
+        withdrawal_count: Set(Some(withdrawals.0)),
+        withdrawal_amount: Set(Some(withdrawals.1)),
+        loan_repayment_count: Set(Some(repayments.0)),
+        loan_repayment_amount: Set(Some(repayments.1)),
+        commission_earned: Set(Some(commission)),
+        active_customer_count: Set(Some(customers.len() as i32)),
+        performance_score: Set(Some(score)),
+        ..Default::default()
+    };
+
+    match existing {
+        Some(existing) => {
+            performance.id = Set(existing.id);
+            performance.updated_at = Set(Some(chrono::Utc::now().into()));
+
+            ActiveModelTrait::update(performance, conn).await?;
+        }
+        None => {
+            let (id, _) = match gen_snowflake_slug() {
+                Ok(res) => res,
+                Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+            };
+
+            performance.id = Set(id);
+
+            performance.insert(conn).await?;
+        }
+    }
+
+    Ok(())
+}
+
+// Scores every active agent of the institution, plus any agent that
+// transacted that day; safe to re-run for the same date
+pub async fn run_performance(
+    model: &RunPerformanceModel,
+    state: &web::Data<AppState>,
+) -> Result<Vec<PerformanceResponseModel>, DbErr> {
+    let data = model.clone();
+
+    if data.report_date > chrono::Utc::now().date_naive() {
+        return Err(DbErr::Custom(
+            "reportDate cannot be in the future".to_string(),
+        ));
+    }
+
+    let config = score_config(state);
+
+    let txn = state.pgdb.begin().await?;
+
+    let mut transactions = day_transactions(&txn, data.institution_id, data.report_date).await?;
+
+    let agents = entity::agents::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agents::Column::InstitutionId.eq(data.institution_id))
+                .add(
+                    Condition::any()
+                        .add(entity::agents::Column::Status.eq(AgentStatus::Active))
+                        .add(entity::agents::Column::Id.is_in(transactions.keys().copied())),
+                ),
+        )
+        .all(&txn)
+        .await?;
+
+    for agent in agents {
+        let agent_transactions = transactions.remove(&agent.id).unwrap_or_default();
+
+        record_performance(&txn, &agent, data.report_date, &agent_transactions, &config).await?;
+    }
+
+    txn.commit().await?;
+
+    let performance = entity::agent_performance::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agent_performance::Column::InstitutionId.eq(data.institution_id))
+                .add(entity::agent_performance::Column::ReportDate.eq(data.report_date)),
+        )
+        .order_by_desc(entity::agent_performance::Column::PerformanceScore)
+        .into_model::<PerformanceResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(performance)
+}
+
+// Scores yesterday for every institution with agents
+pub async fn run_daily_performance(state: &web::Data<AppState>) -> Result<u64, DbErr> {
+    let report_date = chrono::Utc::now().date_naive() - Duration::days(1);
+
+    let institution_ids: Vec<i64> = entity::agents::Entity::find()
+        .select_only()
+        .column(entity::agents::Column::InstitutionId)
+        .filter(entity::agents::Column::Status.is_in([AgentStatus::Active, AgentStatus::Suspended]))
+        .distinct()
+        .into_tuple()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let mut scored = 0;
+
+    for institution_id in institution_ids {
+        match run_performance(
+            &RunPerformanceModel {
+                institution_id,
+                report_date,
+            },
+            state,
+        )
+        .await
+        {
+            Ok(performance) => scored += performance.len() as u64,
+            Err(err) => {
+                log::warn!(
+                    "Agent performance for institution {} on {} not scored: {}",
+                    institution_id,
+                    report_date,
+                    err
+                );
+            }
+        }
+    }
+
+    Ok(scored)
+}
+
+// Defaults to the 30 days up to yesterday
+fn period_bounds(period: &PerformancePeriodModel) -> Result<(NaiveDate, NaiveDate), DbErr> {
+    let to_date = period
+        .to_date
+        .unwrap_or(chrono::Utc::now().date_naive() - Duration::days(1));
+
+    let from_date = period.from_date.unwrap_or(to_date - Duration::days(29));
+
+    if from_date > to_date {
+        return Err(DbErr::Custom("fromDate cannot be after toDate".to_string()));
+    }
+
+    Ok((from_date, to_date))
+}
+
+pub async fn get_branch_rankings(
+    branch_id: &i64,
+    period: &PerformancePeriodModel,
+    state: &web::Data<AppState>,
+) -> Result<Vec<AgentRankingModel>, DbErr> {
+    let (from_date, to_date) = period_bounds(period)?;
+
+    let agents = entity::agents::Entity::find()
+        .filter(entity::agents::Column::BranchId.eq(*branch_id))
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let performance = entity::agent_performance::Entity::find()
+        .filter(
+            Condition::all()
+                .add(
+                    entity::agent_performance::Column::AgentId
+                        .is_in(agents.iter().map(|agent| agent.id)),
+                )
+                .add(entity::agent_performance::Column::ReportDate.gte(from_date))
+                .add(entity::agent_performance::Column::ReportDate.lte(to_date)),
+        )
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let mut rankings: Vec<AgentRankingModel> = agents
+        .into_iter()
+        .filter_map(|agent| {
+            let days: Vec<&entity::agent_performance::Model> = performance
+                .iter()
+                .filter(|day| day.agent_id == agent.id)
+                .collect();
+
+            if days.is_empty() {
+                return None;
+            }
+
+            let total_score: Decimal = days
+                .iter()
+                .map(|day| day.performance_score.unwrap_or(Decimal::ZERO))
+                .sum();
+
+            Some(AgentRankingModel {
+                rank: 0,
+                agent_id: agent.id,
+                agent_number: agent.agent_number,
+                agent_name: agent.agent_name,
+                days_reported: days.len() as i32,
+                transaction_count: days
+                    .iter()
+                    .map(|day| {
+                        i64::from(
+                            day.deposit_count.unwrap_or(0)
+                                + day.withdrawal_count.unwrap_or(0)
+                                + day.loan_repayment_count.unwrap_or(0),
+                        )
+                    })
+                    .sum(),
+                transaction_volume: days
+                    .iter()
+                    .map(|day| {
+                        day.deposit_amount.unwrap_or(0)
+                            + day.withdrawal_amount.unwrap_or(0)
+                            + day.loan_repayment_amount.unwrap_or(0)
+                    })
+                    .sum(),
+                commission_earned: days
+                    .iter()
+                    .map(|day| day.commission_earned.unwrap_or(0))
+                    .sum(),
+                average_score: (total_score / Decimal::from(days.len())).round_dp(2),
+            })
+        })
+        .collect();
+
+    rankings.sort_by(|a, b| {
+        b.average_score
+            .cmp(&a.average_score)
+            .then(b.transaction_volume.cmp(&a.transaction_volume))
+    });
+
+    for (index, ranking) in rankings.iter_mut().enumerate() {
+        ranking.rank = index + 1;
+    }
+
+    Ok(rankings)
+}
+
+pub async fn get_agent_trend(
+    agent_id: &i64,
+    period: &PerformancePeriodModel,
+    state: &web::Data<AppState>,
+) -> Result<Vec<PerformanceResponseModel>, DbErr> {
+    let (from_date, to_date) = period_bounds(period)?;
+
+    let performance = entity::agent_performance::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agent_performance::Column::AgentId.eq(*agent_id))
+                .add(entity::agent_performance::Column::ReportDate.gte(from_date))
+                .add(entity::agent_performance::Column::ReportDate.lte(to_date)),
+        )
+        .order_by_asc(entity::agent_performance::Column::ReportDate)
+        .into_model::<PerformanceResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(performance)
+}
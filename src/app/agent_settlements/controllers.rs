@@ -0,0 +1,191 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::agent_settlements::{
+        models::{
+            ReconcileSettlementModel, ReconcileSettlementParams, RunSettlementModel,
+            RunSettlementParams, SettlementGlAccountsModel, SettlementGlAccountsParams,
+            SettlementOverrideModel,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn run_settlement(
+    req: HttpRequest,
+    payload: web::Json<RunSettlementParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "AGENT_SETTLEMENT_RUN", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let institution_id = id_parser(&data.institution_id, "Institution Id").await?;
+
+    let mut overrides = Vec::new();
+
+    for item in data.overrides.unwrap_or_default() {
+        overrides.push(SettlementOverrideModel {
+            agent_id: id_parser(&item.agent_id, "Agent Id").await?,
+            adjustment: item.adjustment.unwrap_or(0),
+            channel: item.channel,
+            destination: item.destination,
+        });
+    }
+
+    let run = RunSettlementModel {
+        institution_id,
+        settlement_date: data.settlement_date,
+        overrides,
+        initiated_by: Some(staff.id),
+    };
+
+    match services::run_settlement(&run, &state).await {
+        Ok(run) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Settlement Run Completed",
+            run,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn reconcile_settlement(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<ReconcileSettlementParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_SETTLEMENT_RECONCILE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Settlement Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let reconcile = ReconcileSettlementModel {
+        status: data.status,
+        settlement_reference: data.settlement_reference,
+    };
+
+    match services::reconcile_settlement(&id, &reconcile, &state).await {
+        Ok(settlement) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Settlement Reconciled",
+            settlement,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn settlement_details(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Settlement Id").await?;
+
+    match services::get_settlement(&id, &state).await {
+        Ok(settlement) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            settlement,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn agent_settlements(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Agent Id").await?;
+
+    match services::get_agent_settlements(&id, &state).await {
+        Ok(settlements) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            settlements,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn set_gl_accounts(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<SettlementGlAccountsParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_SETTLEMENT_CONFIGURE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Institution Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let gl_accounts = SettlementGlAccountsModel {
+        commission_expense_gl_account_id: id_parser(
+            &data.commission_expense_gl_account_id,
+            "Commission Expense GL Account Id",
+        )
+        .await?,
+        settlement_gl_account_id: id_parser(
+            &data.settlement_gl_account_id,
+            "Settlement GL Account Id",
+        )
+        .await?,
+    };
+
+    match services::set_gl_accounts(&id, &gl_accounts, &state).await {
+        Ok(gl_accounts) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Settlement GL Accounts Updated",
+            gl_accounts,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
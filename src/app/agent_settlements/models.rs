@@ -0,0 +1,137 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use entity::sea_orm_active_enums::{AgentSettlementChannels, AgentSettlementStatus};
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+// Per-agent changes to a settlement run: a signed adjustment and, for
+// payouts outside the bank, the channel and destination to pay to
+#[derive(Debug, Clone)]
+pub struct SettlementOverrideModel {
+    pub agent_id: i64,
+    pub adjustment: i64,
+    pub channel: Option<AgentSettlementChannels>,
+    pub destination: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RunSettlementModel {
+    pub institution_id: i64,
+    pub settlement_date: NaiveDate,
+    pub overrides: Vec<SettlementOverrideModel>,
+    pub initiated_by: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReconcileSettlementModel {
+    pub status: AgentSettlementStatus,
+    pub settlement_reference: Option<String>,
+}
+
+// Commission paid into settlement accounts is posted from the expense GL to
+// the deposits GL those accounts sit in
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct SettlementGlAccountsModel {
+    #[serde_as(as = "DisplayFromStr")]
+    pub commission_expense_gl_account_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub settlement_gl_account_id: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementGlAccountsParams {
+    #[serde(rename = "commissionExpenseGlAccountId")]
+    pub commission_expense_gl_account_id: String,
+    #[serde(rename = "settlementGlAccountId")]
+    pub settlement_gl_account_id: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementOverrideParams {
+    #[serde(rename = "agentId")]
+    pub agent_id: String,
+    pub adjustment: Option<i64>,
+    pub channel: Option<AgentSettlementChannels>,
+    #[validate(length(min = 4, max = 50, message = "destination cannot be < 4 and > 50"))]
+    pub destination: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RunSettlementParams {
+    #[serde(rename = "institutionId")]
+    pub institution_id: String,
+    #[serde(rename = "settlementDate")]
+    pub settlement_date: NaiveDate,
+    #[validate(nested)]
+    pub overrides: Option<Vec<SettlementOverrideParams>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ReconcileSettlementParams {
+    pub status: AgentSettlementStatus,
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "settlementReference cannot be < 2 and > 100"
+    ))]
+    #[serde(rename = "settlementReference")]
+    pub settlement_reference: Option<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::agent_settlements::Entity")]
+pub struct SettlementResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "agent_id")]
+    pub agent_id: i64,
+    #[sea_orm(from_col = "settlement_cycle")]
+    pub settlement_cycle: String,
+    #[sea_orm(from_col = "settlement_date")]
+    pub settlement_date: NaiveDate,
+    #[sea_orm(from_col = "total_transactions")]
+    pub total_transactions: Option<i32>,
+    #[sea_orm(from_col = "total_transaction_volume")]
+    pub total_transaction_volume: Option<i64>,
+    #[sea_orm(from_col = "total_commission_earned")]
+    pub total_commission_earned: Option<i64>,
+    #[sea_orm(from_col = "previous_balance")]
+    pub previous_balance: Option<i64>,
+    #[sea_orm(from_col = "adjustments")]
+    pub adjustments: Option<i64>,
+    #[sea_orm(from_col = "final_settlement_amount")]
+    pub final_settlement_amount: i64,
+    #[sea_orm(from_col = "settlement_account")]
+    pub settlement_account: Option<String>,
+    #[sea_orm(from_col = "settlement_reference")]
+    pub settlement_reference: Option<String>,
+    #[sea_orm(from_col = "settlement_channel")]
+    pub settlement_channel: Option<AgentSettlementChannels>,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<AgentSettlementStatus>,
+    #[sea_orm(from_col = "reconciliation_status")]
+    pub reconciliation_status: Option<AgentSettlementStatus>,
+    #[sea_orm(from_col = "processed_at")]
+    pub processed_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "reconciled_at")]
+    pub reconciled_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SettlementRunModel {
+    pub settlement_cycle: String,
+    pub settlements: Vec<SettlementResponseModel>,
+    pub failed: usize,
+}
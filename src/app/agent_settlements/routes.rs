@@ -0,0 +1,39 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::agent_settlements::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/agent-settlements")
+            .route(
+                "/runs",
+                web::post()
+                    .to(controllers::run_settlement)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}",
+                web::get()
+                    .to(controllers::settlement_details)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/reconcile",
+                web::put()
+                    .to(controllers::reconcile_settlement)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/agents/{id}",
+                web::get()
+                    .to(controllers::agent_settlements)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/institutions/{id}/gl-accounts",
+                web::put()
+                    .to(controllers::set_gl_accounts)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
@@ -0,0 +1,593 @@
+use actix_web::web;
+use chrono::{Duration, NaiveDate, NaiveTime};
+use entity::sea_orm_active_enums::{
+    AgentCommissionsStatus, AgentSettlementStatus, AgentStatus, AgentTransStatus, AgentTransType,
+};
+use migration::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+
+use crate::{
+    AppState,
+    app::{
+        agent_settlements::models::{
+            ReconcileSettlementModel, RunSettlementModel, SettlementGlAccountsModel,
+            SettlementOverrideModel, SettlementResponseModel, SettlementRunModel,
+        },
+        ledger::{self, models::GlEntryModel},
+        savings::services::credit_account,
+    },
+    utils::gen_snow_ids::gen_snowflake_slug,
+};
+
+pub fn settlement_cycle(settlement_date: NaiveDate) -> String {
+    settlement_date.format("%Y-%m-%d").to_string()
+}
+
+// Everything up to the end of the settlement date belongs to the cycle
+fn cycle_cutoff(settlement_date: NaiveDate) -> chrono::DateTime<chrono::Utc> {
+    (settlement_date + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc()
+}
+
+async fn unreconciled_transactions<C: ConnectionTrait>(
+    conn: &C,
+    agent_id: i64,
+    settlement_date: NaiveDate,
+) -> Result<Vec<entity::agent_transactions::Model>, DbErr> {
+    entity::agent_transactions::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agent_transactions::Column::AgentId.eq(agent_id))
+                .add(entity::agent_transactions::Column::Status.eq(AgentTransStatus::Processed))
+                .add(
+                    entity::agent_transactions::Column::TransactionType.is_not_in([
+                        AgentTransType::WalletTopup,
+                        AgentTransType::WalletWithdrawal,
+                    ]),
+                )
+                .add(
+                    Condition::any()
+                        .add(entity::agent_transactions::Column::IsReconciled.eq(false))
+                        .add(entity::agent_transactions::Column::IsReconciled.is_null()),
+                )
+                .add(
+                    entity::agent_transactions::Column::CreatedAt.lt(cycle_cutoff(settlement_date)),
+                ),
+        )
+        .all(conn)
+        .await
+}
+
+async fn mark_transactions_reconciled<C: ConnectionTrait>(
+    conn: &C,
+    agent_id: i64,
+    settlement_date: NaiveDate,
+) -> Result<(), DbErr> {
+    let ids: Vec<i64> = unreconciled_transactions(conn, agent_id, settlement_date)
+        .await?
+        .iter()
+        .map(|transaction| transaction.id)
+        .collect();
+
+    entity::agent_transactions::Entity::update_many()
+        .col_expr(
+            entity::agent_transactions::Column::IsReconciled,
+            Expr::value(true),
+        )
+        .col_expr(
+            entity::agent_transactions::Column::ReconciledAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(entity::agent_transactions::Column::Id.is_in(ids))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+async fn mark_commissions_paid<C: ConnectionTrait>(
+    conn: &C,
+    agent_id: i64,
+    cycle: &str,
+) -> Result<(), DbErr> {
+    entity::agent_commissions::Entity::update_many()
+        .col_expr(
+            entity::agent_commissions::Column::Status,
+            Expr::value(AgentCommissionsStatus::Paid),
+        )
+        .col_expr(
+            entity::agent_commissions::Column::PaidAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .col_expr(
+            entity::agent_commissions::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(
+            Condition::all()
+                .add(entity::agent_commissions::Column::AgentId.eq(agent_id))
+                .add(entity::agent_commissions::Column::SettledCycle.eq(cycle)),
+        )
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+// A settlement that ended below zero carries the shortfall into the next one
+async fn previous_balance<C: ConnectionTrait>(
+    conn: &C,
+    agent_id: i64,
+    cycle: &str,
+) -> Result<i64, DbErr> {
+    let last = entity::agent_settlements::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agent_settlements::Column::AgentId.eq(agent_id))
+                .add(entity::agent_settlements::Column::SettlementCycle.ne(cycle))
+                .add(entity::agent_settlements::Column::Status.ne(AgentSettlementStatus::Failed)),
+        )
+        .order_by_desc(entity::agent_settlements::Column::SettlementDate)
+        .one(conn)
+        .await?;
+
+    Ok(last
+        .map(|settlement| {
+            (settlement.total_commission_earned.unwrap_or(0)
+                + settlement.previous_balance.unwrap_or(0)
+                + settlement.adjustments.unwrap_or(0))
+            .min(0)
+        })
+        .unwrap_or(0))
+}
+
+// Commission paid into a settlement account is expensed against the
+// deposits GL the account sits in
+async fn post_commission_payout<C: ConnectionTrait>(
+    conn: &C,
+    agent: &entity::agents::Model,
+    amount: i64,
+    settlement_date: NaiveDate,
+    reference: &str,
+    posted_by: Option<i64>,
+) -> Result<(), DbErr> {
+    let institution = entity::institutions::Entity::find_by_id(agent.institution_id)
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Institution not found".into()))?;
+
+    let (Some(expense_gl), Some(settlement_gl)) = (
+        institution.agent_commission_expense_gl_account_id,
+        institution.agent_settlement_gl_account_id,
+    ) else {
+        return Err(DbErr::Custom(
+            "Agent commission expense and settlement GL accounts must be configured before settling"
+                .to_string(),
+        ));
+    };
+
+    ledger::services::post(
+        conn,
+        &GlEntryModel {
+            institution_id: agent.institution_id,
+            debit_account_id: expense_gl,
+            credit_account_id: settlement_gl,
+            amount,
+            narration: format!("Commission settlement for agent {}", agent.agent_number),
+            reference_number: Some(reference.to_string()),
+            transaction_id: None,
+            value_date: settlement_date,
+            posted_by,
+        },
+    )
+    .await?;
+
+    Ok(())
+}
+
+// Settles one agent for the cycle. A cycle that already has a pending or
+// processed settlement is returned as is, and a failed one is retried, so
+// re-running a cycle never pays an agent twice
+async fn settle_agent(
+    agent: &entity::agents::Model,
+    settlement_date: NaiveDate,
+    settlement_override: Option<&SettlementOverrideModel>,
+    initiated_by: Option<i64>,
+    state: &web::Data<AppState>,
+) -> Result<Option<i64>, DbErr> {
+    let cycle = settlement_cycle(settlement_date);
+
+    let txn = state.pgdb.begin().await?;
+
+    let existing = entity::agent_settlements::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agent_settlements::Column::AgentId.eq(agent.id))
+                .add(entity::agent_settlements::Column::SettlementCycle.eq(&cycle)),
+        )
+        .lock_exclusive()
+        .one(&txn)
+        .await?;
+
+    if let Some(settlement) = &existing
+        && settlement.status != Some(AgentSettlementStatus::Failed)
+    {
+        return Ok(Some(settlement.id));
+    }
+
+    let commissions = entity::agent_commissions::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agent_commissions::Column::AgentId.eq(agent.id))
+                .add(entity::agent_commissions::Column::Status.eq(AgentCommissionsStatus::Pending))
+                .add(entity::agent_commissions::Column::SettledCycle.is_null())
+                .add(
+                    entity::agent_commissions::Column::CreatedAt.lt(cycle_cutoff(settlement_date)),
+                ),
+        )
+        .all(&txn)
+        .await?;
+
+    let transactions = unreconciled_transactions(&txn, agent.id, settlement_date).await?;
+
+    let adjustments = settlement_override.map(|item| item.adjustment).unwrap_or(0);
+
+    let previous_balance = previous_balance(&txn, agent.id, &cycle).await?;
+
+    if commissions.is_empty() && transactions.is_empty() && adjustments == 0 {
+        return Ok(None);
+    }
+
+    let total_commission: i64 = commissions
+        .iter()
+        .map(|commission| commission.commission_amount)
+        .sum();
+
+    let total_volume: i64 = transactions
+        .iter()
+        .map(|transaction| transaction.amount.unwrap_or(0))
+        .sum();
+
+    let final_amount = (total_commission + previous_balance + adjustments).max(0);
+
+    let channel = settlement_override.and_then(|item| item.channel.clone());
+
+    let (new_id, slug) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    // Payouts to the settlement account happen now; other channels stay
+    // pending until the payout is reconciled
+    let (settlement_account, status) = match &channel {
+        None => {
+            let Some(account_id) = agent.settlement_account_id else {
+                return Err(DbErr::Custom("Agent has no settlement account".to_string()));
+            };
+
+            let account = entity::accounts::Entity::find_by_id(account_id)
+                .one(&txn)
+                .await?
+                .ok_or_else(|| DbErr::RecordNotFound("Settlement account not found".into()))?;
+
+            if final_amount > 0 {
+                credit_account(&txn, account.id, final_amount).await?;
+
+                post_commission_payout(
+                    &txn,
+                    agent,
+                    final_amount,
+                    settlement_date,
+                    &slug,
+                    initiated_by,
+                )
+                .await?;
+            }
+
+            (account.account_number, AgentSettlementStatus::Processed)
+        }
+        Some(_) => {
+            let Some(destination) = settlement_override.and_then(|item| item.destination.clone())
+            else {
+                return Err(DbErr::Custom(
+                    "destination is required for external settlement channels".to_string(),
+                ));
+            };
+
+            (Some(destination), AgentSettlementStatus::Pending)
+        }
+    };
+
+    let is_processed = status == AgentSettlementStatus::Processed;
+
+    let id = existing
+        .as_ref()
+        .map(|settlement| settlement.id)
+        .unwrap_or(new_id);
+
+    let settlement = entity::agent_settlements::ActiveModel {
+        id: Set(id),
+        institution_id: Set(agent.institution_id),
+        agent_id: Set(agent.id),
+        settlement_cycle: Set(cycle.clone()),
+        settlement_date: Set(settlement_date),
+        total_transactions: Set(Some(i32::try_from(transactions.len()).unwrap_or(i32::MAX))),
+        total_transaction_volume: Set(Some(total_volume)),
+        total_commission_earned: Set(Some(total_commission)),
+        previous_balance: Set(Some(previous_balance)),
+        adjustments: Set(Some(adjustments)),
+        final_settlement_amount: Set(final_amount),
+        settlement_account: Set(settlement_account),
+        settlement_reference: Set(Some(slug)),
+        settlement_channel: Set(channel),
+        status: Set(Some(status.clone())),
+        initiated_at: Set(Some(chrono::Utc::now().into())),
+        processed_at: Set(is_processed.then(|| chrono::Utc::now().into())),
+        initiated_by: Set(initiated_by),
+        reconciliation_status: Set(Some(status)),
+        reconciled_at: Set(is_processed.then(|| chrono::Utc::now().into())),
+        updated_at: Set(Some(chrono::Utc::now().into())),
+        ..Default::default()
+    };
+
+    if existing.is_some() {
+        settlement.update(&txn).await?;
+    } else {
+        settlement.insert(&txn).await?;
+    }
+
+    let commission_ids: Vec<i64> = commissions.iter().map(|commission| commission.id).collect();
+
+    entity::agent_commissions::Entity::update_many()
+        .col_expr(
+            entity::agent_commissions::Column::SettledCycle,
+            Expr::value(cycle.clone()),
+        )
+        .col_expr(
+            entity::agent_commissions::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(entity::agent_commissions::Column::Id.is_in(commission_ids))
+        .exec(&txn)
+        .await?;
+
+    if is_processed {
+        mark_commissions_paid(&txn, agent.id, &cycle).await?;
+        mark_transactions_reconciled(&txn, agent.id, settlement_date).await?;
+    }
+
+    txn.commit().await?;
+
+    Ok(Some(id))
+}
+
+pub async fn run_settlement(
+    model: &RunSettlementModel,
+    state: &web::Data<AppState>,
+) -> Result<SettlementRunModel, DbErr> {
+    let data = model.clone();
+
+    let agents = entity::agents::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agents::Column::InstitutionId.eq(data.institution_id))
+                .add(
+                    entity::agents::Column::Status
+                        .is_in([AgentStatus::Active, AgentStatus::Suspended]),
+                ),
+        )
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let mut ids = Vec::new();
+    let mut failed = 0;
+
+    for agent in &agents {
+        let settlement_override = data.overrides.iter().find(|item| item.agent_id == agent.id);
+
+        match settle_agent(
+            agent,
+            data.settlement_date,
+            settlement_override,
+            data.initiated_by,
+            state,
+        )
+        .await
+        {
+            Ok(Some(id)) => ids.push(id),
+            Ok(None) => {}
+            Err(err) => {
+                failed += 1;
+
+                log::warn!(
+                    "Settlement for agent {} on {} failed: {}",
+                    agent.id,
+                    data.settlement_date,
+                    err
+                );
+            }
+        }
+    }
+
+    let settlements = entity::agent_settlements::Entity::find()
+        .filter(entity::agent_settlements::Column::Id.is_in(ids))
+        .order_by_asc(entity::agent_settlements::Column::AgentId)
+        .into_model::<SettlementResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(SettlementRunModel {
+        settlement_cycle: settlement_cycle(data.settlement_date),
+        settlements,
+        failed,
+    })
+}
+
+// Confirms or fails an external payout; a failed payout releases its
+// commissions so the next run picks them up again
+pub async fn reconcile_settlement(
+    settlement_id: &i64,
+    model: &ReconcileSettlementModel,
+    state: &web::Data<AppState>,
+) -> Result<SettlementResponseModel, DbErr> {
+    let data = model.clone();
+
+    if data.status == AgentSettlementStatus::Pending {
+        return Err(DbErr::Custom(
+            "status must be PROCESSED or FAILED".to_string(),
+        ));
+    }
+
+    let txn = state.pgdb.begin().await?;
+
+    let settlement = entity::agent_settlements::Entity::find_by_id(*settlement_id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Settlement not found".into()))?;
+
+    if settlement.status != Some(AgentSettlementStatus::Pending) {
+        return Err(DbErr::Custom(
+            "Only pending settlements can be reconciled".to_string(),
+        ));
+    }
+
+    let agent_id = settlement.agent_id;
+    let cycle = settlement.settlement_cycle.clone();
+    let settlement_date = settlement.settlement_date;
+
+    let mut active_settlement: entity::agent_settlements::ActiveModel = settlement.into();
+
+    if let Some(reference) = data.settlement_reference {
+        active_settlement.settlement_reference = Set(Some(reference));
+    }
+
+    if data.status == AgentSettlementStatus::Processed {
+        active_settlement.processed_at = Set(Some(chrono::Utc::now().into()));
+
+        mark_commissions_paid(&txn, agent_id, &cycle).await?;
+        mark_transactions_reconciled(&txn, agent_id, settlement_date).await?;
+    } else {
+        entity::agent_commissions::Entity::update_many()
+            .col_expr(
+                entity::agent_commissions::Column::SettledCycle,
+                Expr::value(Option::<String>::None),
+            )
+            .col_expr(
+                entity::agent_commissions::Column::UpdatedAt,
+                Expr::value(chrono::Utc::now()),
+            )
+            .filter(
+                Condition::all()
+                    .add(entity::agent_commissions::Column::AgentId.eq(agent_id))
+                    .add(entity::agent_commissions::Column::SettledCycle.eq(&cycle))
+                    .add(
+                        entity::agent_commissions::Column::Status
+                            .eq(AgentCommissionsStatus::Pending),
+                    ),
+            )
+            .exec(&txn)
+            .await?;
+    }
+
+    active_settlement.status = Set(Some(data.status.clone()));
+    active_settlement.reconciliation_status = Set(Some(data.status));
+    active_settlement.reconciled_at = Set(Some(chrono::Utc::now().into()));
+    active_settlement.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_settlement, &txn).await?;
+
+    txn.commit().await?;
+
+    entity::agent_settlements::Entity::find_by_id(*settlement_id)
+        .into_model::<SettlementResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Settlement not found".into()))
+}
+
+pub async fn get_settlement(
+    settlement_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<SettlementResponseModel, DbErr> {
+    entity::agent_settlements::Entity::find_by_id(*settlement_id)
+        .into_model::<SettlementResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Settlement not found".into()))
+}
+
+pub async fn get_agent_settlements(
+    agent_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<SettlementResponseModel>, DbErr> {
+    let settlements = entity::agent_settlements::Entity::find()
+        .filter(entity::agent_settlements::Column::AgentId.eq(*agent_id))
+        .order_by_desc(entity::agent_settlements::Column::SettlementDate)
+        .into_model::<SettlementResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(settlements)
+}
+
+// Settles the previous day for every institution with agents
+pub async fn run_daily_settlements(state: &web::Data<AppState>) -> Result<u64, DbErr> {
+    let settlement_date = chrono::Utc::now().date_naive() - Duration::days(1);
+
+    let institution_ids: Vec<i64> = entity::agents::Entity::find()
+        .select_only()
+        .column(entity::agents::Column::InstitutionId)
+        .filter(entity::agents::Column::Status.is_in([AgentStatus::Active, AgentStatus::Suspended]))
+        .distinct()
+        .into_tuple()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let mut settled = 0;
+
+    for institution_id in institution_ids {
+        let run = run_settlement(
+            &RunSettlementModel {
+                institution_id,
+                settlement_date,
+                overrides: Vec::new(),
+                initiated_by: None,
+            },
+            state,
+        )
+        .await?;
+
+        settled += run.settlements.len() as u64;
+    }
+
+    Ok(settled)
+}
+
+pub async fn set_gl_accounts(
+    institution_id: &i64,
+    model: &SettlementGlAccountsModel,
+    state: &web::Data<AppState>,
+) -> Result<SettlementGlAccountsModel, DbErr> {
+    let data = model.clone();
+
+    let institution = entity::institutions::Entity::find_by_id(*institution_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Institution not found".into()))?;
+
+    let mut active_institution: entity::institutions::ActiveModel = institution.into();
+
+    active_institution.agent_commission_expense_gl_account_id =
+        Set(Some(data.commission_expense_gl_account_id));
+    active_institution.agent_settlement_gl_account_id = Set(Some(data.settlement_gl_account_id));
+    active_institution.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_institution, state.pgdb.get_ref()).await?;
+
+    Ok(data)
+}
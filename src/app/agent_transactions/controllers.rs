@@ -0,0 +1,186 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use entity::sea_orm_active_enums::AgentTransType;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::agent_transactions::{
+        models::{
+            CashTransactionModel, CashTransactionParams, GeoPointModel, OpenWalletModel,
+            OpenWalletParams, TopUpParams,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn open_wallet(
+    req: HttpRequest,
+    payload: web::Json<OpenWalletParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_WALLET_MANAGE", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let agent_id = id_parser(&data.agent_id, "Agent Id").await?;
+
+    let wallet = OpenWalletModel {
+        agent_id,
+        wallet_type: data.wallet_type,
+        min_balance: data.min_balance.unwrap_or(0),
+        max_balance: data.max_balance,
+    };
+
+    match services::open_wallet(&wallet, &state).await {
+        Ok(wallet) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Wallet Opened",
+            wallet,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn top_up_wallet(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<TopUpParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_WALLET_MANAGE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Wallet Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    match services::top_up_wallet(&id, &data.amount, &state).await {
+        Ok(transaction) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Wallet Topped Up",
+            transaction,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+async fn cash_transaction(
+    transaction_type: AgentTransType,
+    payload: web::Json<CashTransactionParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let agent_id = id_parser(&data.agent_id, "Agent Id").await?;
+    let account_id = id_parser(&data.account_id, "Account Id").await?;
+
+    let transaction = CashTransactionModel {
+        agent_id,
+        account_id,
+        amount: data.amount,
+        location: GeoPointModel {
+            latitude: data.latitude,
+            longitude: data.longitude,
+            accuracy: data.geo_accuracy,
+            address: data.address,
+        },
+    };
+
+    match services::cash_transaction(transaction_type, &transaction, &state).await {
+        Ok(transaction) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Transaction Processed",
+            transaction,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn cash_in(
+    req: HttpRequest,
+    payload: web::Json<CashTransactionParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_CASH_IN", &state).await?;
+
+    cash_transaction(AgentTransType::CashDeposit, payload, state).await
+}
+
+pub async fn cash_out(
+    req: HttpRequest,
+    payload: web::Json<CashTransactionParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_CASH_OUT", &state).await?;
+
+    cash_transaction(AgentTransType::CashWithdrawal, payload, state).await
+}
+
+pub async fn agent_wallets(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Agent Id").await?;
+
+    match services::get_wallets(&id, &state).await {
+        Ok(wallets) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            wallets,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn agent_transactions(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Agent Id").await?;
+
+    match services::get_transactions(&id, &state).await {
+        Ok(transactions) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            transactions,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
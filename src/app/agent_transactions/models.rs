@@ -0,0 +1,157 @@
+use chrono::{DateTime, FixedOffset};
+use entity::sea_orm_active_enums::{
+    AgentTransStatus, AgentTransType, AgentWalletStatus, AgentWalletType,
+};
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[derive(Debug, Clone)]
+pub struct OpenWalletModel {
+    pub agent_id: i64,
+    pub wallet_type: AgentWalletType,
+    pub min_balance: i64,
+    pub max_balance: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GeoPointModel {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub accuracy: Option<Decimal>,
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CashTransactionModel {
+    pub agent_id: i64,
+    pub account_id: i64,
+    pub amount: i64,
+    pub location: GeoPointModel,
+}
+
+// One row in agent_transactions; customer details and location are only
+// present for customer-facing cash transactions
+#[derive(Debug, Clone)]
+pub struct PostTransactionModel {
+    pub agent_wallet_id: i64,
+    pub transaction_type: AgentTransType,
+    pub amount: i64,
+    pub customer_phone: Option<String>,
+    pub customer_account: Option<String>,
+    pub location: Option<GeoPointModel>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct OpenWalletParams {
+    #[serde(rename = "agentId")]
+    pub agent_id: String,
+    #[serde(rename = "walletType")]
+    pub wallet_type: AgentWalletType,
+    #[validate(range(min = 0, message = "minBalance cannot be negative"))]
+    #[serde(rename = "minBalance")]
+    pub min_balance: Option<i64>,
+    #[validate(range(min = 1, message = "maxBalance must be greater than 0"))]
+    #[serde(rename = "maxBalance")]
+    pub max_balance: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CashTransactionParams {
+    #[serde(rename = "agentId")]
+    pub agent_id: String,
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+    #[validate(range(min = 1, message = "amount must be greater than 0"))]
+    pub amount: i64,
+    #[validate(range(min = -90.0, max = 90.0, message = "latitude cannot be < -90 and > 90"))]
+    pub latitude: f64,
+    #[validate(range(
+        min = -180.0,
+        max = 180.0,
+        message = "longitude cannot be < -180 and > 180"
+    ))]
+    pub longitude: f64,
+    #[serde(rename = "geoAccuracy")]
+    pub geo_accuracy: Option<Decimal>,
+    #[validate(length(min = 2, max = 250, message = "address cannot be < 2 and > 250"))]
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct TopUpParams {
+    #[validate(range(min = 1, message = "amount must be greater than 0"))]
+    pub amount: i64,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::agent_wallets::Entity")]
+pub struct WalletResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "agent_id")]
+    pub agent_id: i64,
+    #[sea_orm(from_col = "wallet_type")]
+    pub wallet_type: Option<AgentWalletType>,
+    #[sea_orm(from_col = "currency")]
+    pub currency: Option<Value>,
+    #[sea_orm(from_col = "current_balance")]
+    pub current_balance: Option<i64>,
+    #[sea_orm(from_col = "available_balance")]
+    pub available_balance: Option<i64>,
+    #[sea_orm(from_col = "min_balance")]
+    pub min_balance: Option<i64>,
+    #[sea_orm(from_col = "max_balance")]
+    pub max_balance: Option<i64>,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<AgentWalletStatus>,
+    #[sea_orm(from_col = "last_funded_at")]
+    pub last_funded_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::agent_transactions::Entity")]
+pub struct AgentTransactionResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "agent_id")]
+    pub agent_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "agent_wallet_id")]
+    pub agent_wallet_id: i64,
+    #[sea_orm(from_col = "transaction_type")]
+    pub transaction_type: AgentTransType,
+    #[sea_orm(from_col = "amount")]
+    pub amount: Option<i64>,
+    #[sea_orm(from_col = "commission_earned")]
+    pub commission_earned: Option<i64>,
+    #[sea_orm(from_col = "customer_phone")]
+    pub customer_phone: Option<String>,
+    #[sea_orm(from_col = "customer_account")]
+    pub customer_account: Option<String>,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<AgentTransStatus>,
+    #[sea_orm(from_col = "transaction_address")]
+    pub transaction_address: Option<Value>,
+    #[sea_orm(from_col = "geo_accuracy")]
+    pub geo_accuracy: Option<Decimal>,
+    #[sea_orm(from_col = "posted_at")]
+    pub posted_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
@@ -0,0 +1,43 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::agent_transactions::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/agent-transactions")
+            .route(
+                "/wallets",
+                web::post()
+                    .to(controllers::open_wallet)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/wallets/{id}/top-up",
+                web::post()
+                    .to(controllers::top_up_wallet)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/cash-in",
+                web::post().to(controllers::cash_in).wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/cash-out",
+                web::post()
+                    .to(controllers::cash_out)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/agents/{id}/wallets",
+                web::get()
+                    .to(controllers::agent_wallets)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/agents/{id}",
+                web::get()
+                    .to(controllers::agent_transactions)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
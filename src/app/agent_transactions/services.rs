@@ -0,0 +1,548 @@
+use actix_web::web;
+use entity::sea_orm_active_enums::{
+    AccTypeStatus, AgentStatus, AgentTransStatus, AgentTransType, AgentWalletStatus,
+    AgentWalletType, AmlAlertsStatus, AmlRiskLevelEnum,
+};
+use migration::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, TransactionTrait,
+};
+use serde_json::json;
+
+use crate::{
+    AppState,
+    app::{
+        agent_commissions::services::apply_commission,
+        agent_transactions::models::{
+            AgentTransactionResponseModel, CashTransactionModel, GeoPointModel, OpenWalletModel,
+            PostTransactionModel, WalletResponseModel,
+        },
+        agents::{
+            models::GeofenceBreachModel,
+            services::{GEOFENCE_REJECT, find_agent, geofence_action, geofence_breach},
+        },
+        savings::services::{credit_account, debit_account},
+    },
+    utils::gen_snow_ids::gen_snowflake_slug,
+};
+
+fn ensure_agent_active(agent: &entity::agents::Model) -> Result<(), DbErr> {
+    if agent.status != Some(AgentStatus::Active) {
+        return Err(DbErr::Custom("Agent is not active".to_string()));
+    }
+
+    Ok(())
+}
+
+pub async fn open_wallet(
+    model: &OpenWalletModel,
+    state: &web::Data<AppState>,
+) -> Result<WalletResponseModel, DbErr> {
+    let data = model.clone();
+
+    if let Some(max) = data.max_balance
+        && data.min_balance > max
+    {
+        return Err(DbErr::Custom(
+            "minBalance cannot be greater than maxBalance".to_string(),
+        ));
+    }
+
+    let agent = find_agent(state.pgdb.get_ref(), data.agent_id).await?;
+
+    ensure_agent_active(&agent)?;
+
+    let existing = entity::agent_wallets::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agent_wallets::Column::AgentId.eq(agent.id))
+                .add(entity::agent_wallets::Column::WalletType.eq(data.wallet_type.clone())),
+        )
+        .one(state.pgdb.get_ref())
+        .await?;
+
+    if existing.is_some() {
+        return Err(DbErr::Custom(
+            "Agent already has a wallet of this type".to_string(),
+        ));
+    }
+
+    // Wallets carry the currency of the agent's settlement account
+    let currency = match agent.settlement_account_id {
+        Some(account_id) => entity::accounts::Entity::find_by_id(account_id)
+            .one(state.pgdb.get_ref())
+            .await?
+            .and_then(|account| account.currency),
+        None => None,
+    };
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::agent_wallets::ActiveModel {
+        id: Set(id),
+        institution_id: Set(agent.institution_id),
+        agent_id: Set(agent.id),
+        wallet_type: Set(Some(data.wallet_type)),
+        currency: Set(currency),
+        current_balance: Set(Some(0)),
+        available_balance: Set(Some(0)),
+        min_balance: Set(Some(data.min_balance)),
+        max_balance: Set(data.max_balance),
+        status: Set(Some(AgentWalletStatus::Active)),
+        ..Default::default()
+    }
+    .insert(state.pgdb.get_ref())
+    .await?;
+
+    entity::agent_wallets::Entity::find_by_id(id)
+        .into_model::<WalletResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Agent wallet not found".into()))
+}
+
+pub async fn float_wallet<C: ConnectionTrait>(
+    conn: &C,
+    agent_id: i64,
+) -> Result<entity::agent_wallets::Model, DbErr> {
+    entity::agent_wallets::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agent_wallets::Column::AgentId.eq(agent_id))
+                .add(entity::agent_wallets::Column::WalletType.eq(AgentWalletType::Float))
+                .add(entity::agent_wallets::Column::Status.eq(AgentWalletStatus::Active)),
+        )
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::Custom("Agent has no active float wallet".to_string()))
+}
+
+// Debits only while the wallet stays at or above its minimum balance
+pub async fn debit_wallet<C: ConnectionTrait>(
+    conn: &C,
+    wallet: &entity::agent_wallets::Model,
+    amount: i64,
+) -> Result<(), DbErr> {
+    let floor = wallet.min_balance.unwrap_or(0) + amount;
+
+    let result = entity::agent_wallets::Entity::update_many()
+        .col_expr(
+            entity::agent_wallets::Column::CurrentBalance,
+            Expr::col(entity::agent_wallets::Column::CurrentBalance)
+                .if_null(0)
+                .sub(amount),
+        )
+        .col_expr(
+            entity::agent_wallets::Column::AvailableBalance,
+            Expr::col(entity::agent_wallets::Column::AvailableBalance)
+                .if_null(0)
+                .sub(amount),
+        )
+        .col_expr(
+            entity::agent_wallets::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(
+            Condition::all()
+                .add(entity::agent_wallets::Column::Id.eq(wallet.id))
+                .add(entity::agent_wallets::Column::Status.eq(AgentWalletStatus::Active))
+                .add(entity::agent_wallets::Column::AvailableBalance.gte(floor)),
+        )
+        .exec(conn)
+        .await?;
+
+    if result.rows_affected == 0 {
+        return Err(DbErr::Custom(
+            "Insufficient float, wallet would fall below its minimum balance".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+// Credits only while the wallet stays at or below its maximum balance
+pub async fn credit_wallet<C: ConnectionTrait>(
+    conn: &C,
+    wallet: &entity::agent_wallets::Model,
+    amount: i64,
+) -> Result<(), DbErr> {
+    let mut condition = Condition::all()
+        .add(entity::agent_wallets::Column::Id.eq(wallet.id))
+        .add(entity::agent_wallets::Column::Status.eq(AgentWalletStatus::Active));
+
+    if let Some(max) = wallet.max_balance {
+        condition =
+            condition.add(entity::agent_wallets::Column::AvailableBalance.lte(max - amount));
+    }
+
+    let result = entity::agent_wallets::Entity::update_many()
+        .col_expr(
+            entity::agent_wallets::Column::CurrentBalance,
+            Expr::col(entity::agent_wallets::Column::CurrentBalance)
+                .if_null(0)
+                .add(amount),
+        )
+        .col_expr(
+            entity::agent_wallets::Column::AvailableBalance,
+            Expr::col(entity::agent_wallets::Column::AvailableBalance)
+                .if_null(0)
+                .add(amount),
+        )
+        .col_expr(
+            entity::agent_wallets::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(condition)
+        .exec(conn)
+        .await?;
+
+    if result.rows_affected == 0 {
+        return Err(DbErr::Custom(
+            "Wallet would exceed its maximum balance".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+// Records a processed agent transaction with the commission it earned;
+// customer-facing ones also count towards the agent's daily volume and
+// transaction count
+pub async fn post_transaction<C: ConnectionTrait>(
+    conn: &C,
+    agent: &entity::agents::Model,
+    model: &PostTransactionModel,
+) -> Result<i64, DbErr> {
+    let data = model.clone();
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let commission_earned =
+        apply_commission(conn, agent, id, &data.transaction_type, data.amount).await?;
+
+    let is_customer_facing = matches!(
+        data.transaction_type,
+        AgentTransType::CashDeposit | AgentTransType::CashWithdrawal
+    );
+
+    let (transaction_address, geo_accuracy) = match data.location {
+        Some(location) => (
+            Some(json!({
+                "latitude": location.latitude,
+                "longitude": location.longitude,
+                "address": location.address,
+            })),
+            location.accuracy,
+        ),
+        None => (None, None),
+    };
+
+    entity::agent_transactions::ActiveModel {
+        id: Set(id),
+        institution_id: Set(agent.institution_id),
+        agent_id: Set(agent.id),
+        agent_wallet_id: Set(data.agent_wallet_id),
+        transaction_type: Set(data.transaction_type),
+        amount: Set(Some(data.amount)),
+        commission_earned: Set(Some(commission_earned)),
+        customer_phone: Set(data.customer_phone),
+        customer_account: Set(data.customer_account),
+        status: Set(Some(AgentTransStatus::Processed)),
+        posted_at: Set(Some(chrono::Utc::now().into())),
+        confirmed_at: Set(Some(chrono::Utc::now().into())),
+        transaction_address: Set(transaction_address),
+        geo_accuracy: Set(geo_accuracy),
+        is_reconciled: Set(Some(false)),
+        ..Default::default()
+    }
+    .insert(conn)
+    .await?;
+
+    if is_customer_facing {
+        entity::agents::Entity::update_many()
+            .col_expr(
+                entity::agents::Column::TransactionVolumeToday,
+                Expr::col(entity::agents::Column::TransactionVolumeToday)
+                    .if_null(0)
+                    .add(data.amount),
+            )
+            .col_expr(
+                entity::agents::Column::TransactionCountToday,
+                Expr::col(entity::agents::Column::TransactionCountToday)
+                    .if_null(0)
+                    .add(1),
+            )
+            .col_expr(
+                entity::agents::Column::LastTransactionAt,
+                Expr::value(chrono::Utc::now()),
+            )
+            .filter(entity::agents::Column::Id.eq(agent.id))
+            .exec(conn)
+            .await?;
+    }
+
+    Ok(id)
+}
+
+// Moves funds from the agent's settlement account into its float wallet
+pub async fn top_up_wallet(
+    wallet_id: &i64,
+    amount: &i64,
+    state: &web::Data<AppState>,
+) -> Result<AgentTransactionResponseModel, DbErr> {
+    let txn = state.pgdb.begin().await?;
+
+    let wallet = entity::agent_wallets::Entity::find_by_id(*wallet_id)
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Agent wallet not found".into()))?;
+
+    if wallet.wallet_type != Some(AgentWalletType::Float) {
+        return Err(DbErr::Custom(
+            "Only float wallets can be topped up".to_string(),
+        ));
+    }
+
+    let agent = find_agent(&txn, wallet.agent_id).await?;
+
+    ensure_agent_active(&agent)?;
+
+    let Some(settlement_account_id) = agent.settlement_account_id else {
+        return Err(DbErr::Custom("Agent has no settlement account".to_string()));
+    };
+
+    debit_account(&txn, settlement_account_id, *amount).await?;
+
+    credit_wallet(&txn, &wallet, *amount).await?;
+
+    entity::agent_wallets::Entity::update_many()
+        .col_expr(
+            entity::agent_wallets::Column::LastFundedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(entity::agent_wallets::Column::Id.eq(wallet.id))
+        .exec(&txn)
+        .await?;
+
+    let id = post_transaction(
+        &txn,
+        &agent,
+        &PostTransactionModel {
+            agent_wallet_id: wallet.id,
+            transaction_type: AgentTransType::WalletTopup,
+            amount: *amount,
+            customer_phone: None,
+            customer_account: None,
+            location: None,
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    entity::agent_transactions::Entity::find_by_id(id)
+        .into_model::<AgentTransactionResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Agent transaction not found".into()))
+}
+
+async fn customer_account<C: ConnectionTrait>(
+    conn: &C,
+    agent: &entity::agents::Model,
+    account_id: i64,
+) -> Result<(entity::accounts::Model, entity::customers::Model), DbErr> {
+    let account = entity::accounts::Entity::find_by_id(account_id)
+        .filter(
+            Condition::all()
+                .add(entity::accounts::Column::InstitutionId.eq(agent.institution_id))
+                .add(entity::accounts::Column::Status.eq(AccTypeStatus::Active)),
+        )
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::Custom("Account not found or not active".to_string()))?;
+
+    let customer = entity::customers::Entity::find_by_id(account.customer_id)
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Customer not found".into()))?;
+
+    Ok((account, customer))
+}
+
+fn customer_phone(customer: &entity::customers::Model) -> Option<String> {
+    customer.phone_number.as_ref().map(|number| {
+        format!(
+            "{}{}",
+            customer.phone_country_code.clone().unwrap_or_default(),
+            number
+        )
+    })
+}
+
+// Raises an AML alert for a transaction the agent performed outside its geofence
+async fn flag_geofence_breach<C: ConnectionTrait>(
+    conn: &C,
+    agent: &entity::agents::Model,
+    agent_transaction_id: i64,
+    account: &entity::accounts::Model,
+    location: &GeoPointModel,
+    breach: &GeofenceBreachModel,
+) -> Result<(), DbErr> {
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::aml_alerts::ActiveModel {
+        id: Set(id),
+        institution_id: Set(agent.institution_id),
+        alert_type: Set("AGENT_GEOFENCE_BREACH".to_string()),
+        risk_level: Set(Some(AmlRiskLevelEnum::Medium)),
+        customer_id: Set(Some(account.customer_id)),
+        account_id: Set(Some(account.id)),
+        alert_details: Set(json!({
+            "agentId": agent.id.to_string(),
+            "agentTransactionId": agent_transaction_id.to_string(),
+            "latitude": location.latitude,
+            "longitude": location.longitude,
+            "fenceLatitude": breach.latitude,
+            "fenceLongitude": breach.longitude,
+            "radiusMeters": breach.radius_meters,
+            "distanceMeters": breach.distance_meters.round(),
+        })),
+        status: Set(Some(AmlAlertsStatus::Open)),
+        ..Default::default()
+    }
+    .insert(conn)
+    .await?;
+
+    Ok(())
+}
+
+// Cash-in: the agent takes cash, its float pays into the customer account.
+// Cash-out: the customer account pays into the float, the agent hands over cash
+pub async fn cash_transaction(
+    transaction_type: AgentTransType,
+    model: &CashTransactionModel,
+    state: &web::Data<AppState>,
+) -> Result<AgentTransactionResponseModel, DbErr> {
+    let data = model.clone();
+
+    let txn = state.pgdb.begin().await?;
+
+    let agent = find_agent(&txn, data.agent_id).await?;
+
+    ensure_agent_active(&agent)?;
+
+    let breach = geofence_breach(&agent, (data.location.latitude, data.location.longitude));
+
+    if breach.is_some() && geofence_action(&txn, agent.institution_id).await? == GEOFENCE_REJECT {
+        return Err(DbErr::Custom(
+            "Transaction location is outside the agent's geofence".to_string(),
+        ));
+    }
+
+    let wallet = float_wallet(&txn, agent.id).await?;
+
+    let (account, customer) = customer_account(&txn, &agent, data.account_id).await?;
+
+    match transaction_type {
+        AgentTransType::CashDeposit => {
+            debit_wallet(&txn, &wallet, data.amount).await?;
+            credit_account(&txn, account.id, data.amount).await?;
+        }
+        AgentTransType::CashWithdrawal => {
+            debit_account(&txn, account.id, data.amount).await?;
+            credit_wallet(&txn, &wallet, data.amount).await?;
+        }
+        _ => {
+            return Err(DbErr::Custom(
+                "Unsupported agent cash transaction".to_string(),
+            ));
+        }
+    }
+
+    let id = post_transaction(
+        &txn,
+        &agent,
+        &PostTransactionModel {
+            agent_wallet_id: wallet.id,
+            transaction_type,
+            amount: data.amount,
+            customer_phone: customer_phone(&customer),
+            customer_account: account.account_number.clone(),
+            location: Some(data.location.clone()),
+        },
+    )
+    .await?;
+
+    if let Some(breach) = breach {
+        flag_geofence_breach(&txn, &agent, id, &account, &data.location, &breach).await?;
+    }
+
+    txn.commit().await?;
+
+    entity::agent_transactions::Entity::find_by_id(id)
+        .into_model::<AgentTransactionResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Agent transaction not found".into()))
+}
+
+pub async fn get_wallets(
+    agent_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<WalletResponseModel>, DbErr> {
+    let wallets = entity::agent_wallets::Entity::find()
+        .filter(entity::agent_wallets::Column::AgentId.eq(*agent_id))
+        .order_by_asc(entity::agent_wallets::Column::CreatedAt)
+        .into_model::<WalletResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(wallets)
+}
+
+pub async fn get_transactions(
+    agent_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<AgentTransactionResponseModel>, DbErr> {
+    let transactions = entity::agent_transactions::Entity::find()
+        .filter(entity::agent_transactions::Column::AgentId.eq(*agent_id))
+        .order_by_desc(entity::agent_transactions::Column::CreatedAt)
+        .into_model::<AgentTransactionResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(transactions)
+}
+
+// Starts every agent's day with zero volume and count
+pub async fn reset_daily_counters(state: &web::Data<AppState>) -> Result<u64, DbErr> {
+    let result = entity::agents::Entity::update_many()
+        .col_expr(
+            entity::agents::Column::TransactionVolumeToday,
+            Expr::value(0),
+        )
+        .col_expr(
+            entity::agents::Column::TransactionCountToday,
+            Expr::value(0),
+        )
+        .filter(
+            Condition::any()
+                .add(entity::agents::Column::TransactionVolumeToday.ne(0))
+                .add(entity::agents::Column::TransactionCountToday.ne(0)),
+        )
+        .exec(state.pgdb.get_ref())
+        .await?;
+
+    Ok(result.rows_affected)
+}
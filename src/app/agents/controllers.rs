@@ -0,0 +1,282 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::agents::{
+        models::{
+            GeofenceActionParams, GeofenceModel, GeofenceParams, OnboardAgentModel,
+            OnboardAgentParams, RejectDocumentParams, ReviewDocumentModel, UploadDocumentModel,
+            UploadDocumentParams,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn onboard_agent(
+    req: HttpRequest,
+    payload: web::Json<OnboardAgentParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_ONBOARD", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let branch_id = id_parser(&data.branch_id, "Branch Id").await?;
+    let customer_id = id_parser(&data.customer_id, "Customer Id").await?;
+
+    let agent = OnboardAgentModel {
+        branch_id,
+        customer_id,
+        agent_name: data.agent_name,
+        business_name: data.business_name,
+        entity_type: data.entity_type,
+        business_registration_number: data.business_registration_number,
+        phone_country_code: data.phone_country_code,
+        phone_number: data.phone_number,
+        email: data.email,
+        operating_address: data.operating_address,
+        business_address: data.business_address,
+    };
+
+    match services::onboard_agent(&agent, &state).await {
+        Ok(agent) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Agent Onboarded",
+            agent,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn upload_document(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<UploadDocumentParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_KYC_UPLOAD", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Agent Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let document = UploadDocumentModel {
+        document_type: data.document_type,
+        document_url: data.document_url,
+    };
+
+    match services::upload_document(&id, &document, &state).await {
+        Ok(document) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Document Uploaded",
+            document,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn verify_document(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "AGENT_KYC_VERIFY", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Document Id").await?;
+
+    let review = ReviewDocumentModel {
+        reviewed_by: staff.id,
+        rejection_reason: None,
+    };
+
+    match services::review_document(&id, &review, &state).await {
+        Ok(document) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Document Verified",
+            document,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn reject_document(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<RejectDocumentParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "AGENT_KYC_VERIFY", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Document Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let review = ReviewDocumentModel {
+        reviewed_by: staff.id,
+        rejection_reason: Some(data.reason),
+    };
+
+    match services::review_document(&id, &review, &state).await {
+        Ok(document) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Document Rejected",
+            document,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn activate_agent(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "AGENT_ACTIVATE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Agent Id").await?;
+
+    match services::activate_agent(&id, &staff.id, &state).await {
+        Ok(agent) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Agent Activated",
+            agent,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn set_geofence(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<GeofenceParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_GEOFENCE_MANAGE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Agent Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let geofence = GeofenceModel {
+        latitude: data.latitude,
+        longitude: data.longitude,
+        radius_meters: data.radius_meters,
+    };
+
+    match services::set_geofence(&id, &geofence, &state).await {
+        Ok(agent) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Agent Geofence Updated",
+            agent,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn set_geofence_action(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<GeofenceActionParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "AGENT_GEOFENCE_MANAGE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Institution Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    match services::set_geofence_action(&id, &data.action, &state).await {
+        Ok(action) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Geofence Action Updated",
+            action,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn agent_details(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Agent Id").await?;
+
+    match services::get_agent(&id, &state).await {
+        Ok(agent) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            agent,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
@@ -0,0 +1,217 @@
+use chrono::{DateTime, FixedOffset};
+use entity::sea_orm_active_enums::{
+    AgentEntityType, AgentKycDocsDoctype, AgentStatus, VerificationStatusType,
+};
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[derive(Debug, Clone)]
+pub struct OnboardAgentModel {
+    pub branch_id: i64,
+    pub customer_id: i64,
+    pub agent_name: String,
+    pub business_name: Option<String>,
+    pub entity_type: AgentEntityType,
+    pub business_registration_number: Option<String>,
+    pub phone_country_code: String,
+    pub phone_number: String,
+    pub email: Option<String>,
+    pub operating_address: Value,
+    pub business_address: Option<Value>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadDocumentModel {
+    pub document_type: AgentKycDocsDoctype,
+    pub document_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReviewDocumentModel {
+    pub reviewed_by: i64,
+    pub rejection_reason: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GeofenceModel {
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub radius_meters: f64,
+}
+
+// How far a transaction was from the agent's fence centre
+#[derive(Debug, Clone)]
+pub struct GeofenceBreachModel {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub radius_meters: f64,
+    pub distance_meters: f64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct OnboardAgentParams {
+    #[serde(rename = "branchId")]
+    pub branch_id: String,
+    #[serde(rename = "customerId")]
+    pub customer_id: String,
+    #[validate(length(min = 2, max = 100, message = "agentName cannot be < 2 and > 100"))]
+    #[serde(rename = "agentName")]
+    pub agent_name: String,
+    #[validate(length(min = 2, max = 150, message = "businessName cannot be < 2 and > 150"))]
+    #[serde(rename = "businessName")]
+    pub business_name: Option<String>,
+    #[serde(rename = "entityType")]
+    pub entity_type: AgentEntityType,
+    #[validate(length(
+        min = 2,
+        max = 50,
+        message = "businessRegistrationNumber cannot be < 2 and > 50"
+    ))]
+    #[serde(rename = "businessRegistrationNumber")]
+    pub business_registration_number: Option<String>,
+    #[validate(length(min = 1, max = 5, message = "phoneCountryCode cannot be < 1 and > 5"))]
+    #[serde(rename = "phoneCountryCode")]
+    pub phone_country_code: String,
+    #[validate(length(min = 6, max = 15, message = "phoneNumber cannot be < 6 and > 15"))]
+    #[serde(rename = "phoneNumber")]
+    pub phone_number: String,
+    #[validate(email)]
+    pub email: Option<String>,
+    #[serde(rename = "operatingAddress")]
+    pub operating_address: Value,
+    #[serde(rename = "businessAddress")]
+    pub business_address: Option<Value>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UploadDocumentParams {
+    #[serde(rename = "documentType")]
+    pub document_type: AgentKycDocsDoctype,
+    #[validate(url(message = "documentUrl must be a valid url"))]
+    #[serde(rename = "documentUrl")]
+    pub document_url: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RejectDocumentParams {
+    #[validate(length(min = 2, max = 500, message = "reason cannot be < 2 and > 500"))]
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct GeofenceParams {
+    #[validate(range(min = -90.0, max = 90.0, message = "latitude cannot be < -90 and > 90"))]
+    pub latitude: Option<f64>,
+    #[validate(range(
+        min = -180.0,
+        max = 180.0,
+        message = "longitude cannot be < -180 and > 180"
+    ))]
+    pub longitude: Option<f64>,
+    #[validate(range(min = 1.0, message = "radiusMeters must be greater than 0"))]
+    #[serde(rename = "radiusMeters")]
+    pub radius_meters: f64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct GeofenceActionParams {
+    #[validate(length(min = 4, max = 6, message = "action must be REJECT or FLAG"))]
+    pub action: String,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::agents::Entity")]
+pub struct AgentResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "institution_id")]
+    pub institution_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "branch_id")]
+    pub branch_id: i64,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "customer_id")]
+    pub customer_id: Option<i64>,
+    #[sea_orm(from_col = "agent_number")]
+    pub agent_number: String,
+    #[sea_orm(from_col = "agent_name")]
+    pub agent_name: Option<String>,
+    #[sea_orm(from_col = "business_name")]
+    pub business_name: Option<String>,
+    #[sea_orm(from_col = "entity_type")]
+    pub entity_type: Option<AgentEntityType>,
+    #[sea_orm(from_col = "business_registration_number")]
+    pub business_registration_number: Option<String>,
+    #[sea_orm(from_col = "phone_country_code")]
+    pub phone_country_code: Option<String>,
+    #[sea_orm(from_col = "phone_number")]
+    pub phone_number: Option<String>,
+    #[sea_orm(from_col = "email")]
+    pub email: Option<String>,
+    #[sea_orm(from_col = "operating_address")]
+    pub operating_address: Option<Value>,
+    #[sea_orm(from_col = "business_address")]
+    pub business_address: Option<Value>,
+    #[sea_orm(from_col = "kyc_status")]
+    pub kyc_status: Option<Value>,
+    #[sea_orm(from_col = "kyc_completed_at")]
+    pub kyc_completed_at: Option<DateTime<FixedOffset>>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "settlement_account_id")]
+    pub settlement_account_id: Option<i64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "commission_account_id")]
+    pub commission_account_id: Option<i64>,
+    #[sea_orm(from_col = "geofence_radius")]
+    pub geofence_radius: Option<Value>,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<AgentStatus>,
+    #[sea_orm(from_col = "suspension_reason")]
+    pub suspension_reason: Option<String>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::agent_kyc_docs::Entity")]
+pub struct DocumentResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "agent_id")]
+    pub agent_id: i64,
+    #[sea_orm(from_col = "document_type")]
+    pub document_type: Option<AgentKycDocsDoctype>,
+    #[sea_orm(from_col = "document_url")]
+    pub document_url: Option<String>,
+    #[sea_orm(from_col = "verification_status")]
+    pub verification_status: Option<VerificationStatusType>,
+    #[sea_orm(from_col = "verified_at")]
+    pub verified_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "rejection_reason")]
+    pub rejection_reason: Option<String>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AgentDetailsModel {
+    pub agent: AgentResponseModel,
+    pub documents: Vec<DocumentResponseModel>,
+    pub missing_documents: Vec<AgentKycDocsDoctype>,
+}
@@ -0,0 +1,57 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::agents::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/agents")
+            .route(
+                "",
+                web::post()
+                    .to(controllers::onboard_agent)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}",
+                web::get()
+                    .to(controllers::agent_details)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/documents",
+                web::post()
+                    .to(controllers::upload_document)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/activate",
+                web::put()
+                    .to(controllers::activate_agent)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/geofence",
+                web::put()
+                    .to(controllers::set_geofence)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/institutions/{id}/geofence-action",
+                web::put()
+                    .to(controllers::set_geofence_action)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/documents/{id}/verify",
+                web::put()
+                    .to(controllers::verify_document)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/documents/{id}/reject",
+                web::put()
+                    .to(controllers::reject_document)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
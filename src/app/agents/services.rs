@@ -0,0 +1,643 @@
+use std::collections::HashMap;
+
+use actix_web::web;
+use entity::sea_orm_active_enums::{
+    AccTypeStatus, AgentEntityType, AgentKycDocsDoctype, AgentStatus, VerificationStatusType,
+};
+use sea_orm::{
+    ActiveEnum, ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, TransactionTrait,
+};
+use serde_json::{Value, json};
+
+use crate::{
+    AppState,
+    app::agents::models::{
+        AgentDetailsModel, AgentResponseModel, DocumentResponseModel, GeofenceBreachModel,
+        GeofenceModel, OnboardAgentModel, ReviewDocumentModel, UploadDocumentModel,
+    },
+    utils::{gen_snow_ids::gen_snowflake_slug, geo::distance_meters},
+};
+
+// What happens to an agent transaction reported outside the agent's geofence
+pub const GEOFENCE_REJECT: &str = "REJECT";
+pub const GEOFENCE_FLAG: &str = "FLAG";
+
+// Individuals prove identity and address; every other entity type
+// also needs its registration certificate
+pub fn required_documents(entity_type: &AgentEntityType) -> Vec<AgentKycDocsDoctype> {
+    match entity_type {
+        AgentEntityType::Individual => {
+            vec![AgentKycDocsDoctype::Id, AgentKycDocsDoctype::UtilityBill]
+        }
+        _ => vec![
+            AgentKycDocsDoctype::Id,
+            AgentKycDocsDoctype::BusinessCert,
+            AgentKycDocsDoctype::UtilityBill,
+        ],
+    }
+}
+
+pub async fn find_agent<C: ConnectionTrait>(
+    conn: &C,
+    agent_id: i64,
+) -> Result<entity::agents::Model, DbErr> {
+    entity::agents::Entity::find_by_id(agent_id)
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Agent not found".into()))
+}
+
+fn missing_documents(
+    agent: &entity::agents::Model,
+    documents: &[entity::agent_kyc_docs::Model],
+) -> Vec<AgentKycDocsDoctype> {
+    let entity_type = agent
+        .entity_type
+        .clone()
+        .unwrap_or(AgentEntityType::Individual);
+
+    required_documents(&entity_type)
+        .into_iter()
+        .filter(|required| {
+            !documents.iter().any(|doc| {
+                doc.document_type.as_ref() == Some(required)
+                    && doc.verification_status == Some(VerificationStatusType::Verified)
+            })
+        })
+        .collect()
+}
+
+fn kyc_status(agent: &entity::agents::Model, documents: &[entity::agent_kyc_docs::Model]) -> Value {
+    let entity_type = agent
+        .entity_type
+        .clone()
+        .unwrap_or(AgentEntityType::Individual);
+
+    let required: Vec<String> = required_documents(&entity_type)
+        .iter()
+        .map(|doc| doc.to_value())
+        .collect();
+
+    let statuses: HashMap<String, String> = documents
+        .iter()
+        .filter_map(|doc| {
+            let doc_type = doc.document_type.as_ref()?.to_value();
+            let status = doc
+                .verification_status
+                .as_ref()
+                .unwrap_or(&VerificationStatusType::Pending)
+                .to_value();
+
+            Some((doc_type, status))
+        })
+        .collect();
+
+    json!({
+        "required": required,
+        "documents": statuses,
+        "complete": missing_documents(agent, documents).is_empty(),
+    })
+}
+
+// Rebuilds the agent's kyc_status summary from its documents
+async fn refresh_kyc_status<C: ConnectionTrait>(conn: &C, agent_id: i64) -> Result<(), DbErr> {
+    let agent = find_agent(conn, agent_id).await?;
+
+    let documents = entity::agent_kyc_docs::Entity::find()
+        .filter(entity::agent_kyc_docs::Column::AgentId.eq(agent_id))
+        .all(conn)
+        .await?;
+
+    let status = kyc_status(&agent, &documents);
+
+    let mut active_agent: entity::agents::ActiveModel = agent.into();
+
+    active_agent.kyc_status = Set(Some(status));
+    active_agent.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_agent, conn).await?;
+
+    Ok(())
+}
+
+pub async fn onboard_agent(
+    model: &OnboardAgentModel,
+    state: &web::Data<AppState>,
+) -> Result<AgentResponseModel, DbErr> {
+    let data = model.clone();
+
+    if data.entity_type != AgentEntityType::Individual && data.business_name.is_none() {
+        return Err(DbErr::Custom(
+            "businessName is required for non individual agents".to_string(),
+        ));
+    }
+
+    let branch = entity::branches::Entity::find_by_id(data.branch_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Branch not found".into()))?;
+
+    let customer = entity::customers::Entity::find_by_id(data.customer_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Customer not found".into()))?;
+
+    if customer.institution_id != branch.institution_id {
+        return Err(DbErr::Custom(
+            "Customer does not belong to the branch's institution".to_string(),
+        ));
+    }
+
+    if customer.is_black_listed.unwrap_or(false) {
+        return Err(DbErr::Custom("Customer is blacklisted".to_string()));
+    }
+
+    let existing = entity::agents::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agents::Column::CustomerId.eq(customer.id))
+                .add(entity::agents::Column::Status.ne(AgentStatus::Terminated)),
+        )
+        .one(state.pgdb.get_ref())
+        .await?;
+
+    if existing.is_some() {
+        return Err(DbErr::Custom(
+            "Customer is already onboarded as an agent".to_string(),
+        ));
+    }
+
+    let (id, slug) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let required: Vec<String> = required_documents(&data.entity_type)
+        .iter()
+        .map(|doc| doc.to_value())
+        .collect();
+
+    entity::agents::ActiveModel {
+        id: Set(id),
+        institution_id: Set(branch.institution_id),
+        branch_id: Set(branch.id),
+        customer_id: Set(Some(customer.id)),
+        agent_number: Set(slug),
+        agent_name: Set(Some(data.agent_name)),
+        business_name: Set(data.business_name),
+        entity_type: Set(Some(data.entity_type)),
+        business_registration_number: Set(data.business_registration_number),
+        phone_country_code: Set(Some(data.phone_country_code)),
+        phone_number: Set(Some(data.phone_number)),
+        email: Set(data.email),
+        operating_address: Set(Some(data.operating_address)),
+        business_address: Set(data.business_address),
+        transaction_volume_today: Set(Some(0)),
+        transaction_count_today: Set(Some(0)),
+        status: Set(Some(AgentStatus::Pending)),
+        kyc_status: Set(Some(json!({
+            "required": required,
+            "documents": {},
+            "complete": false,
+        }))),
+        ..Default::default()
+    }
+    .insert(state.pgdb.get_ref())
+    .await?;
+
+    entity::agents::Entity::find_by_id(id)
+        .into_model::<AgentResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Agent not found".into()))
+}
+
+pub async fn upload_document(
+    agent_id: &i64,
+    model: &UploadDocumentModel,
+    state: &web::Data<AppState>,
+) -> Result<DocumentResponseModel, DbErr> {
+    let data = model.clone();
+
+    let txn = state.pgdb.begin().await?;
+
+    let agent = find_agent(&txn, *agent_id).await?;
+
+    if agent.status != Some(AgentStatus::Pending) {
+        return Err(DbErr::Custom(
+            "KYC documents can only be uploaded for pending agents".to_string(),
+        ));
+    }
+
+    let existing = entity::agent_kyc_docs::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::agent_kyc_docs::Column::AgentId.eq(agent.id))
+                .add(entity::agent_kyc_docs::Column::DocumentType.eq(data.document_type.clone())),
+        )
+        .one(&txn)
+        .await?;
+
+    // A re-upload replaces the file and sends it back for review
+    let id = match existing {
+        Some(doc) => {
+            let id = doc.id;
+            let mut active_doc: entity::agent_kyc_docs::ActiveModel = doc.into();
+
+            active_doc.document_url = Set(Some(data.document_url));
+            active_doc.verification_status = Set(Some(VerificationStatusType::Pending));
+            active_doc.verified_at = Set(None);
+            active_doc.verified_by = Set(None);
+            active_doc.rejection_reason = Set(None);
+            active_doc.updated_at = Set(Some(chrono::Utc::now().into()));
+
+            ActiveModelTrait::update(active_doc, &txn).await?;
+
+            id
+        }
+        None => {
+            let (id, _) = match gen_snowflake_slug() {
+                Ok(res) => res,
+                Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+            };
+
+            entity::agent_kyc_docs::ActiveModel {
+                id: Set(id),
+                agent_id: Set(agent.id),
+                document_type: Set(Some(data.document_type)),
+                document_url: Set(Some(data.document_url)),
+                verification_status: Set(Some(VerificationStatusType::Pending)),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await?;
+
+            id
+        }
+    };
+
+    refresh_kyc_status(&txn, agent.id).await?;
+
+    txn.commit().await?;
+
+    entity::agent_kyc_docs::Entity::find_by_id(id)
+        .into_model::<DocumentResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("KYC document not found".into()))
+}
+
+// Verifies the document, or rejects it when a rejection reason is given
+pub async fn review_document(
+    document_id: &i64,
+    model: &ReviewDocumentModel,
+    state: &web::Data<AppState>,
+) -> Result<DocumentResponseModel, DbErr> {
+    let data = model.clone();
+
+    let txn = state.pgdb.begin().await?;
+
+    let document = entity::agent_kyc_docs::Entity::find_by_id(*document_id)
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("KYC document not found".into()))?;
+
+    if document.verification_status != Some(VerificationStatusType::Pending) {
+        return Err(DbErr::Custom(
+            "Only pending documents can be reviewed".to_string(),
+        ));
+    }
+
+    let agent_id = document.agent_id;
+    let mut active_doc: entity::agent_kyc_docs::ActiveModel = document.into();
+
+    match data.rejection_reason {
+        Some(reason) => {
+            active_doc.verification_status = Set(Some(VerificationStatusType::Rejected));
+            active_doc.rejection_reason = Set(Some(reason));
+        }
+        None => {
+            active_doc.verification_status = Set(Some(VerificationStatusType::Verified));
+            active_doc.verified_at = Set(Some(chrono::Utc::now().into()));
+        }
+    }
+
+    active_doc.verified_by = Set(Some(data.reviewed_by));
+    active_doc.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_doc, &txn).await?;
+
+    refresh_kyc_status(&txn, agent_id).await?;
+
+    txn.commit().await?;
+
+    entity::agent_kyc_docs::Entity::find_by_id(*document_id)
+        .into_model::<DocumentResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("KYC document not found".into()))
+}
+
+async fn open_agent_account<C: ConnectionTrait>(
+    conn: &C,
+    agent: &entity::agents::Model,
+    customer_id: i64,
+    account_type_code: &str,
+    account_name: String,
+    created_by: i64,
+) -> Result<i64, DbErr> {
+    let account_type = entity::account_types::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::account_types::Column::InstitutionId.eq(agent.institution_id))
+                .add(entity::account_types::Column::Code.eq(account_type_code)),
+        )
+        .one(conn)
+        .await?
+        .ok_or_else(|| {
+            DbErr::RecordNotFound(format!("Account type {} not found", account_type_code))
+        })?;
+
+    let (id, slug) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::accounts::ActiveModel {
+        id: Set(id),
+        institution_id: Set(agent.institution_id),
+        customer_id: Set(customer_id),
+        account_type_id: Set(account_type.id),
+        account_number: Set(Some(slug)),
+        account_name: Set(Some(account_name)),
+        currency: Set(account_type.currency),
+        current_balance: Set(Some(0)),
+        available_balance: Set(Some(0)),
+        ledger_balance: Set(Some(0)),
+        hold_balance: Set(Some(0)),
+        status: Set(Some(AccTypeStatus::Active)),
+        activation_date: Set(Some(chrono::Utc::now().into())),
+        created_by: Set(Some(created_by)),
+        ..Default::default()
+    }
+    .insert(conn)
+    .await?;
+
+    Ok(id)
+}
+
+// Activation needs every required document verified; the settlement and
+// commission accounts are opened for the agent's customer at the same time
+pub async fn activate_agent(
+    agent_id: &i64,
+    activated_by: &i64,
+    state: &web::Data<AppState>,
+) -> Result<AgentResponseModel, DbErr> {
+    let txn = state.pgdb.begin().await?;
+
+    let agent = find_agent(&txn, *agent_id).await?;
+
+    if agent.status != Some(AgentStatus::Pending) {
+        return Err(DbErr::Custom(
+            "Only pending agents can be activated".to_string(),
+        ));
+    }
+
+    let Some(customer_id) = agent.customer_id else {
+        return Err(DbErr::Custom(
+            "Agent is not linked to a customer".to_string(),
+        ));
+    };
+
+    let documents = entity::agent_kyc_docs::Entity::find()
+        .filter(entity::agent_kyc_docs::Column::AgentId.eq(agent.id))
+        .all(&txn)
+        .await?;
+
+    let missing = missing_documents(&agent, &documents);
+
+    if !missing.is_empty() {
+        let missing: Vec<String> = missing.iter().map(|doc| doc.to_value()).collect();
+
+        return Err(DbErr::Custom(format!(
+            "KYC documents not verified: {}",
+            missing.join(", ")
+        )));
+    }
+
+    let agent_name = agent.agent_name.clone().unwrap_or_default();
+
+    let settlement_account_id = match agent.settlement_account_id {
+        Some(id) => id,
+        None => {
+            let code = state
+                .config
+                .get::<String>("agents.settlement_account_type")
+                .unwrap_or_else(|_| "AGENT_SETTLEMENT".to_string());
+
+            open_agent_account(
+                &txn,
+                &agent,
+                customer_id,
+                &code,
+                format!("{} Settlement", agent_name),
+                *activated_by,
+            )
+            .await?
+        }
+    };
+
+    let commission_account_id = match agent.commission_account_id {
+        Some(id) => id,
+        None => {
+            let code = state
+                .config
+                .get::<String>("agents.commission_account_type")
+                .unwrap_or_else(|_| "AGENT_COMMISSION".to_string());
+
+            open_agent_account(
+                &txn,
+                &agent,
+                customer_id,
+                &code,
+                format!("{} Commission", agent_name),
+                *activated_by,
+            )
+            .await?
+        }
+    };
+
+    let status = kyc_status(&agent, &documents);
+
+    let mut active_agent: entity::agents::ActiveModel = agent.into();
+
+    active_agent.settlement_account_id = Set(Some(settlement_account_id));
+    active_agent.commission_account_id = Set(Some(commission_account_id));
+    active_agent.kyc_status = Set(Some(status));
+    active_agent.kyc_completed_at = Set(Some(chrono::Utc::now().into()));
+    active_agent.verified_by = Set(Some(*activated_by));
+    active_agent.status = Set(Some(AgentStatus::Active));
+    active_agent.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_agent, &txn).await?;
+
+    txn.commit().await?;
+
+    entity::agents::Entity::find_by_id(*agent_id)
+        .into_model::<AgentResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Agent not found".into()))
+}
+
+pub async fn get_agent(
+    agent_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<AgentDetailsModel, DbErr> {
+    let agent = find_agent(state.pgdb.get_ref(), *agent_id).await?;
+
+    let documents = entity::agent_kyc_docs::Entity::find()
+        .filter(entity::agent_kyc_docs::Column::AgentId.eq(agent.id))
+        .order_by_asc(entity::agent_kyc_docs::Column::CreatedAt)
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let missing_documents = missing_documents(&agent, &documents);
+
+    let agent = entity::agents::Entity::find_by_id(*agent_id)
+        .into_model::<AgentResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Agent not found".into()))?;
+
+    let documents = entity::agent_kyc_docs::Entity::find()
+        .filter(entity::agent_kyc_docs::Column::AgentId.eq(*agent_id))
+        .order_by_asc(entity::agent_kyc_docs::Column::CreatedAt)
+        .into_model::<DocumentResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(AgentDetailsModel {
+        agent,
+        documents,
+        missing_documents,
+    })
+}
+
+fn coordinates(value: &Value) -> Option<(f64, f64)> {
+    Some((
+        value.get("latitude")?.as_f64()?,
+        value.get("longitude")?.as_f64()?,
+    ))
+}
+
+// The fence is centred on its own coordinates when set, otherwise on the
+// coordinates of the agent's operating address
+pub async fn set_geofence(
+    agent_id: &i64,
+    model: &GeofenceModel,
+    state: &web::Data<AppState>,
+) -> Result<AgentResponseModel, DbErr> {
+    let data = model.clone();
+
+    let agent = find_agent(state.pgdb.get_ref(), *agent_id).await?;
+
+    let centre = match (data.latitude, data.longitude) {
+        (Some(latitude), Some(longitude)) => (latitude, longitude),
+        (None, None) => agent
+            .operating_address
+            .as_ref()
+            .and_then(coordinates)
+            .ok_or_else(|| {
+                DbErr::Custom(
+                    "Operating address has no coordinates, latitude and longitude are required"
+                        .to_string(),
+                )
+            })?,
+        _ => {
+            return Err(DbErr::Custom(
+                "latitude and longitude must be given together".to_string(),
+            ));
+        }
+    };
+
+    let mut active_agent: entity::agents::ActiveModel = agent.into();
+
+    active_agent.geofence_radius = Set(Some(json!({
+        "latitude": centre.0,
+        "longitude": centre.1,
+        "radiusMeters": data.radius_meters,
+    })));
+    active_agent.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_agent, state.pgdb.get_ref()).await?;
+
+    entity::agents::Entity::find_by_id(*agent_id)
+        .into_model::<AgentResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Agent not found".into()))
+}
+
+pub async fn set_geofence_action(
+    institution_id: &i64,
+    action: &str,
+    state: &web::Data<AppState>,
+) -> Result<String, DbErr> {
+    let action = action.to_uppercase();
+
+    if action != GEOFENCE_REJECT && action != GEOFENCE_FLAG {
+        return Err(DbErr::Custom("action must be REJECT or FLAG".to_string()));
+    }
+
+    let institution = entity::institutions::Entity::find_by_id(*institution_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Institution not found".into()))?;
+
+    let mut active_institution: entity::institutions::ActiveModel = institution.into();
+
+    active_institution.agent_geofence_action = Set(Some(action.clone()));
+    active_institution.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_institution, state.pgdb.get_ref()).await?;
+
+    Ok(action)
+}
+
+pub async fn geofence_action<C: ConnectionTrait>(
+    conn: &C,
+    institution_id: i64,
+) -> Result<String, DbErr> {
+    let institution = entity::institutions::Entity::find_by_id(institution_id)
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Institution not found".into()))?;
+
+    Ok(institution
+        .agent_geofence_action
+        .unwrap_or_else(|| GEOFENCE_FLAG.to_string()))
+}
+
+// None when the agent has no fence or the point lies inside it
+pub fn geofence_breach(
+    agent: &entity::agents::Model,
+    point: (f64, f64),
+) -> Option<GeofenceBreachModel> {
+    let fence = agent.geofence_radius.as_ref()?;
+
+    let radius_meters = fence.get("radiusMeters")?.as_f64()?;
+
+    let centre =
+        coordinates(fence).or_else(|| agent.operating_address.as_ref().and_then(coordinates))?;
+
+    let distance = distance_meters(centre, point);
+
+    (distance > radius_meters).then_some(GeofenceBreachModel {
+        latitude: centre.0,
+        longitude: centre.1,
+        radius_meters,
+        distance_meters: distance,
+    })
+}
@@ -0,0 +1,55 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use entity::sea_orm_active_enums::ContributionCyclesStatus;
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::Serialize;
+use serde_with::{DisplayFromStr, serde_as};
+
+// What one saver owes in a cycle: the goal's contribution amount for a
+// personal goal, or a member's committed amount for a group goal
+#[derive(Debug, Clone)]
+pub struct ObligationModel {
+    pub saving_goal_id: i64,
+    pub customer_id: i64,
+    pub member_id: Option<i64>,
+    pub account_id: Option<i64>,
+    pub amount: i64,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::contribution_cycles::Entity")]
+pub struct CycleResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "savings_product_id")]
+    pub savings_product_id: i64,
+    #[sea_orm(from_col = "cycle_name")]
+    pub cycle_name: Option<String>,
+    #[sea_orm(from_col = "start_date")]
+    pub start_date: Option<NaiveDate>,
+    #[sea_orm(from_col = "end_date")]
+    pub end_date: Option<NaiveDate>,
+    #[sea_orm(from_col = "expected_contributions")]
+    pub expected_contributions: Option<i64>,
+    #[sea_orm(from_col = "actual_contributions")]
+    pub actual_contributions: Option<i64>,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<ContributionCyclesStatus>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CycleReportModel {
+    pub cycle: CycleResponseModel,
+    pub expected_contributions: i64,
+    pub actual_contributions: i64,
+    pub collection_rate: Decimal,
+    pub obligations: usize,
+    pub paid_in_full: usize,
+    pub missed_contributions: usize,
+    pub penalties_applied: i64,
+}
@@ -0,0 +1,21 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::contribution_cycles::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/contribution-cycles")
+            .route(
+                "/{id}",
+                web::get()
+                    .to(controllers::cycle_report)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/products/{id}",
+                web::get()
+                    .to(controllers::product_cycles)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
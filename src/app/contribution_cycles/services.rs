@@ -0,0 +1,540 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_web::web;
+use chrono::NaiveDate;
+use entity::sea_orm_active_enums::{
+    ContributionCyclesStatus, ContributionStatus, ContributionType, SavingGoalsStatus,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+
+use crate::{
+    AppState,
+    app::{
+        contribution_cycles::models::{CycleReportModel, CycleResponseModel, ObligationModel},
+        savings::services::{lock_goal, post_penalty, record_contribution},
+    },
+    utils::{
+        finance::{collection_rate, cycle_end_date, progress_percentage},
+        gen_snow_ids::gen_snowflake_slug,
+    },
+};
+
+pub async fn current_cycle_id<C: ConnectionTrait>(
+    conn: &C,
+    savings_product_id: i64,
+    today: NaiveDate,
+) -> Result<Option<i64>, DbErr> {
+    let cycle = entity::contribution_cycles::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::contribution_cycles::Column::SavingsProductId.eq(savings_product_id))
+                .add(
+                    entity::contribution_cycles::Column::Status
+                        .eq(ContributionCyclesStatus::Active),
+                )
+                .add(entity::contribution_cycles::Column::StartDate.lte(today))
+                .add(entity::contribution_cycles::Column::EndDate.gte(today)),
+        )
+        .one(conn)
+        .await?;
+
+    Ok(cycle.map(|cycle| cycle.id))
+}
+
+// Active goals and members that were already saving when the cycle opened
+async fn obligations<C: ConnectionTrait>(
+    conn: &C,
+    savings_product_id: i64,
+    cycle_start: NaiveDate,
+) -> Result<Vec<ObligationModel>, DbErr> {
+    let goals = entity::saving_goals::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::saving_goals::Column::SavingsProductId.eq(savings_product_id))
+                .add(entity::saving_goals::Column::Status.eq(SavingGoalsStatus::Active))
+                .add(entity::saving_goals::Column::StartDate.lte(cycle_start)),
+        )
+        .order_by_asc(entity::saving_goals::Column::Id)
+        .all(conn)
+        .await?;
+
+    let mut obligations = vec![];
+
+    for goal in goals {
+        if !goal.is_group_savings.unwrap_or(false) {
+            if let Some(amount) = goal.contribution_amount.filter(|amount| *amount > 0) {
+                obligations.push(ObligationModel {
+                    saving_goal_id: goal.id,
+                    customer_id: goal.customer_id,
+                    member_id: None,
+                    account_id: Some(goal.account_id),
+                    amount,
+                });
+            }
+
+            continue;
+        }
+
+        let members = entity::group_saving_members::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(entity::group_saving_members::Column::GroupGoalId.eq(goal.id))
+                    .add(entity::group_saving_members::Column::IsActive.eq(true))
+                    .add(entity::group_saving_members::Column::JoinedDate.lte(cycle_start)),
+            )
+            .order_by_asc(entity::group_saving_members::Column::Id)
+            .all(conn)
+            .await?;
+
+        for member in members {
+            if let Some(amount) = member.committed_amount.filter(|amount| *amount > 0) {
+                obligations.push(ObligationModel {
+                    saving_goal_id: goal.id,
+                    customer_id: member.customer_id,
+                    member_id: Some(member.id),
+                    account_id: None,
+                    amount,
+                });
+            }
+        }
+    }
+
+    Ok(obligations)
+}
+
+// Completed contributions in the cycle per (goal, customer)
+async fn collected<C: ConnectionTrait>(
+    conn: &C,
+    cycle_id: i64,
+) -> Result<HashMap<(i64, i64), i64>, DbErr> {
+    let contributions = entity::contributions::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::contributions::Column::ContributionCycleId.eq(cycle_id))
+                .add(entity::contributions::Column::Status.eq(ContributionStatus::Completed))
+                .add(entity::contributions::Column::ContributionType.eq(ContributionType::Regular)),
+        )
+        .all(conn)
+        .await?;
+
+    let mut collected: HashMap<(i64, i64), i64> = HashMap::new();
+
+    for contribution in contributions {
+        if let Some(goal_id) = contribution.saving_goal_id {
+            *collected
+                .entry((goal_id, contribution.customer_id))
+                .or_default() += contribution.amount;
+        }
+    }
+
+    Ok(collected)
+}
+
+fn outstanding(obligation: &ObligationModel, collected: &HashMap<(i64, i64), i64>) -> i64 {
+    let paid = collected
+        .get(&(obligation.saving_goal_id, obligation.customer_id))
+        .copied()
+        .unwrap_or(0);
+
+    (obligation.amount - paid).max(0)
+}
+
+async fn open_cycle(
+    product: &entity::savings_products::Model,
+    today: NaiveDate,
+    state: &web::Data<AppState>,
+) -> Result<Option<entity::contribution_cycles::Model>, DbErr> {
+    let Some(freq) = product.contribution_freq.as_ref() else {
+        return Ok(None);
+    };
+
+    let obligations = obligations(state.pgdb.get_ref(), product.id, today).await?;
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let label = product
+        .code
+        .clone()
+        .or_else(|| product.name.clone())
+        .unwrap_or_else(|| product.id.to_string());
+
+    let cycle = entity::contribution_cycles::ActiveModel {
+        id: Set(id),
+        savings_product_id: Set(product.id),
+        institution_id: Set(product.institution_id),
+        cycle_name: Set(Some(format!("{} {}", label, today))),
+        start_date: Set(Some(today)),
+        end_date: Set(Some(cycle_end_date(freq, today))),
+        expected_contributions: Set(Some(
+            obligations.iter().map(|obligation| obligation.amount).sum(),
+        )),
+        actual_contributions: Set(Some(0)),
+        status: Set(Some(ContributionCyclesStatus::Active)),
+        ..Default::default()
+    }
+    .insert(state.pgdb.get_ref())
+    .await?;
+
+    Ok(Some(cycle))
+}
+
+// Personal goals are debited from their linked account for whatever is still
+// due; a failed debit is retried on the next run until the cycle closes.
+// Group members pay in through the group.
+async fn collect_due(
+    cycle: &entity::contribution_cycles::Model,
+    state: &web::Data<AppState>,
+) -> Result<u64, DbErr> {
+    let Some(cycle_start) = cycle.start_date else {
+        return Ok(0);
+    };
+
+    let obligations =
+        obligations(state.pgdb.get_ref(), cycle.savings_product_id, cycle_start).await?;
+    let collected = collected(state.pgdb.get_ref(), cycle.id).await?;
+
+    let mut debited = 0;
+
+    for obligation in &obligations {
+        let Some(account_id) = obligation.account_id else {
+            continue;
+        };
+
+        let due = outstanding(obligation, &collected);
+
+        if due == 0 {
+            continue;
+        }
+
+        let txn = state.pgdb.begin().await?;
+
+        let Some(goal) = entity::saving_goals::Entity::find_by_id(obligation.saving_goal_id)
+            .filter(entity::saving_goals::Column::Status.eq(SavingGoalsStatus::Active))
+            .one(&txn)
+            .await?
+        else {
+            continue;
+        };
+
+        match record_contribution(&txn, goal.id, obligation.customer_id, account_id, due).await {
+            Ok(_) => {
+                txn.commit().await?;
+                debited += 1;
+            }
+            Err(err) => {
+                txn.rollback().await?;
+                log::warn!(
+                    "Scheduled contribution for goal {} not collected: {}",
+                    obligation.saving_goal_id,
+                    err
+                );
+            }
+        }
+    }
+
+    Ok(debited)
+}
+
+// Anything still outstanding at close is recorded as a missed contribution
+// and the product's penalty is taken from the saver's balance in the goal.
+// The cycle, goal and member rows are locked so a concurrent run, contribution
+// or payout cannot apply on top of a stale balance
+async fn close_cycle(
+    product: &entity::savings_products::Model,
+    cycle_id: i64,
+    state: &web::Data<AppState>,
+) -> Result<(), DbErr> {
+    let txn = state.pgdb.begin().await?;
+
+    let Some(cycle) = entity::contribution_cycles::Entity::find_by_id(cycle_id)
+        .filter(entity::contribution_cycles::Column::Status.eq(ContributionCyclesStatus::Active))
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+    else {
+        return Ok(());
+    };
+
+    let cycle_start = cycle.start_date.unwrap_or_default();
+    let cycle_end = cycle.end_date.unwrap_or(cycle_start);
+    let penalty = product.missed_contribution_penalty.unwrap_or(0).max(0);
+
+    let obligations = obligations(&txn, cycle.savings_product_id, cycle_start).await?;
+    let collected = collected(&txn, cycle.id).await?;
+
+    for obligation in &obligations {
+        let shortfall = outstanding(obligation, &collected);
+
+        if shortfall == 0 {
+            continue;
+        }
+
+        let goal = lock_goal(&txn, obligation.saving_goal_id).await?;
+
+        let member = match obligation.member_id {
+            Some(member_id) => {
+                entity::group_saving_members::Entity::find_by_id(member_id)
+                    .lock_exclusive()
+                    .one(&txn)
+                    .await?
+            }
+            None => None,
+        };
+
+        let saved = match &member {
+            Some(member) => member.actual_contributed.unwrap_or(0),
+            None => goal.current_amount.unwrap_or(0),
+        };
+        let penalty_applied = penalty.min(saved.max(0));
+
+        let (contribution_id, slug) = match gen_snowflake_slug() {
+            Ok(res) => res,
+            Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+        };
+
+        entity::contributions::ActiveModel {
+            id: Set(contribution_id),
+            institution_id: Set(cycle.institution_id),
+            saving_goal_id: Set(Some(goal.id)),
+            customer_id: Set(obligation.customer_id),
+            contribution_cycle_id: Set(Some(cycle.id)),
+            account_id: Set(obligation.account_id),
+            contribution_date: Set(Some(cycle_end)),
+            amount: Set(shortfall),
+            contribution_type: Set(Some(ContributionType::Regular)),
+            contribution_reference: Set(Some(slug.clone())),
+            is_missed_contribution: Set(Some(true)),
+            missed_contribution_date: Set(Some(cycle_end)),
+            penalty_applied: Set(Some(penalty_applied)),
+            status: Set(Some(ContributionStatus::Cancelled)),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        if penalty_applied == 0 {
+            continue;
+        }
+
+        post_penalty(
+            &txn,
+            product,
+            penalty_applied,
+            format!("Missed contribution penalty on saving goal {}", goal.id),
+            format!("MCP-{}", slug),
+            None,
+        )
+        .await?;
+
+        if let Some(member) = member {
+            let actual_contributed = member.actual_contributed.unwrap_or(0) - penalty_applied;
+
+            let mut active_member: entity::group_saving_members::ActiveModel = member.into();
+            active_member.actual_contributed = Set(Some(actual_contributed));
+            active_member.updated_at = Set(Some(chrono::Utc::now().into()));
+            active_member.update(&txn).await?;
+        }
+
+        let current_amount = goal.current_amount.unwrap_or(0) - penalty_applied;
+        let target_amount = goal.target_amount;
+
+        let mut active_goal: entity::saving_goals::ActiveModel = goal.into();
+
+        active_goal.current_amount = Set(Some(current_amount));
+
+        if let Some(target) = target_amount {
+            active_goal.progress_percentage =
+                Set(Some(progress_percentage(current_amount, target)));
+        }
+
+        active_goal.updated_at = Set(Some(chrono::Utc::now().into()));
+        active_goal.update(&txn).await?;
+    }
+
+    let expected: i64 = obligations.iter().map(|obligation| obligation.amount).sum();
+    let actual: i64 = collected.values().sum();
+
+    let mut active_cycle: entity::contribution_cycles::ActiveModel = cycle.into();
+    active_cycle.expected_contributions = Set(Some(expected));
+    active_cycle.actual_contributions = Set(Some(actual));
+    active_cycle.status = Set(Some(ContributionCyclesStatus::Completed));
+    active_cycle.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_cycle.update(&txn).await?;
+
+    txn.commit().await?;
+
+    Ok(())
+}
+
+async fn run_product_cycles(
+    product: &entity::savings_products::Model,
+    today: NaiveDate,
+    state: &web::Data<AppState>,
+) -> Result<u64, DbErr> {
+    let mut processed = 0;
+
+    let ended = entity::contribution_cycles::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::contribution_cycles::Column::SavingsProductId.eq(product.id))
+                .add(
+                    entity::contribution_cycles::Column::Status
+                        .eq(ContributionCyclesStatus::Active),
+                )
+                .add(entity::contribution_cycles::Column::EndDate.lt(today)),
+        )
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    for cycle in ended {
+        close_cycle(product, cycle.id, state).await?;
+        processed += 1;
+    }
+
+    let cycle = match current_cycle_id(state.pgdb.get_ref(), product.id, today).await? {
+        Some(id) => {
+            entity::contribution_cycles::Entity::find_by_id(id)
+                .one(state.pgdb.get_ref())
+                .await?
+        }
+        None => open_cycle(product, today, state).await?,
+    };
+
+    if let Some(cycle) = cycle {
+        processed += collect_due(&cycle, state).await?;
+    }
+
+    Ok(processed)
+}
+
+// Daily: close cycles that have ended, open the next one for every product
+// with a contribution frequency, then collect what is due in it. A product
+// that fails is logged and skipped so the others still run
+pub async fn run_cycles(state: &web::Data<AppState>) -> Result<u64, DbErr> {
+    let today = chrono::Utc::now().date_naive();
+
+    let products = entity::savings_products::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::savings_products::Column::IsActive.eq(true))
+                .add(entity::savings_products::Column::ContributionFreq.is_not_null()),
+        )
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let mut processed = 0;
+
+    for product in &products {
+        match run_product_cycles(product, today, state).await {
+            Ok(count) => processed += count,
+            Err(err) => {
+                log::warn!(
+                    "Contribution cycles for savings product {} not run: {}",
+                    product.id,
+                    err
+                );
+            }
+        }
+    }
+
+    Ok(processed)
+}
+
+pub async fn get_product_cycles(
+    savings_product_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<CycleResponseModel>, DbErr> {
+    let cycles = entity::contribution_cycles::Entity::find()
+        .filter(entity::contribution_cycles::Column::SavingsProductId.eq(*savings_product_id))
+        .order_by_desc(entity::contribution_cycles::Column::StartDate)
+        .into_model::<CycleResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(cycles)
+}
+
+// Open cycles are measured against who owes today; closed cycles against
+// the totals fixed when they closed and the contributions recorded in them
+pub async fn get_cycle_report(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<CycleReportModel, DbErr> {
+    let cycle = entity::contribution_cycles::Entity::find_by_id(*id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Contribution cycle not found".into()))?;
+
+    let obligations = obligations(
+        state.pgdb.get_ref(),
+        cycle.savings_product_id,
+        cycle.start_date.unwrap_or_default(),
+    )
+    .await?;
+    let collected = collected(state.pgdb.get_ref(), cycle.id).await?;
+
+    let missed = entity::contributions::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::contributions::Column::ContributionCycleId.eq(cycle.id))
+                .add(entity::contributions::Column::IsMissedContribution.eq(true)),
+        )
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let actual_contributions: i64 = collected.values().sum();
+
+    let (expected_contributions, obligation_count, paid_in_full) =
+        if cycle.status == Some(ContributionCyclesStatus::Active) {
+            (
+                obligations.iter().map(|obligation| obligation.amount).sum(),
+                obligations.len(),
+                obligations
+                    .iter()
+                    .filter(|obligation| outstanding(obligation, &collected) == 0)
+                    .count(),
+            )
+        } else {
+            let savers: HashSet<(i64, i64)> = collected
+                .keys()
+                .copied()
+                .chain(missed.iter().filter_map(|contribution| {
+                    contribution
+                        .saving_goal_id
+                        .map(|goal_id| (goal_id, contribution.customer_id))
+                }))
+                .collect();
+
+            (
+                cycle.expected_contributions.unwrap_or(0),
+                savers.len(),
+                savers.len().saturating_sub(missed.len()),
+            )
+        };
+
+    let response = entity::contribution_cycles::Entity::find_by_id(*id)
+        .into_model::<CycleResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Contribution cycle not found".into()))?;
+
+    Ok(CycleReportModel {
+        cycle: response,
+        expected_contributions,
+        actual_contributions,
+        collection_rate: collection_rate(actual_contributions, expected_contributions),
+        obligations: obligation_count,
+        paid_in_full,
+        missed_contributions: missed.len(),
+        penalties_applied: missed
+            .iter()
+            .map(|contribution| contribution.penalty_applied.unwrap_or(0))
+            .sum(),
+    })
+}
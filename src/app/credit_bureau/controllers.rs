@@ -0,0 +1,99 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::credit_bureau::{
+        models::{RecordConsentModel, RecordConsentParams},
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn record_consent(
+    req: HttpRequest,
+    payload: web::Json<RecordConsentParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "CREDIT_REPORT_CONSENT", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let loan_application_id = id_parser(&data.loan_application_id, "Loan Application Id").await?;
+
+    let provider_id = match data.provider_id {
+        Some(provider_id) => Some(id_parser(&provider_id, "Provider Id").await?),
+        None => None,
+    };
+
+    let consent = RecordConsentModel {
+        loan_application_id,
+        provider_id,
+    };
+
+    match services::record_consent(&consent, &state).await {
+        Ok(report) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Consent Recorded",
+            report,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn pull_report(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "CREDIT_REPORT_PULL", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Application Id").await?;
+
+    match services::pull_report(&id, &state).await {
+        Ok(report) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Credit Report Retrieved",
+            report,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn application_reports(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Application Id").await?;
+
+    match services::get_application_reports(&id, &state).await {
+        Ok(reports) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            reports,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
@@ -0,0 +1,5 @@
+pub mod controllers;
+pub mod models;
+pub mod providers;
+pub mod routes;
+pub mod services;
@@ -0,0 +1,102 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[derive(Debug, Clone)]
+pub struct RecordConsentModel {
+    pub loan_application_id: i64,
+    pub provider_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BureauInquiryModel {
+    pub customer_id: i64,
+    pub bureau_customer_id: Option<String>,
+    pub id_type: Option<String>,
+    pub id_number: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub date_of_birth: Option<NaiveDate>,
+    pub phone_number: Option<String>,
+    pub inquiry_type: String,
+    pub requested_amount: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BureauReportModel {
+    pub bureau_customer_id: Option<String>,
+    pub credit_score: Option<i32>,
+    pub risk_grade: Option<String>,
+    pub number_of_inquiries: Option<i32>,
+    pub inquiries_last30_days: Option<i32>,
+    pub total_accounts: Option<i32>,
+    pub active_accounts: Option<i32>,
+    pub defaults_count: Option<i32>,
+    pub total_outstanding_default_amount: Option<i64>,
+    pub report_data: Option<Value>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RecordConsentParams {
+    #[serde(rename = "loanApplicationId")]
+    pub loan_application_id: String,
+    #[serde(rename = "providerId")]
+    pub provider_id: Option<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::credit_bureau_reports::Entity")]
+pub struct CreditReportResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "provider_id")]
+    pub provider_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "customer_id")]
+    pub customer_id: i64,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "loan_application_id")]
+    pub loan_application_id: Option<i64>,
+    #[sea_orm(from_col = "inquiry_type")]
+    pub inquiry_type: String,
+    #[sea_orm(from_col = "bureau_customer_id")]
+    pub bureau_customer_id: Option<String>,
+    #[sea_orm(from_col = "credit_score")]
+    pub credit_score: Option<i32>,
+    #[sea_orm(from_col = "risk_grade")]
+    pub risk_grade: Option<String>,
+    #[sea_orm(from_col = "number_of_inquiries")]
+    pub number_of_inquiries: Option<i32>,
+    #[sea_orm(from_col = "inquiries_last30_days")]
+    pub inquiries_last30_days: Option<i32>,
+    #[sea_orm(from_col = "total_accounts")]
+    pub total_accounts: Option<i32>,
+    #[sea_orm(from_col = "active_accounts")]
+    pub active_accounts: Option<i32>,
+    #[sea_orm(from_col = "defaults_count")]
+    pub defaults_count: Option<i32>,
+    #[sea_orm(from_col = "total_outstanding_default_amount")]
+    pub total_outstanding_default_amount: Option<i64>,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<String>,
+    #[sea_orm(from_col = "error_code")]
+    pub error_code: Option<String>,
+    #[sea_orm(from_col = "error_message")]
+    pub error_message: Option<String>,
+    #[sea_orm(from_col = "consent_provided")]
+    pub consent_provided: Option<bool>,
+    #[sea_orm(from_col = "consent_date")]
+    pub consent_date: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "updated_at")]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
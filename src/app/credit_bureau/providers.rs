@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use sea_orm::DbErr;
+use serde_json::json;
+
+use crate::{
+    app::credit_bureau::models::{BureauInquiryModel, BureauReportModel},
+    utils::crypto::decrypt_secret,
+};
+
+pub trait CreditBureauProvider {
+    fn fetch_report(
+        &self,
+        inquiry: &BureauInquiryModel,
+    ) -> impl Future<Output = Result<BureauReportModel, DbErr>> + Send;
+}
+
+// Talks to a bureau over HTTP using the connection details held in `integration_providers`
+pub struct HttpBureauProvider {
+    client: reqwest::Client,
+    endpoint: String,
+    api_key: Option<String>,
+    auth_method: Option<String>,
+    max_attempts: i32,
+}
+
+impl HttpBureauProvider {
+    pub fn from_provider(
+        provider: &entity::integration_providers::Model,
+        timeout_secs: u64,
+    ) -> Result<Self, DbErr> {
+        let Some(base_url) = provider.api_base_url.clone() else {
+            return Err(DbErr::Custom(format!(
+                "Credit bureau provider {} has no API base url",
+                provider.provider_code
+            )));
+        };
+
+        let endpoint = match &provider.api_version {
+            Some(version) => format!("{}/{}/reports", base_url.trim_end_matches('/'), version),
+            None => format!("{}/reports", base_url.trim_end_matches('/')),
+        };
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(timeout_secs))
+            .build()
+            .map_err(|err| DbErr::Custom(err.to_string()))?;
+
+        let max_attempts = if provider.retry_enabled.unwrap_or(false) {
+            provider.max_retry_attempts.unwrap_or(1).max(1)
+        } else {
+            1
+        };
+
+        // The provider's API key is stored sealed; see `utils::crypto`
+        let api_key = provider
+            .api_key_encrypted
+            .as_deref()
+            .map(decrypt_secret)
+            .transpose()
+            .map_err(|err| {
+                DbErr::Custom(format!(
+                    "Credit bureau provider {} API key: {}",
+                    provider.provider_code, err
+                ))
+            })?;
+
+        Ok(Self {
+            client,
+            endpoint,
+            api_key,
+            auth_method: provider.auth_method.clone(),
+            max_attempts,
+        })
+    }
+
+    async fn send(&self, inquiry: &BureauInquiryModel) -> Result<BureauReportModel, String> {
+        let mut request = self.client.post(&self.endpoint).json(inquiry);
+
+        if let Some(api_key) = &self.api_key {
+            request = match self.auth_method.as_deref() {
+                Some("API_KEY") => request.header("x-api-key", api_key),
+                _ => request.bearer_auth(api_key),
+            };
+        }
+
+        let response = request.send().await.map_err(|err| err.to_string())?;
+
+        if !response.status().is_success() {
+            return Err(format!("Bureau responded with {}", response.status()));
+        }
+
+        response
+            .json::<BureauReportModel>()
+            .await
+            .map_err(|err| err.to_string())
+    }
+}
+
+impl CreditBureauProvider for HttpBureauProvider {
+    async fn fetch_report(&self, inquiry: &BureauInquiryModel) -> Result<BureauReportModel, DbErr> {
+        let mut last_error = String::new();
+
+        for attempt in 1..=self.max_attempts {
+            match self.send(inquiry).await {
+                Ok(report) => return Ok(report),
+                Err(err) => {
+                    log::warn!(
+                        "Credit bureau request attempt {} of {} failed: {}",
+                        attempt,
+                        self.max_attempts,
+                        err
+                    );
+                    last_error = err;
+                }
+            }
+        }
+
+        Err(DbErr::Custom(format!(
+            "Credit bureau request failed: {}",
+            last_error
+        )))
+    }
+}
+
+// Deterministic offline bureau so the same customer always gets the same report
+pub struct MockBureauProvider;
+
+impl CreditBureauProvider for MockBureauProvider {
+    async fn fetch_report(&self, inquiry: &BureauInquiryModel) -> Result<BureauReportModel, DbErr> {
+        let seed = inquiry.customer_id.unsigned_abs();
+
+        let credit_score = 300 + (seed % 551) as i32;
+        let defaults_count = if credit_score < 500 { 1 } else { 0 };
+
+        let risk_grade = match credit_score {
+            750.. => "A",
+            650..=749 => "B",
+            550..=649 => "C",
+            450..=549 => "D",
+            _ => "E",
+        };
+
+        Ok(BureauReportModel {
+            bureau_customer_id: Some(format!("MOCK-{}", inquiry.customer_id)),
+            credit_score: Some(credit_score),
+            risk_grade: Some(risk_grade.to_string()),
+            number_of_inquiries: Some((seed % 7) as i32),
+            inquiries_last30_days: Some((seed % 3) as i32),
+            total_accounts: Some((seed % 5) as i32 + 1),
+            active_accounts: Some((seed % 3) as i32),
+            defaults_count: Some(defaults_count),
+            total_outstanding_default_amount: Some(i64::from(defaults_count) * 50_000),
+            report_data: Some(json!({
+                "provider": "MOCK",
+                "inquiry_type": inquiry.inquiry_type,
+                "requested_amount": inquiry.requested_amount,
+            })),
+        })
+    }
+}
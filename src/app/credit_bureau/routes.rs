@@ -0,0 +1,27 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::credit_bureau::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/credit-reports")
+            .route(
+                "/consents",
+                web::post()
+                    .to(controllers::record_consent)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/applications/{id}/pull",
+                web::post()
+                    .to(controllers::pull_report)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/applications/{id}",
+                web::get()
+                    .to(controllers::application_reports)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
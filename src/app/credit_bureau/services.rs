@@ -0,0 +1,285 @@
+use actix_web::web;
+use entity::sea_orm_active_enums::{
+    IntegrationProvidersProvStatus, IntegrationProvidersServiceType, LoanApplicationStatus,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, TransactionTrait,
+};
+
+use crate::{
+    AppState,
+    app::credit_bureau::{
+        models::{BureauInquiryModel, CreditReportResponseModel, RecordConsentModel},
+        providers::{CreditBureauProvider, HttpBureauProvider, MockBureauProvider},
+    },
+    utils::gen_snow_ids::gen_snowflake_slug,
+};
+
+const STATUS_CONSENTED: &str = "CONSENTED";
+const STATUS_COMPLETED: &str = "COMPLETED";
+const STATUS_FAILED: &str = "FAILED";
+
+async fn find_application(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<entity::loan_applications::Model, DbErr> {
+    entity::loan_applications::Entity::find_by_id(*id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan application not found".into()))
+}
+
+async fn get_report(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<CreditReportResponseModel, DbErr> {
+    entity::credit_bureau_reports::Entity::find_by_id(*id)
+        .into_model::<CreditReportResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Credit report not found".into()))
+}
+
+// Falls back to the institution's primary active bureau when none is named
+async fn find_provider(
+    institution_id: i64,
+    provider_id: Option<i64>,
+    state: &web::Data<AppState>,
+) -> Result<entity::integration_providers::Model, DbErr> {
+    let mut query = entity::integration_providers::Entity::find().filter(
+        Condition::all()
+            .add(entity::integration_providers::Column::InstitutionId.eq(institution_id))
+            .add(
+                entity::integration_providers::Column::ServiceType
+                    .eq(IntegrationProvidersServiceType::CreditBureau),
+            )
+            .add(
+                entity::integration_providers::Column::ProviderStatus
+                    .eq(IntegrationProvidersProvStatus::Active),
+            ),
+    );
+
+    if let Some(provider_id) = provider_id {
+        query = query.filter(entity::integration_providers::Column::Id.eq(provider_id));
+    }
+
+    query
+        .order_by_desc(entity::integration_providers::Column::IsPrimary)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("No active credit bureau provider configured".into()))
+}
+
+async fn build_inquiry(
+    application: &entity::loan_applications::Model,
+    report: &entity::credit_bureau_reports::Model,
+    state: &web::Data<AppState>,
+) -> Result<BureauInquiryModel, DbErr> {
+    let customer = entity::customers::Entity::find_by_id(application.customer_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Customer not found".into()))?;
+
+    let identification = entity::customer_identifications::Entity::find()
+        .filter(entity::customer_identifications::Column::CustomerId.eq(customer.id))
+        .order_by_desc(entity::customer_identifications::Column::VerifiedAt)
+        .one(state.pgdb.get_ref())
+        .await?;
+
+    let phone_number = match (customer.phone_country_code, customer.phone_number) {
+        (Some(code), Some(number)) => Some(format!("{}{}", code, number)),
+        (None, Some(number)) => Some(number),
+        _ => None,
+    };
+
+    Ok(BureauInquiryModel {
+        customer_id: customer.id,
+        bureau_customer_id: report.bureau_customer_id.clone(),
+        id_type: identification
+            .as_ref()
+            .and_then(|identification| identification.id_type.clone()),
+        id_number: identification.and_then(|identification| identification.id_number),
+        first_name: customer.first_name,
+        last_name: customer.last_name,
+        date_of_birth: customer.date_of_birth,
+        phone_number,
+        inquiry_type: report.inquiry_type.clone(),
+        requested_amount: application.requested_principal,
+    })
+}
+
+pub async fn record_consent(
+    model: &RecordConsentModel,
+    state: &web::Data<AppState>,
+) -> Result<CreditReportResponseModel, DbErr> {
+    let data = model.clone();
+
+    let application = find_application(&data.loan_application_id, state).await?;
+
+    if !matches!(
+        application.status,
+        Some(LoanApplicationStatus::Draft) | Some(LoanApplicationStatus::Pending)
+    ) {
+        return Err(DbErr::Custom(
+            "Consent can only be recorded for draft or pending applications".to_string(),
+        ));
+    }
+
+    let provider = find_provider(application.institution_id, data.provider_id, state).await?;
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::credit_bureau_reports::ActiveModel {
+        id: Set(id),
+        institution_id: Set(application.institution_id),
+        provider_id: Set(provider.id),
+        customer_id: Set(application.customer_id),
+        inquiry_type: Set("LOAN_APPLICATION".to_string()),
+        loan_application_id: Set(Some(application.id)),
+        status: Set(Some(STATUS_CONSENTED.to_string())),
+        consent_provided: Set(Some(true)),
+        consent_date: Set(Some(chrono::Utc::now().into())),
+        ..Default::default()
+    }
+    .insert(state.pgdb.get_ref())
+    .await?;
+
+    get_report(&id, state).await
+}
+
+// A failed pull keeps its consent so the officer can retry without re-capturing it
+pub async fn pull_report(
+    application_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<CreditReportResponseModel, DbErr> {
+    let application = find_application(application_id, state).await?;
+
+    if !matches!(
+        application.status,
+        Some(LoanApplicationStatus::Draft) | Some(LoanApplicationStatus::Pending)
+    ) {
+        return Err(DbErr::Custom(
+            "Credit reports can only be pulled for draft or pending applications".to_string(),
+        ));
+    }
+
+    let Some(report) = entity::credit_bureau_reports::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::credit_bureau_reports::Column::LoanApplicationId.eq(application.id))
+                .add(entity::credit_bureau_reports::Column::ConsentProvided.eq(true))
+                .add(
+                    entity::credit_bureau_reports::Column::Status
+                        .is_in([STATUS_CONSENTED, STATUS_FAILED]),
+                ),
+        )
+        .order_by_desc(entity::credit_bureau_reports::Column::CreatedAt)
+        .one(state.pgdb.get_ref())
+        .await?
+    else {
+        return Err(DbErr::Custom(
+            "Customer consent must be recorded before pulling a credit report".to_string(),
+        ));
+    };
+
+    let provider = entity::integration_providers::Entity::find_by_id(report.provider_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Credit bureau provider not found".into()))?;
+
+    if provider.provider_status != Some(IntegrationProvidersProvStatus::Active) {
+        return Err(DbErr::Custom(format!(
+            "Credit bureau provider {} is not active",
+            provider.provider_code
+        )));
+    }
+
+    let inquiry = build_inquiry(&application, &report, state).await?;
+
+    let result = if provider.provider_code.eq_ignore_ascii_case("MOCK") {
+        let environment = state
+            .config
+            .get::<String>("app.environment")
+            .unwrap_or_default();
+
+        if environment.eq_ignore_ascii_case("PRODUCTION") {
+            return Err(DbErr::Custom(
+                "Mock credit bureau cannot be used in production".to_string(),
+            ));
+        }
+
+        MockBureauProvider.fetch_report(&inquiry).await
+    } else {
+        let timeout_secs = state
+            .config
+            .get::<u64>("integrations.timeout_secs")
+            .unwrap_or(30);
+
+        match HttpBureauProvider::from_provider(&provider, timeout_secs) {
+            Ok(client) => client.fetch_report(&inquiry).await,
+            Err(err) => Err(err),
+        }
+    };
+
+    let report_id = report.id;
+
+    let mut active_report: entity::credit_bureau_reports::ActiveModel = report.into();
+    active_report.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    let bureau = match result {
+        Ok(bureau) => bureau,
+        Err(err) => {
+            active_report.status = Set(Some(STATUS_FAILED.to_string()));
+            active_report.error_code = Set(Some("PROVIDER_ERROR".to_string()));
+            active_report.error_message = Set(Some(err.to_string()));
+            active_report.update(state.pgdb.get_ref()).await?;
+
+            return Err(err);
+        }
+    };
+
+    let txn = state.pgdb.begin().await?;
+
+    active_report.bureau_customer_id = Set(bureau.bureau_customer_id);
+    active_report.report_data = Set(bureau.report_data.clone());
+    active_report.credit_score = Set(bureau.credit_score);
+    active_report.risk_grade = Set(bureau.risk_grade);
+    active_report.number_of_inquiries = Set(bureau.number_of_inquiries);
+    active_report.inquiries_last30_days = Set(bureau.inquiries_last30_days);
+    active_report.total_accounts = Set(bureau.total_accounts);
+    active_report.active_accounts = Set(bureau.active_accounts);
+    active_report.defaults_count = Set(bureau.defaults_count);
+    active_report.total_outstanding_default_amount = Set(bureau.total_outstanding_default_amount);
+    active_report.status = Set(Some(STATUS_COMPLETED.to_string()));
+    active_report.error_code = Set(None);
+    active_report.error_message = Set(None);
+    active_report.update(&txn).await?;
+
+    let mut active_application: entity::loan_applications::ActiveModel = application.into();
+    active_application.credit_score = Set(bureau.credit_score);
+    active_application.credit_bureau_response = Set(bureau.report_data);
+    active_application.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_application.update(&txn).await?;
+
+    txn.commit().await?;
+
+    get_report(&report_id, state).await
+}
+
+pub async fn get_application_reports(
+    application_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<CreditReportResponseModel>, DbErr> {
+    find_application(application_id, state).await?;
+
+    entity::credit_bureau_reports::Entity::find()
+        .filter(entity::credit_bureau_reports::Column::LoanApplicationId.eq(*application_id))
+        .order_by_desc(entity::credit_bureau_reports::Column::CreatedAt)
+        .into_model::<CreditReportResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await
+}
@@ -0,0 +1,251 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use entity::sea_orm_active_enums::GroupSavingMemberRole;
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::group_savings::{
+        models::{
+            AddMemberModel, AddMemberParams, CreateGroupModel, CreateGroupParams,
+            MemberContributionModel, MemberContributionParams, MemberExitModel, MemberExitParams,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn create_group(
+    req: HttpRequest,
+    payload: web::Json<CreateGroupParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "GROUP_SAVING_CREATE", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let owner_id = id_parser(&data.owner_id, "Owner Id").await?;
+    let savings_product_id = id_parser(&data.savings_product_id, "Savings Product Id").await?;
+    let account_id = id_parser(&data.account_id, "Account Id").await?;
+
+    let group = CreateGroupModel {
+        owner_id,
+        savings_product_id,
+        account_id,
+        goal_name: data.goal_name,
+        target_amount: data.target_amount,
+        target_completion_date: data.target_completion_date,
+        committed_amount: data.committed_amount,
+    };
+
+    match services::create_group(&group, &state).await {
+        Ok(group) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Savings Group Created",
+            group,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn add_member(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<AddMemberParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "GROUP_SAVING_MANAGE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Group Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let requested_by = id_parser(&data.requested_by, "Requested By").await?;
+    let customer_id = id_parser(&data.customer_id, "Customer Id").await?;
+
+    let member = AddMemberModel {
+        requested_by,
+        customer_id,
+        member_role: data.member_role.unwrap_or(GroupSavingMemberRole::Member),
+        committed_amount: data.committed_amount,
+    };
+
+    match services::add_member(&id, &member, &state).await {
+        Ok(member) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Member Added",
+            member,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn contribute(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<MemberContributionParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "GROUP_SAVING_CONTRIBUTE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Group Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let member_id = id_parser(&data.member_id, "Member Id").await?;
+    let account_id = id_parser(&data.account_id, "Account Id").await?;
+
+    let contribution = MemberContributionModel {
+        member_id,
+        account_id,
+        amount: data.amount,
+    };
+
+    match services::contribute(&id, &contribution, &state).await {
+        Ok(contribution) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Contribution Recorded",
+            contribution,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn exit_member(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<MemberExitParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "GROUP_SAVING_MANAGE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Member Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let requested_by = id_parser(&data.requested_by, "Requested By").await?;
+    let account_id = id_parser(&data.account_id, "Account Id").await?;
+
+    let exit = MemberExitModel {
+        requested_by,
+        account_id,
+        reason: data.reason,
+        exited_by: staff.id,
+    };
+
+    match services::exit_member(&id, &exit, &state).await {
+        Ok(member) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Member Exited",
+            member,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn group_details(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Group Id").await?;
+
+    match services::get_group(&id, &state).await {
+        Ok(group) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            group,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn group_ledger(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Group Id").await?;
+
+    match services::get_ledger(&id, &state).await {
+        Ok(ledger) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            ledger,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn member_ledger(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Member Id").await?;
+
+    match services::get_member_ledger(&id, &state).await {
+        Ok(ledger) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            ledger,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
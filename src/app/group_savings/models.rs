@@ -0,0 +1,165 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use entity::sea_orm_active_enums::GroupSavingMemberRole;
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+use crate::app::savings::models::GoalResponseModel;
+
+#[derive(Debug, Clone)]
+pub struct CreateGroupModel {
+    pub owner_id: i64,
+    pub savings_product_id: i64,
+    pub account_id: i64,
+    pub goal_name: String,
+    pub target_amount: Option<i64>,
+    pub target_completion_date: Option<NaiveDate>,
+    pub committed_amount: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct AddMemberModel {
+    pub requested_by: i64,
+    pub customer_id: i64,
+    pub member_role: GroupSavingMemberRole,
+    pub committed_amount: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MemberContributionModel {
+    pub member_id: i64,
+    pub account_id: i64,
+    pub amount: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MemberExitModel {
+    pub requested_by: i64,
+    pub account_id: i64,
+    pub reason: String,
+    pub exited_by: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateGroupParams {
+    #[serde(rename = "ownerId")]
+    pub owner_id: String,
+    #[serde(rename = "savingsProductId")]
+    pub savings_product_id: String,
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+    #[validate(length(min = 2, max = 100, message = "goalName cannot be < 2 and > 100"))]
+    #[serde(rename = "goalName")]
+    pub goal_name: String,
+    #[validate(range(min = 1, message = "targetAmount must be greater than 0"))]
+    #[serde(rename = "targetAmount")]
+    pub target_amount: Option<i64>,
+    #[serde(rename = "targetCompletionDate")]
+    pub target_completion_date: Option<NaiveDate>,
+    #[validate(range(min = 1, message = "committedAmount must be greater than 0"))]
+    #[serde(rename = "committedAmount")]
+    pub committed_amount: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct AddMemberParams {
+    #[serde(rename = "requestedBy")]
+    pub requested_by: String,
+    #[serde(rename = "customerId")]
+    pub customer_id: String,
+    #[serde(rename = "memberRole")]
+    pub member_role: Option<GroupSavingMemberRole>,
+    #[validate(range(min = 1, message = "committedAmount must be greater than 0"))]
+    #[serde(rename = "committedAmount")]
+    pub committed_amount: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct MemberContributionParams {
+    #[serde(rename = "memberId")]
+    pub member_id: String,
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+    #[validate(range(min = 1, message = "amount must be greater than 0"))]
+    pub amount: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct MemberExitParams {
+    #[serde(rename = "requestedBy")]
+    pub requested_by: String,
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+    #[validate(length(min = 2, max = 500, message = "reason cannot be < 2 and > 500"))]
+    pub reason: String,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::group_saving_members::Entity")]
+pub struct MemberResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "group_goal_id")]
+    pub group_goal_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "customer_id")]
+    pub customer_id: i64,
+    #[sea_orm(from_col = "member_role")]
+    pub member_role: Option<GroupSavingMemberRole>,
+    #[sea_orm(from_col = "joined_date")]
+    pub joined_date: Option<NaiveDate>,
+    #[sea_orm(from_col = "committed_amount")]
+    pub committed_amount: Option<i64>,
+    #[sea_orm(from_col = "actual_contributed")]
+    pub actual_contributed: Option<i64>,
+    #[sea_orm(from_col = "is_active")]
+    pub is_active: Option<bool>,
+    #[sea_orm(from_col = "exit_date")]
+    pub exit_date: Option<NaiveDate>,
+    #[sea_orm(from_col = "exit_reason")]
+    pub exit_reason: Option<String>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GroupDetailsModel {
+    pub goal: GoalResponseModel,
+    pub active_members: usize,
+    pub total_committed: i64,
+    pub total_contributed: i64,
+    pub members: Vec<MemberResponseModel>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct LedgerEntryModel {
+    #[serde_as(as = "DisplayFromStr")]
+    pub id: i64,
+    pub entry_type: String,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub customer_id: Option<i64>,
+    pub reference: Option<String>,
+    pub amount: i64,
+    pub penalty_amount: i64,
+    pub balance: i64,
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct GroupLedgerModel {
+    #[serde_as(as = "DisplayFromStr")]
+    pub group_goal_id: i64,
+    pub balance: i64,
+    pub entries: Vec<LedgerEntryModel>,
+}
@@ -0,0 +1,51 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::group_savings::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/group-savings")
+            .route(
+                "",
+                web::post()
+                    .to(controllers::create_group)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}",
+                web::get()
+                    .to(controllers::group_details)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/members",
+                web::post()
+                    .to(controllers::add_member)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/contributions",
+                web::post()
+                    .to(controllers::contribute)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/ledger",
+                web::get()
+                    .to(controllers::group_ledger)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/members/{id}/exit",
+                web::put()
+                    .to(controllers::exit_member)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/members/{id}/ledger",
+                web::get()
+                    .to(controllers::member_ledger)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
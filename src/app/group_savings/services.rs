@@ -0,0 +1,650 @@
+use actix_web::web;
+use entity::sea_orm_active_enums::{ContributionStatus, GroupSavingMemberRole, SavingGoalsStatus};
+use migration::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+
+use crate::{
+    AppState,
+    app::{
+        group_savings::models::{
+            AddMemberModel, CreateGroupModel, GroupDetailsModel, GroupLedgerModel,
+            LedgerEntryModel, MemberContributionModel, MemberExitModel, MemberResponseModel,
+        },
+        savings::{
+            models::{ContributionResponseModel, GoalResponseModel},
+            services::{
+                credit_account, ensure_contribution_bounds, ensure_customer_account,
+                ensure_target_bounds, find_product, lock_goal, post_payout, post_penalty,
+                record_contribution, withdrawal_penalty,
+            },
+        },
+    },
+    utils::{finance::progress_percentage, gen_snow_ids::gen_snowflake_slug},
+};
+
+async fn find_group<C: ConnectionTrait>(
+    conn: &C,
+    group_goal_id: i64,
+) -> Result<entity::saving_goals::Model, DbErr> {
+    entity::saving_goals::Entity::find_by_id(group_goal_id)
+        .filter(entity::saving_goals::Column::IsGroupSavings.eq(true))
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Group goal not found".into()))
+}
+
+async fn find_member<C: ConnectionTrait>(
+    conn: &C,
+    member_id: i64,
+) -> Result<entity::group_saving_members::Model, DbErr> {
+    entity::group_saving_members::Entity::find_by_id(member_id)
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Group member not found".into()))
+}
+
+// Holds the member row for the rest of the transaction so an exit pays out once
+async fn lock_member<C: ConnectionTrait>(
+    conn: &C,
+    member_id: i64,
+) -> Result<entity::group_saving_members::Model, DbErr> {
+    entity::group_saving_members::Entity::find_by_id(member_id)
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Group member not found".into()))
+}
+
+// Membership changes are requested by an active admin of the group
+async fn ensure_group_admin<C: ConnectionTrait>(
+    conn: &C,
+    group_goal_id: i64,
+    member_id: i64,
+) -> Result<(), DbErr> {
+    let member = find_member(conn, member_id).await?;
+
+    if member.group_goal_id != group_goal_id
+        || !member.is_active.unwrap_or(false)
+        || member.member_role != Some(GroupSavingMemberRole::Admin)
+    {
+        return Err(DbErr::Custom(
+            "Only an active group admin can change the group's membership".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+async fn active_member_count<C: ConnectionTrait>(
+    conn: &C,
+    group_goal_id: i64,
+) -> Result<u64, DbErr> {
+    entity::group_saving_members::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::group_saving_members::Column::GroupGoalId.eq(group_goal_id))
+                .add(entity::group_saving_members::Column::IsActive.eq(true)),
+        )
+        .count(conn)
+        .await
+}
+
+async fn member_response(
+    member_id: i64,
+    state: &web::Data<AppState>,
+) -> Result<MemberResponseModel, DbErr> {
+    entity::group_saving_members::Entity::find_by_id(member_id)
+        .into_model::<MemberResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Group member not found".into()))
+}
+
+// The owner opens the group as its first admin member
+pub async fn create_group(
+    model: &CreateGroupModel,
+    state: &web::Data<AppState>,
+) -> Result<GroupDetailsModel, DbErr> {
+    let data = model.clone();
+
+    let product = find_product(state.pgdb.get_ref(), data.savings_product_id).await?;
+
+    if !product.is_active.unwrap_or(true) {
+        return Err(DbErr::Custom("Savings product is not active".to_string()));
+    }
+
+    if !product.is_group_savings_allowed.unwrap_or(false) {
+        return Err(DbErr::Custom(
+            "Savings product does not allow group savings".to_string(),
+        ));
+    }
+
+    let owner = entity::customers::Entity::find_by_id(data.owner_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Customer not found".into()))?;
+
+    if owner.institution_id != product.institution_id {
+        return Err(DbErr::Custom(
+            "Savings product is not offered to this customer".to_string(),
+        ));
+    }
+
+    ensure_customer_account(state.pgdb.get_ref(), owner.id, data.account_id).await?;
+    ensure_target_bounds(&product, data.target_amount)?;
+    ensure_contribution_bounds(&product, data.committed_amount)?;
+
+    let today = chrono::Utc::now().date_naive();
+
+    if data
+        .target_completion_date
+        .is_some_and(|date| date <= today)
+    {
+        return Err(DbErr::Custom(
+            "targetCompletionDate must be in the future".to_string(),
+        ));
+    }
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let (member_id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let txn = state.pgdb.begin().await?;
+
+    entity::saving_goals::ActiveModel {
+        id: Set(id),
+        institution_id: Set(product.institution_id),
+        customer_id: Set(owner.id),
+        savings_product_id: Set(product.id),
+        account_id: Set(data.account_id),
+        goal_name: Set(Some(data.goal_name)),
+        target_amount: Set(data.target_amount),
+        current_amount: Set(Some(0)),
+        start_date: Set(today),
+        target_completion_date: Set(data.target_completion_date),
+        contribution_amount: Set(Some(data.committed_amount)),
+        contribution_freq: Set(product.contribution_freq.clone()),
+        status: Set(Some(SavingGoalsStatus::Active)),
+        progress_percentage: Set(Some(0)),
+        is_group_savings: Set(Some(true)),
+        group_owner_id: Set(Some(owner.id)),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+
+    entity::group_saving_members::ActiveModel {
+        id: Set(member_id),
+        group_goal_id: Set(id),
+        customer_id: Set(owner.id),
+        member_role: Set(Some(GroupSavingMemberRole::Admin)),
+        joined_date: Set(Some(today)),
+        committed_amount: Set(Some(data.committed_amount)),
+        actual_contributed: Set(Some(0)),
+        is_active: Set(Some(true)),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+
+    txn.commit().await?;
+
+    get_group(&id, state).await
+}
+
+// A member who left earlier can be invited back; their previous share has
+// already been paid out, so they rejoin with nothing contributed
+pub async fn add_member(
+    group_goal_id: &i64,
+    model: &AddMemberModel,
+    state: &web::Data<AppState>,
+) -> Result<MemberResponseModel, DbErr> {
+    let data = model.clone();
+
+    let goal = find_group(state.pgdb.get_ref(), *group_goal_id).await?;
+
+    if !matches!(
+        goal.status,
+        Some(SavingGoalsStatus::Active) | Some(SavingGoalsStatus::Paused)
+    ) {
+        return Err(DbErr::Custom(
+            "Members can only join active groups".to_string(),
+        ));
+    }
+
+    ensure_group_admin(state.pgdb.get_ref(), goal.id, data.requested_by).await?;
+
+    let product = find_product(state.pgdb.get_ref(), goal.savings_product_id).await?;
+
+    ensure_contribution_bounds(&product, data.committed_amount)?;
+
+    let customer = entity::customers::Entity::find_by_id(data.customer_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Customer not found".into()))?;
+
+    if customer.institution_id != goal.institution_id {
+        return Err(DbErr::Custom(
+            "Customer does not belong to the group's institution".to_string(),
+        ));
+    }
+
+    let members = active_member_count(state.pgdb.get_ref(), goal.id).await?;
+
+    if let Some(max) = product.max_group_members
+        && members >= max.max(0) as u64
+    {
+        return Err(DbErr::Custom(format!(
+            "Group already has the maximum of {} members",
+            max
+        )));
+    }
+
+    let existing = entity::group_saving_members::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::group_saving_members::Column::GroupGoalId.eq(goal.id))
+                .add(entity::group_saving_members::Column::CustomerId.eq(customer.id)),
+        )
+        .one(state.pgdb.get_ref())
+        .await?;
+
+    let today = chrono::Utc::now().date_naive();
+
+    let id = match existing {
+        Some(member) if member.is_active.unwrap_or(false) => {
+            return Err(DbErr::Custom(
+                "Customer is already a member of this group".to_string(),
+            ));
+        }
+        Some(member) => {
+            let id = member.id;
+            let mut active_member: entity::group_saving_members::ActiveModel = member.into();
+
+            active_member.member_role = Set(Some(data.member_role));
+            active_member.joined_date = Set(Some(today));
+            active_member.committed_amount = Set(Some(data.committed_amount));
+            active_member.actual_contributed = Set(Some(0));
+            active_member.is_active = Set(Some(true));
+            active_member.exit_date = Set(None);
+            active_member.exit_reason = Set(None);
+            active_member.updated_at = Set(Some(chrono::Utc::now().into()));
+
+            ActiveModelTrait::update(active_member, state.pgdb.get_ref()).await?;
+
+            id
+        }
+        None => {
+            let (id, _) = match gen_snowflake_slug() {
+                Ok(res) => res,
+                Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+            };
+
+            entity::group_saving_members::ActiveModel {
+                id: Set(id),
+                group_goal_id: Set(goal.id),
+                customer_id: Set(customer.id),
+                member_role: Set(Some(data.member_role)),
+                joined_date: Set(Some(today)),
+                committed_amount: Set(Some(data.committed_amount)),
+                actual_contributed: Set(Some(0)),
+                is_active: Set(Some(true)),
+                ..Default::default()
+            }
+            .insert(state.pgdb.get_ref())
+            .await?;
+
+            id
+        }
+    };
+
+    member_response(id, state).await
+}
+
+// Debits the member's own account into the group pot; the group only takes
+// contributions once it has reached the product's minimum membership
+pub async fn contribute(
+    group_goal_id: &i64,
+    model: &MemberContributionModel,
+    state: &web::Data<AppState>,
+) -> Result<ContributionResponseModel, DbErr> {
+    let data = model.clone();
+
+    let goal = find_group(state.pgdb.get_ref(), *group_goal_id).await?;
+
+    if goal.status != Some(SavingGoalsStatus::Active) {
+        return Err(DbErr::Custom(
+            "Contributions can only be made to active groups".to_string(),
+        ));
+    }
+
+    let member = find_member(state.pgdb.get_ref(), data.member_id).await?;
+
+    if member.group_goal_id != goal.id || !member.is_active.unwrap_or(false) {
+        return Err(DbErr::Custom(
+            "Customer is not an active member of this group".to_string(),
+        ));
+    }
+
+    let product = find_product(state.pgdb.get_ref(), goal.savings_product_id).await?;
+
+    let members = active_member_count(state.pgdb.get_ref(), goal.id).await?;
+
+    if let Some(min) = product.min_group_members
+        && members < min.max(0) as u64
+    {
+        return Err(DbErr::Custom(format!(
+            "Group needs at least {} members before contributions start",
+            min
+        )));
+    }
+
+    ensure_contribution_bounds(&product, data.amount)?;
+    ensure_customer_account(state.pgdb.get_ref(), member.customer_id, data.account_id).await?;
+
+    let customer_id = member.customer_id;
+
+    let txn = state.pgdb.begin().await?;
+
+    let contribution_id =
+        record_contribution(&txn, goal.id, customer_id, data.account_id, data.amount).await?;
+
+    let result = entity::group_saving_members::Entity::update_many()
+        .col_expr(
+            entity::group_saving_members::Column::ActualContributed,
+            Expr::col(entity::group_saving_members::Column::ActualContributed)
+                .if_null(0)
+                .add(data.amount),
+        )
+        .col_expr(
+            entity::group_saving_members::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(
+            Condition::all()
+                .add(entity::group_saving_members::Column::Id.eq(member.id))
+                .add(entity::group_saving_members::Column::IsActive.eq(true)),
+        )
+        .exec(&txn)
+        .await?;
+
+    if result.rows_affected == 0 {
+        return Err(DbErr::Custom(
+            "Customer is not an active member of this group".to_string(),
+        ));
+    }
+
+    txn.commit().await?;
+
+    entity::contributions::Entity::find_by_id(contribution_id)
+        .into_model::<ContributionResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Contribution not found".into()))
+}
+
+// An exiting member is paid out what they actually contributed, less any
+// early withdrawal penalty; the group is abandoned once nobody is left
+pub async fn exit_member(
+    member_id: &i64,
+    model: &MemberExitModel,
+    state: &web::Data<AppState>,
+) -> Result<MemberResponseModel, DbErr> {
+    let data = model.clone();
+
+    let txn = state.pgdb.begin().await?;
+
+    // The goal is locked before the member, the same order contributions and
+    // cycle closes take them in
+    let group_goal_id = find_member(&txn, *member_id).await?.group_goal_id;
+
+    let goal = lock_goal(&txn, group_goal_id).await?;
+
+    if !goal.is_group_savings.unwrap_or(false) {
+        return Err(DbErr::RecordNotFound("Group goal not found".into()));
+    }
+
+    let member = lock_member(&txn, *member_id).await?;
+
+    if !member.is_active.unwrap_or(false) {
+        return Err(DbErr::Custom(
+            "Member has already left the group".to_string(),
+        ));
+    }
+
+    // Members may leave on their own; anyone else is removed by an admin
+    if data.requested_by != member.id {
+        ensure_group_admin(&txn, goal.id, data.requested_by).await?;
+    }
+
+    let members = active_member_count(&txn, goal.id).await?;
+
+    if goal.group_owner_id == Some(member.customer_id) && members > 1 {
+        return Err(DbErr::Custom(
+            "The group owner cannot exit while other members remain".to_string(),
+        ));
+    }
+
+    ensure_customer_account(&txn, member.customer_id, data.account_id).await?;
+
+    let product = find_product(&txn, goal.savings_product_id).await?;
+
+    let today = chrono::Utc::now().date_naive();
+    let share = member.actual_contributed.unwrap_or(0);
+
+    let (is_early_withdrawal, penalty_amount) = if share > 0 {
+        withdrawal_penalty(&goal, &product, share, today)?
+    } else {
+        (false, 0)
+    };
+    let net_amount = share - penalty_amount;
+
+    if share > 0 {
+        let (withdrawal_id, slug) = match gen_snowflake_slug() {
+            Ok(res) => res,
+            Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+        };
+
+        if net_amount > 0 {
+            credit_account(&txn, data.account_id, net_amount).await?;
+        }
+
+        post_payout(
+            &txn,
+            &product,
+            net_amount,
+            format!("Exit payout from group saving goal {}", goal.id),
+            slug.clone(),
+            Some(data.exited_by),
+        )
+        .await?;
+
+        post_penalty(
+            &txn,
+            &product,
+            penalty_amount,
+            format!("Early exit penalty on group saving goal {}", goal.id),
+            format!("SWP-{}", slug),
+            Some(data.exited_by),
+        )
+        .await?;
+
+        entity::saving_goal_withdrawals::ActiveModel {
+            id: Set(withdrawal_id),
+            saving_goal_id: Set(goal.id),
+            account_id: Set(data.account_id),
+            amount: Set(share),
+            penalty_amount: Set(penalty_amount),
+            net_amount: Set(net_amount),
+            is_early_withdrawal: Set(is_early_withdrawal),
+            reference_number: Set(slug),
+            withdrawn_by: Set(Some(data.exited_by)),
+            customer_id: Set(Some(member.customer_id)),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+    }
+
+    let mut active_member: entity::group_saving_members::ActiveModel = member.into();
+    active_member.is_active = Set(Some(false));
+    active_member.exit_date = Set(Some(today));
+    active_member.exit_reason = Set(Some(data.reason));
+    active_member.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_member.update(&txn).await?;
+
+    let remaining = (goal.current_amount.unwrap_or(0) - share).max(0);
+    let target_amount = goal.target_amount;
+
+    let mut active_goal: entity::saving_goals::ActiveModel = goal.into();
+
+    active_goal.current_amount = Set(Some(remaining));
+
+    if let Some(target) = target_amount {
+        active_goal.progress_percentage = Set(Some(progress_percentage(remaining, target)));
+    }
+
+    if members <= 1 {
+        active_goal.status = Set(Some(SavingGoalsStatus::Abandoned));
+    }
+
+    active_goal.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_goal.update(&txn).await?;
+
+    txn.commit().await?;
+
+    member_response(*member_id, state).await
+}
+
+pub async fn get_group(
+    group_goal_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<GroupDetailsModel, DbErr> {
+    find_group(state.pgdb.get_ref(), *group_goal_id).await?;
+
+    let goal = entity::saving_goals::Entity::find_by_id(*group_goal_id)
+        .into_model::<GoalResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Group goal not found".into()))?;
+
+    let members = entity::group_saving_members::Entity::find()
+        .filter(entity::group_saving_members::Column::GroupGoalId.eq(*group_goal_id))
+        .order_by_asc(entity::group_saving_members::Column::JoinedDate)
+        .into_model::<MemberResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let active: Vec<&MemberResponseModel> = members
+        .iter()
+        .filter(|member| member.is_active.unwrap_or(false))
+        .collect();
+
+    Ok(GroupDetailsModel {
+        goal,
+        active_members: active.len(),
+        total_committed: active
+            .iter()
+            .map(|member| member.committed_amount.unwrap_or(0))
+            .sum(),
+        total_contributed: active
+            .iter()
+            .map(|member| member.actual_contributed.unwrap_or(0))
+            .sum(),
+        members,
+    })
+}
+
+// Every contribution, missed-contribution penalty and exit payout on the
+// group in order, with the pot balance after each entry
+pub async fn get_ledger(
+    group_goal_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<GroupLedgerModel, DbErr> {
+    find_group(state.pgdb.get_ref(), *group_goal_id).await?;
+
+    let contributions = entity::contributions::Entity::find()
+        .filter(entity::contributions::Column::SavingGoalId.eq(*group_goal_id))
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let withdrawals = entity::saving_goal_withdrawals::Entity::find()
+        .filter(entity::saving_goal_withdrawals::Column::SavingGoalId.eq(*group_goal_id))
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    // Missed contributions only move the pot by the penalty taken
+    let mut entries: Vec<LedgerEntryModel> = contributions
+        .into_iter()
+        .filter_map(|contribution| {
+            let penalty = contribution.penalty_applied.unwrap_or(0);
+
+            let (entry_type, amount) = if contribution.is_missed_contribution.unwrap_or(false) {
+                ("MISSED_CONTRIBUTION_PENALTY", -penalty)
+            } else if contribution.status == Some(ContributionStatus::Completed) {
+                ("CONTRIBUTION", contribution.amount)
+            } else {
+                return None;
+            };
+
+            if amount == 0 {
+                return None;
+            }
+
+            Some(LedgerEntryModel {
+                id: contribution.id,
+                entry_type: entry_type.to_string(),
+                customer_id: Some(contribution.customer_id),
+                reference: contribution.contribution_reference,
+                amount,
+                penalty_amount: if amount < 0 { penalty } else { 0 },
+                balance: 0,
+                created_at: contribution.created_at,
+            })
+        })
+        .chain(withdrawals.into_iter().map(|withdrawal| LedgerEntryModel {
+            id: withdrawal.id,
+            entry_type: "EXIT_PAYOUT".to_string(),
+            customer_id: withdrawal.customer_id,
+            reference: Some(withdrawal.reference_number),
+            amount: -withdrawal.amount,
+            penalty_amount: withdrawal.penalty_amount,
+            balance: 0,
+            created_at: withdrawal.created_at,
+        }))
+        .collect();
+
+    entries.sort_by_key(|entry| (entry.created_at, entry.id));
+
+    let mut balance = 0;
+
+    for entry in entries.iter_mut() {
+        balance += entry.amount;
+        entry.balance = balance;
+    }
+
+    Ok(GroupLedgerModel {
+        group_goal_id: *group_goal_id,
+        balance,
+        entries,
+    })
+}
+
+// Members, including those who have exited, see the full group ledger
+pub async fn get_member_ledger(
+    member_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<GroupLedgerModel, DbErr> {
+    let member = find_member(state.pgdb.get_ref(), *member_id).await?;
+
+    get_ledger(&member.group_goal_id, state).await
+}
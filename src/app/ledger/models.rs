@@ -0,0 +1,14 @@
+use chrono::NaiveDate;
+
+#[derive(Debug, Clone)]
+pub struct GlEntryModel {
+    pub institution_id: i64,
+    pub debit_account_id: i64,
+    pub credit_account_id: i64,
+    pub amount: i64,
+    pub narration: String,
+    pub reference_number: Option<String>,
+    pub transaction_id: Option<i64>,
+    pub value_date: NaiveDate,
+    pub posted_by: Option<i64>,
+}
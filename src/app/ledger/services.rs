@@ -0,0 +1,47 @@
+use sea_orm::{ActiveModelTrait, ActiveValue::Set, ConnectionTrait, DbErr};
+
+use crate::{app::ledger::models::GlEntryModel, utils::gen_snow_ids::gen_snowflake_slug};
+
+// Takes any connection so callers can post inside their own transaction
+pub async fn post<C: ConnectionTrait>(
+    conn: &C,
+    entry: &GlEntryModel,
+) -> Result<entity::gl_postings::Model, DbErr> {
+    if entry.amount <= 0 {
+        return Err(DbErr::Custom(
+            "GL posting amount must be greater than 0".to_string(),
+        ));
+    }
+
+    if entry.debit_account_id == entry.credit_account_id {
+        return Err(DbErr::Custom(
+            "GL posting cannot debit and credit the same account".to_string(),
+        ));
+    }
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let data = entry.clone();
+
+    entity::gl_postings::ActiveModel {
+        id: Set(id),
+        institution_id: Set(data.institution_id),
+        transaction_id: Set(data.transaction_id),
+        reference_number: Set(data.reference_number),
+        value_date: Set(data.value_date),
+        posting_date: Set(Some(chrono::Utc::now().into())),
+        debit_account_id: Set(data.debit_account_id),
+        debit_amount: Set(data.amount),
+        credit_account_id: Set(data.credit_account_id),
+        credit_amount: Set(data.amount),
+        narration: Set(data.narration),
+        is_reversed: Set(Some(false)),
+        reversal_posting_id: Set(None),
+        posted_by: Set(data.posted_by),
+    }
+    .insert(conn)
+    .await
+}
@@ -0,0 +1,182 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::loan_applications::{
+        models::{
+            ApproveApplicationModel, ApproveApplicationParams, CreateApplicationModel,
+            CreateApplicationParams, RejectApplicationModel, RejectApplicationParams,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn create_application(
+    req: HttpRequest,
+    payload: web::Json<CreateApplicationParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_APPLICATION_CREATE", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let customer_id = id_parser(&data.customer_id, "Customer Id").await?;
+
+    let loan_product_id = id_parser(&data.loan_product_id, "Loan Product Id").await?;
+
+    let application = CreateApplicationModel {
+        customer_id,
+        loan_product_id,
+        requested_principal: data.requested_principal,
+        requested_tenure_days: data.requested_tenure_days,
+        purpose: data.purpose,
+        application_data: data.application_data,
+        employment_details: data.employment_details,
+        financial_details: data.financial_details,
+        refinanced_loan_id: None,
+        created_by: staff.id,
+    };
+
+    match services::create_application(&application, &state).await {
+        Ok(application) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Loan Application Created",
+            application,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn submit_application(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_APPLICATION_CREATE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Application Id").await?;
+
+    match services::submit_application(&id, staff.id, &state).await {
+        Ok(application) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Loan Application Submitted",
+            application,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn loan_application(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Application Id").await?;
+
+    match services::get_application(&id, &state).await {
+        Ok(application) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            application,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn approve_application(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<ApproveApplicationParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_APPLICATION_APPROVE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Application Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let approval = ApproveApplicationModel {
+        approved_principal: data.approved_principal,
+        approved_tenure_days: data.approved_tenure_days,
+        approved_interest_rate: data.approved_interest_rate,
+        conditions: data.conditions,
+        approved_by: staff.id,
+    };
+
+    match services::approve_application(&id, &approval, &state).await {
+        Ok(application) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Loan Application Approved",
+            application,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn reject_application(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<RejectApplicationParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_APPLICATION_APPROVE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Application Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let rejection = RejectApplicationModel {
+        reason: payload.into_inner().reason,
+        rejected_by: staff.id,
+    };
+
+    match services::reject_application(&id, &rejection, &state).await {
+        Ok(application) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Loan Application Rejected",
+            application,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
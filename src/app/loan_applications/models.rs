@@ -0,0 +1,155 @@
+use chrono::{DateTime, FixedOffset};
+use entity::sea_orm_active_enums::{LoanApplicationStatus, LoanRiskRating};
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+use crate::utils::validators::validate_percentage;
+
+#[derive(Debug, Clone)]
+pub struct CreateApplicationModel {
+    pub customer_id: i64,
+    pub loan_product_id: i64,
+    pub requested_principal: i64,
+    pub requested_tenure_days: i32,
+    pub purpose: Option<String>,
+    pub application_data: Option<Value>,
+    pub employment_details: Option<Value>,
+    pub financial_details: Option<Value>,
+    pub refinanced_loan_id: Option<i64>,
+    pub created_by: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApproveApplicationModel {
+    pub approved_principal: Option<i64>,
+    pub approved_tenure_days: Option<i32>,
+    pub approved_interest_rate: Option<Decimal>,
+    pub conditions: Option<Value>,
+    pub approved_by: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct RejectApplicationModel {
+    pub reason: String,
+    pub rejected_by: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateApplicationParams {
+    #[serde(rename = "customerId")]
+    pub customer_id: String,
+    #[serde(rename = "loanProductId")]
+    pub loan_product_id: String,
+    #[validate(range(min = 1, message = "requestedPrincipal must be greater than 0"))]
+    #[serde(rename = "requestedPrincipal")]
+    pub requested_principal: i64,
+    #[validate(range(min = 1, message = "requestedTenureDays must be greater than 0"))]
+    #[serde(rename = "requestedTenureDays")]
+    pub requested_tenure_days: i32,
+    #[validate(length(min = 2, max = 500, message = "purpose cannot be < 2 and > 500"))]
+    pub purpose: Option<String>,
+    #[serde(rename = "applicationData")]
+    pub application_data: Option<Value>,
+    #[serde(rename = "employmentDetails")]
+    pub employment_details: Option<Value>,
+    #[serde(rename = "financialDetails")]
+    pub financial_details: Option<Value>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ApproveApplicationParams {
+    #[validate(range(min = 1, message = "approvedPrincipal must be greater than 0"))]
+    #[serde(rename = "approvedPrincipal")]
+    pub approved_principal: Option<i64>,
+    #[validate(range(min = 1, message = "approvedTenureDays must be greater than 0"))]
+    #[serde(rename = "approvedTenureDays")]
+    pub approved_tenure_days: Option<i32>,
+    #[validate(custom(function = "validate_percentage"))]
+    #[serde(rename = "approvedInterestRate")]
+    pub approved_interest_rate: Option<Decimal>,
+    pub conditions: Option<Value>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RejectApplicationParams {
+    #[validate(length(min = 5, max = 255, message = "reason cannot be < 5 and > 255"))]
+    pub reason: String,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_applications::Entity")]
+pub struct LoanApplicationResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "institution_id")]
+    pub institution_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "loan_product_id")]
+    pub loan_product_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "customer_id")]
+    pub customer_id: i64,
+    #[sea_orm(from_col = "application_number")]
+    pub application_number: Option<String>,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<LoanApplicationStatus>,
+    #[sea_orm(from_col = "requested_principal")]
+    pub requested_principal: i64,
+    #[sea_orm(from_col = "requested_tenure_days")]
+    pub requested_tenure_days: i32,
+    #[sea_orm(from_col = "purpose")]
+    pub purpose: Option<String>,
+    #[sea_orm(from_col = "approved_principal")]
+    pub approved_principal: Option<i64>,
+    #[sea_orm(from_col = "approved_tenure_days")]
+    pub approved_tenure_days: Option<i32>,
+    #[sea_orm(from_col = "approved_interest_rate")]
+    pub approved_interest_rate: Option<Decimal>,
+    #[sea_orm(from_col = "credit_score")]
+    pub credit_score: Option<i32>,
+    #[sea_orm(from_col = "risk_rating")]
+    pub risk_rating: Option<LoanRiskRating>,
+    #[sea_orm(from_col = "documents_missing")]
+    pub documents_missing: Option<Value>,
+    #[sea_orm(from_col = "current_stage")]
+    pub current_stage: Option<String>,
+    #[sea_orm(from_col = "submitted_at")]
+    pub submitted_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "approved_at")]
+    pub approved_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "approval_conditions")]
+    pub approval_conditions: Option<Value>,
+    #[sea_orm(from_col = "rejected_reason")]
+    pub rejected_reason: Option<String>,
+    #[sea_orm(from_col = "rejected_at")]
+    pub rejected_at: Option<DateTime<FixedOffset>>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "assigned_officer")]
+    pub assigned_officer: Option<i64>,
+    #[sea_orm(from_col = "assigned_at")]
+    pub assigned_at: Option<DateTime<FixedOffset>>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "branch_id")]
+    pub branch_id: Option<i64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "approved_by")]
+    pub approved_by: Option<i64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "rejected_by")]
+    pub rejected_by: Option<i64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "refinanced_loan_id")]
+    pub refinanced_loan_id: Option<i64>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
@@ -0,0 +1,39 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loan_applications::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loan-applications")
+            .route(
+                "",
+                web::post()
+                    .to(controllers::create_application)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}",
+                web::get()
+                    .to(controllers::loan_application)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/submit",
+                web::put()
+                    .to(controllers::submit_application)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/approve",
+                web::put()
+                    .to(controllers::approve_application)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/reject",
+                web::put()
+                    .to(controllers::reject_application)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
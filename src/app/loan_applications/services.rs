@@ -0,0 +1,460 @@
+use actix_web::web;
+use entity::sea_orm_active_enums::LoanApplicationStatus;
+use sea_orm::{
+    ActiveEnum, ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+use serde_json::{Value, json};
+
+use crate::{
+    AppState,
+    app::{
+        loan_applications::models::{
+            ApproveApplicationModel, CreateApplicationModel, LoanApplicationResponseModel,
+            RejectApplicationModel,
+        },
+        loan_approvals::services::{approval_authority, required_approvals},
+        loan_assignments::services::auto_assign,
+        loan_collaterals::services::collateral_coverage,
+        loan_documents::services::{ensure_documents_complete, refresh_documents},
+        loan_eligibility::services::ensure_eligible,
+        loan_guarantors::services::consented_guarantor_count,
+    },
+    utils::gen_snow_ids::gen_snowflake_slug,
+};
+
+async fn find_application(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<entity::loan_applications::Model, DbErr> {
+    entity::loan_applications::Entity::find_by_id(*id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan application not found".into()))
+}
+
+async fn find_product(
+    id: i64,
+    state: &web::Data<AppState>,
+) -> Result<entity::loan_products::Model, DbErr> {
+    entity::loan_products::Entity::find_by_id(id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan product not found".into()))
+}
+
+pub async fn record_status_change<C: ConnectionTrait>(
+    conn: &C,
+    application_id: i64,
+    from_status: Option<&LoanApplicationStatus>,
+    to_status: &LoanApplicationStatus,
+    reason: Value,
+    changed_by: i64,
+) -> Result<(), DbErr> {
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::loan_application_status_history::ActiveModel {
+        id: Set(id),
+        loan_application_id: Set(application_id),
+        from_status: Set(from_status.map(|status| status.to_value())),
+        to_status: Set(Some(to_status.to_value())),
+        transition_reason: Set(Some(reason)),
+        changed_at: Set(Some(chrono::Utc::now().into())),
+        changed_by: Set(Some(changed_by)),
+        ..Default::default()
+    }
+    .insert(conn)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn get_application(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<LoanApplicationResponseModel, DbErr> {
+    entity::loan_applications::Entity::find_by_id(*id)
+        .into_model::<LoanApplicationResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan application not found".into()))
+}
+
+pub async fn create_application(
+    model: &CreateApplicationModel,
+    state: &web::Data<AppState>,
+) -> Result<LoanApplicationResponseModel, DbErr> {
+    let data = model.clone();
+
+    let product = find_product(data.loan_product_id, state).await?;
+
+    if !product.is_active.unwrap_or(true) {
+        return Err(DbErr::Custom("Loan product is not active".to_string()));
+    }
+
+    let customer = entity::customers::Entity::find_by_id(data.customer_id)
+        .filter(entity::customers::Column::InstitutionId.eq(product.institution_id))
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Customer not found".into()))?;
+
+    if data.requested_principal < product.minimum_principal
+        || data.requested_principal > product.maximum_principal
+    {
+        return Err(DbErr::Custom(format!(
+            "Requested principal must be between {} and {}",
+            product.minimum_principal, product.maximum_principal
+        )));
+    }
+
+    if data.requested_tenure_days < product.minimum_tenure_days
+        || data.requested_tenure_days > product.maximum_tenure_days
+    {
+        return Err(DbErr::Custom(format!(
+            "Requested tenure must be between {} and {} days",
+            product.minimum_tenure_days, product.maximum_tenure_days
+        )));
+    }
+
+    // Applications belong to the branch of the staff member capturing them
+    let branch_id = entity::staff::Entity::find_by_id(data.created_by)
+        .one(state.pgdb.get_ref())
+        .await?
+        .and_then(|staff| staff.branch_id);
+
+    let (id, slug) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let txn = state.pgdb.begin().await?;
+
+    entity::loan_applications::ActiveModel {
+        id: Set(id),
+        institution_id: Set(product.institution_id),
+        loan_product_id: Set(product.id),
+        customer_id: Set(customer.id),
+        application_number: Set(Some(slug)),
+        status: Set(Some(LoanApplicationStatus::Draft)),
+        requested_principal: Set(data.requested_principal),
+        requested_tenure_days: Set(data.requested_tenure_days),
+        purpose: Set(data.purpose),
+        application_data: Set(data.application_data),
+        employment_details: Set(data.employment_details),
+        financial_details: Set(data.financial_details),
+        refinanced_loan_id: Set(data.refinanced_loan_id),
+        branch_id: Set(branch_id),
+        current_stage: Set(Some("DRAFT".to_string())),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+
+    refresh_documents(&txn, id, product.id).await?;
+
+    auto_assign(&txn, id, product.institution_id, branch_id, state).await?;
+
+    record_status_change(
+        &txn,
+        id,
+        None,
+        &LoanApplicationStatus::Draft,
+        json!({ "reason": "Application created" }),
+        data.created_by,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    get_application(&id, state).await
+}
+
+pub async fn submit_application(
+    id: &i64,
+    submitted_by: i64,
+    state: &web::Data<AppState>,
+) -> Result<LoanApplicationResponseModel, DbErr> {
+    let application = find_application(id, state).await?;
+
+    if application.status != Some(LoanApplicationStatus::Draft) {
+        return Err(DbErr::Custom(
+            "Only draft applications can be submitted".to_string(),
+        ));
+    }
+
+    ensure_documents_complete(
+        state.pgdb.get_ref(),
+        application.id,
+        application.loan_product_id,
+    )
+    .await?;
+
+    let eligibility = ensure_eligible(
+        &application.customer_id,
+        &application.loan_product_id,
+        application.credit_score,
+        state,
+    )
+    .await?;
+
+    let txn = state.pgdb.begin().await?;
+
+    let from_status = application.status.clone();
+
+    let mut active_application: entity::loan_applications::ActiveModel = application.into();
+    active_application.status = Set(Some(LoanApplicationStatus::Pending));
+    active_application.submitted_at = Set(Some(chrono::Utc::now().into()));
+    active_application.submitted_by = Set(Some(submitted_by));
+    active_application.current_stage = Set(Some("REVIEW".to_string()));
+    active_application.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_application.update(&txn).await?;
+
+    record_status_change(
+        &txn,
+        *id,
+        from_status.as_ref(),
+        &LoanApplicationStatus::Pending,
+        json!({ "eligibility": eligibility.rules }),
+        submitted_by,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    get_application(id, state).await
+}
+
+pub async fn approve_application(
+    id: &i64,
+    model: &ApproveApplicationModel,
+    state: &web::Data<AppState>,
+) -> Result<LoanApplicationResponseModel, DbErr> {
+    let data = model.clone();
+
+    let txn = state.pgdb.begin().await?;
+
+    // The application stays locked until commit so concurrent approvers are
+    // counted one after the other and a collateral release cannot slip in
+    // between the coverage check and the approval
+    let application = entity::loan_applications::Entity::find_by_id(*id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan application not found".into()))?;
+
+    if application.status != Some(LoanApplicationStatus::Pending) {
+        return Err(DbErr::Custom(
+            "Only pending applications can be approved".to_string(),
+        ));
+    }
+
+    let product = find_product(application.loan_product_id, state).await?;
+
+    let prior_approvals = entity::loan_application_approvals::Entity::find()
+        .filter(entity::loan_application_approvals::Column::LoanApplicationId.eq(application.id))
+        .order_by_asc(entity::loan_application_approvals::Column::ApprovalLevel)
+        .all(&txn)
+        .await?;
+
+    if prior_approvals
+        .iter()
+        .any(|approval| approval.approved_by == data.approved_by)
+    {
+        return Err(DbErr::Custom(
+            "Approver has already approved this application".to_string(),
+        ));
+    }
+
+    // Later approvers sign off on the terms set by the first approver
+    let first_approval = prior_approvals.first();
+
+    let principal = data
+        .approved_principal
+        .or(first_approval.map(|approval| approval.approved_principal))
+        .unwrap_or(application.requested_principal);
+    let tenure_days = data
+        .approved_tenure_days
+        .or(first_approval.map(|approval| approval.approved_tenure_days))
+        .unwrap_or(application.requested_tenure_days);
+    let interest_rate = data
+        .approved_interest_rate
+        .or(first_approval.map(|approval| approval.approved_interest_rate))
+        .unwrap_or(product.interest_rate);
+    let conditions = data
+        .conditions
+        .clone()
+        .or_else(|| first_approval.and_then(|approval| approval.conditions.clone()));
+
+    if let Some(first) = first_approval
+        && (
+            first.approved_principal,
+            first.approved_tenure_days,
+            first.approved_interest_rate,
+        ) != (principal, tenure_days, interest_rate)
+    {
+        return Err(DbErr::Custom(
+            "Approval terms must match those of the first approver".to_string(),
+        ));
+    }
+
+    if principal < product.minimum_principal || principal > product.maximum_principal {
+        return Err(DbErr::Custom(format!(
+            "Approved principal must be between {} and {}",
+            product.minimum_principal, product.maximum_principal
+        )));
+    }
+
+    if tenure_days < product.minimum_tenure_days || tenure_days > product.maximum_tenure_days {
+        return Err(DbErr::Custom(format!(
+            "Approved tenure must be between {} and {} days",
+            product.minimum_tenure_days, product.maximum_tenure_days
+        )));
+    }
+
+    let coverage = collateral_coverage(&txn, &application, &product, principal).await?;
+
+    if !coverage.is_sufficient {
+        return Err(DbErr::Custom(format!(
+            "Verified collateral covers {}% of principal, product requires {}%",
+            coverage.coverage_ratio, coverage.minimum_ratio
+        )));
+    }
+
+    let minimum_guarantors = if product.is_guarantor_required.unwrap_or(false) {
+        product.minimum_guarantors.unwrap_or(1).max(1)
+    } else {
+        product.minimum_guarantors.unwrap_or(0).max(0)
+    };
+
+    let guarantors = consented_guarantor_count(&txn, application.id).await?;
+
+    if guarantors < minimum_guarantors as u64 {
+        return Err(DbErr::Custom(format!(
+            "Application has {} consented guarantors, product requires {}",
+            guarantors, minimum_guarantors
+        )));
+    }
+
+    let authority = approval_authority(&txn, data.approved_by, principal).await?;
+
+    let required = required_approvals(&txn, application.institution_id, principal).await?;
+
+    let level = prior_approvals.len() as i32 + 1;
+
+    let (approval_id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::loan_application_approvals::ActiveModel {
+        id: Set(approval_id),
+        loan_application_id: Set(application.id),
+        approved_by: Set(data.approved_by),
+        role_id: Set(Some(authority.role_id)),
+        approval_limit: Set(authority.approval_limit),
+        approved_principal: Set(principal),
+        approved_tenure_days: Set(tenure_days),
+        approved_interest_rate: Set(interest_rate),
+        approval_level: Set(level),
+        conditions: Set(conditions.clone()),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+
+    // Large loans stay pending until enough distinct approvers sign off
+    if level < required {
+        let mut active_application: entity::loan_applications::ActiveModel = application.into();
+        active_application.current_stage = Set(Some(format!("APPROVAL {}/{}", level, required)));
+        active_application.updated_at = Set(Some(chrono::Utc::now().into()));
+        active_application.update(&txn).await?;
+
+        txn.commit().await?;
+
+        return get_application(id, state).await;
+    }
+
+    let from_status = application.status.clone();
+
+    let mut active_application: entity::loan_applications::ActiveModel = application.into();
+    active_application.status = Set(Some(LoanApplicationStatus::Approved));
+    active_application.approved_principal = Set(Some(principal));
+    active_application.approved_tenure_days = Set(Some(tenure_days));
+    active_application.approved_interest_rate = Set(Some(interest_rate));
+    active_application.approval_conditions = Set(conditions.clone());
+    active_application.approved_at = Set(Some(chrono::Utc::now().into()));
+    active_application.approved_by = Set(Some(data.approved_by));
+    active_application.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_application.update(&txn).await?;
+
+    record_status_change(
+        &txn,
+        *id,
+        from_status.as_ref(),
+        &LoanApplicationStatus::Approved,
+        json!({
+            "approved_principal": principal,
+            "approved_tenure_days": tenure_days,
+            "approved_interest_rate": interest_rate,
+            "collateral_coverage_ratio": coverage.coverage_ratio,
+            "consented_guarantors": guarantors,
+            "approvals": level,
+            "required_approvals": required,
+            "conditions": conditions,
+        }),
+        data.approved_by,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    get_application(id, state).await
+}
+
+pub async fn reject_application(
+    id: &i64,
+    model: &RejectApplicationModel,
+    state: &web::Data<AppState>,
+) -> Result<LoanApplicationResponseModel, DbErr> {
+    let data = model.clone();
+
+    let application = find_application(id, state).await?;
+
+    if !matches!(
+        application.status,
+        Some(LoanApplicationStatus::Draft) | Some(LoanApplicationStatus::Pending)
+    ) {
+        return Err(DbErr::Custom(
+            "Only draft or pending applications can be rejected".to_string(),
+        ));
+    }
+
+    let txn = state.pgdb.begin().await?;
+
+    let from_status = application.status.clone();
+
+    let mut active_application: entity::loan_applications::ActiveModel = application.into();
+    active_application.status = Set(Some(LoanApplicationStatus::Rejected));
+    active_application.rejected_reason = Set(Some(data.reason.clone()));
+    active_application.rejected_at = Set(Some(chrono::Utc::now().into()));
+    active_application.rejected_by = Set(Some(data.rejected_by));
+    active_application.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_application.update(&txn).await?;
+
+    record_status_change(
+        &txn,
+        *id,
+        from_status.as_ref(),
+        &LoanApplicationStatus::Rejected,
+        json!({ "reason": data.reason }),
+        data.rejected_by,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    get_application(id, state).await
+}
@@ -0,0 +1,132 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::loan_approvals::{
+        models::{SaveLevelModel, SaveLevelParams, SetRoleLimitModel, SetRoleLimitParams},
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn set_role_limit(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<SetRoleLimitParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "LOAN_APPROVAL_CONFIGURE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Role Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let limit = SetRoleLimitModel {
+        approval_limit: data.approval_limit,
+        has_unlimited_approval: data.has_unlimited_approval.unwrap_or(false),
+    };
+
+    match services::set_role_limit(&id, &limit, &state).await {
+        Ok(role) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            role,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn save_level(
+    req: HttpRequest,
+    payload: web::Json<SaveLevelParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_APPROVAL_CONFIGURE", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let institution_id = id_parser(&data.institution_id, "Institution Id").await?;
+
+    let level = SaveLevelModel {
+        institution_id,
+        min_principal: data.min_principal,
+        required_approvals: data.required_approvals,
+        description: data.description,
+        created_by: staff.id,
+    };
+
+    match services::save_level(&level, &state).await {
+        Ok(level) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            level,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn get_levels(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Institution Id").await?;
+
+    match services::get_levels(&id, &state).await {
+        Ok(levels) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            levels,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn get_application_approvals(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Application Id").await?;
+
+    match services::get_application_approvals(&id, &state).await {
+        Ok(approvals) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            approvals,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
@@ -0,0 +1,132 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[derive(Debug, Clone)]
+pub struct SetRoleLimitModel {
+    pub approval_limit: Option<i64>,
+    pub has_unlimited_approval: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SaveLevelModel {
+    pub institution_id: i64,
+    pub min_principal: i64,
+    pub required_approvals: i32,
+    pub description: Option<String>,
+    pub created_by: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApprovalAuthorityModel {
+    pub role_id: i64,
+    pub approval_limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SetRoleLimitParams {
+    #[validate(range(min = 0, message = "approvalLimit cannot be < 0"))]
+    #[serde(rename = "approvalLimit")]
+    pub approval_limit: Option<i64>,
+    #[serde(rename = "hasUnlimitedApproval")]
+    pub has_unlimited_approval: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SaveLevelParams {
+    #[serde(rename = "institutionId")]
+    pub institution_id: String,
+    #[validate(range(min = 0, message = "minPrincipal cannot be < 0"))]
+    #[serde(rename = "minPrincipal")]
+    pub min_principal: i64,
+    #[validate(range(
+        min = 1,
+        max = 10,
+        message = "requiredApprovals cannot be < 1 and > 10"
+    ))]
+    #[serde(rename = "requiredApprovals")]
+    pub required_approvals: i32,
+    #[validate(length(min = 2, max = 500, message = "description cannot be < 2 and > 500"))]
+    pub description: Option<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::staff_roles::Entity")]
+pub struct RoleLimitResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[sea_orm(from_col = "role_name")]
+    pub role_name: String,
+    #[sea_orm(from_col = "role_code")]
+    pub role_code: String,
+    #[sea_orm(from_col = "loan_approval_limit")]
+    pub loan_approval_limit: Option<i64>,
+    #[sea_orm(from_col = "has_unlimited_loan_approval")]
+    pub has_unlimited_loan_approval: bool,
+    #[sea_orm(from_col = "updated_at")]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_approval_levels::Entity")]
+pub struct LevelResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "institution_id")]
+    pub institution_id: i64,
+    #[sea_orm(from_col = "min_principal")]
+    pub min_principal: i64,
+    #[sea_orm(from_col = "required_approvals")]
+    pub required_approvals: i32,
+    #[sea_orm(from_col = "description")]
+    pub description: Option<String>,
+    #[sea_orm(from_col = "is_active")]
+    pub is_active: Option<bool>,
+    #[sea_orm(from_col = "updated_at")]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_application_approvals::Entity")]
+pub struct ApprovalResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "loan_application_id")]
+    pub loan_application_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "approved_by")]
+    pub approved_by: i64,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "role_id")]
+    pub role_id: Option<i64>,
+    #[sea_orm(from_col = "approval_limit")]
+    pub approval_limit: Option<i64>,
+    #[sea_orm(from_col = "approved_principal")]
+    pub approved_principal: i64,
+    #[sea_orm(from_col = "approved_tenure_days")]
+    pub approved_tenure_days: i32,
+    #[sea_orm(from_col = "approved_interest_rate")]
+    pub approved_interest_rate: Decimal,
+    #[sea_orm(from_col = "approval_level")]
+    pub approval_level: i32,
+    #[sea_orm(from_col = "conditions")]
+    pub conditions: Option<Value>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
@@ -0,0 +1,33 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loan_approvals::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loan-approvals")
+            .route(
+                "/roles/{id}/limit",
+                web::put()
+                    .to(controllers::set_role_limit)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/levels",
+                web::post()
+                    .to(controllers::save_level)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/levels/{id}",
+                web::get()
+                    .to(controllers::get_levels)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/applications/{id}",
+                web::get()
+                    .to(controllers::get_application_approvals)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
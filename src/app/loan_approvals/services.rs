@@ -0,0 +1,204 @@
+use actix_web::web;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder,
+};
+
+use crate::{
+    AppState,
+    app::loan_approvals::models::{
+        ApprovalAuthorityModel, ApprovalResponseModel, LevelResponseModel, RoleLimitResponseModel,
+        SaveLevelModel, SetRoleLimitModel,
+    },
+    utils::gen_snow_ids::gen_snowflake_slug,
+};
+
+pub async fn set_role_limit(
+    role_id: &i64,
+    model: &SetRoleLimitModel,
+    state: &web::Data<AppState>,
+) -> Result<RoleLimitResponseModel, DbErr> {
+    let role = entity::staff_roles::Entity::find_by_id(*role_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Staff role not found".into()))?;
+
+    let mut active_role: entity::staff_roles::ActiveModel = role.into();
+
+    active_role.loan_approval_limit = Set(model.approval_limit);
+    active_role.has_unlimited_loan_approval = Set(model.has_unlimited_approval);
+    active_role.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    active_role.update(state.pgdb.get_ref()).await?;
+
+    entity::staff_roles::Entity::find_by_id(*role_id)
+        .into_model::<RoleLimitResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Staff role not found".into()))
+}
+
+pub async fn save_level(
+    model: &SaveLevelModel,
+    state: &web::Data<AppState>,
+) -> Result<LevelResponseModel, DbErr> {
+    let data = model.clone();
+
+    let existing = entity::loan_approval_levels::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_approval_levels::Column::InstitutionId.eq(data.institution_id))
+                .add(entity::loan_approval_levels::Column::MinPrincipal.eq(data.min_principal)),
+        )
+        .one(state.pgdb.get_ref())
+        .await?;
+
+    let id = match existing {
+        Some(level) => {
+            let id = level.id;
+            let mut active_level: entity::loan_approval_levels::ActiveModel = level.into();
+
+            active_level.required_approvals = Set(data.required_approvals);
+            active_level.description = Set(data.description);
+            active_level.is_active = Set(Some(true));
+            active_level.updated_at = Set(Some(chrono::Utc::now().into()));
+
+            ActiveModelTrait::update(active_level, state.pgdb.get_ref()).await?;
+
+            id
+        }
+        None => {
+            let (id, _) = match gen_snowflake_slug() {
+                Ok(res) => res,
+                Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+            };
+
+            entity::loan_approval_levels::ActiveModel {
+                id: Set(id),
+                institution_id: Set(data.institution_id),
+                min_principal: Set(data.min_principal),
+                required_approvals: Set(data.required_approvals),
+                description: Set(data.description),
+                is_active: Set(Some(true)),
+                created_by: Set(Some(data.created_by)),
+                ..Default::default()
+            }
+            .insert(state.pgdb.get_ref())
+            .await?;
+
+            id
+        }
+    };
+
+    entity::loan_approval_levels::Entity::find_by_id(id)
+        .into_model::<LevelResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Approval level not found".into()))
+}
+
+pub async fn get_levels(
+    institution_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<LevelResponseModel>, DbErr> {
+    let levels = entity::loan_approval_levels::Entity::find()
+        .filter(entity::loan_approval_levels::Column::InstitutionId.eq(*institution_id))
+        .order_by_asc(entity::loan_approval_levels::Column::MinPrincipal)
+        .into_model::<LevelResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(levels)
+}
+
+pub async fn get_application_approvals(
+    application_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<ApprovalResponseModel>, DbErr> {
+    let approvals = entity::loan_application_approvals::Entity::find()
+        .filter(entity::loan_application_approvals::Column::LoanApplicationId.eq(*application_id))
+        .order_by_asc(entity::loan_application_approvals::Column::ApprovalLevel)
+        .into_model::<ApprovalResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(approvals)
+}
+
+// Approvers are held to their role's limit. A role with no limit is denied
+// unless it has unlimited approval (e.g. credit committee), and staff
+// without an active role cannot approve
+pub async fn approval_authority<C: ConnectionTrait>(
+    conn: &C,
+    approver_id: i64,
+    principal: i64,
+) -> Result<ApprovalAuthorityModel, DbErr> {
+    let approver = entity::staff::Entity::find_by_id(approver_id)
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Approver not found".into()))?;
+
+    let role = match approver.role_id {
+        Some(role_id) => {
+            entity::staff_roles::Entity::find_by_id(role_id)
+                .one(conn)
+                .await?
+        }
+        None => None,
+    };
+
+    let Some(role) = role.filter(|role| role.is_active.unwrap_or(true)) else {
+        return Err(DbErr::Custom(
+            "Approver has no active role with approval authority".to_string(),
+        ));
+    };
+
+    // Unlimited authority has to be granted explicitly, a role without a
+    // limit cannot approve at all
+    if role.has_unlimited_loan_approval {
+        return Ok(ApprovalAuthorityModel {
+            role_id: role.id,
+            approval_limit: None,
+        });
+    }
+
+    let Some(limit) = role.loan_approval_limit else {
+        return Err(DbErr::Custom(format!(
+            "The {} role has no loan approval limit",
+            role.role_name
+        )));
+    };
+
+    if principal > limit {
+        return Err(DbErr::Custom(format!(
+            "Approved principal of {} exceeds the {} approval limit of {}",
+            principal, role.role_name, limit
+        )));
+    }
+
+    Ok(ApprovalAuthorityModel {
+        role_id: role.id,
+        approval_limit: Some(limit),
+    })
+}
+
+// The highest active level at or below the principal decides how many
+// distinct approvers are needed; one approval when no level applies
+pub async fn required_approvals<C: ConnectionTrait>(
+    conn: &C,
+    institution_id: i64,
+    principal: i64,
+) -> Result<i32, DbErr> {
+    let level = entity::loan_approval_levels::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_approval_levels::Column::InstitutionId.eq(institution_id))
+                .add(entity::loan_approval_levels::Column::MinPrincipal.lte(principal))
+                .add(entity::loan_approval_levels::Column::IsActive.eq(true)),
+        )
+        .order_by_desc(entity::loan_approval_levels::Column::MinPrincipal)
+        .one(conn)
+        .await?;
+
+    Ok(level.map_or(1, |level| level.required_approvals.max(1)))
+}
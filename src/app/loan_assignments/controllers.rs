@@ -0,0 +1,79 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::loan_assignments::{
+        models::{ReassignApplicationModel, ReassignApplicationParams},
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn reassign_application(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<ReassignApplicationParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_APPLICATION_ASSIGN", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Application Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let officer_id = id_parser(&data.officer_id, "Officer Id").await?;
+
+    let assignment = ReassignApplicationModel {
+        officer_id,
+        reason: data.reason,
+        assigned_by: staff.id,
+    };
+
+    match services::reassign_application(&id, &assignment, &state).await {
+        Ok(application) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Application Reassigned",
+            application,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn get_pipeline(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Officer Id").await?;
+
+    match services::get_pipeline(&id, &state).await {
+        Ok(pipeline) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            pipeline,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
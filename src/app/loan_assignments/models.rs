@@ -0,0 +1,52 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[derive(Debug, Clone)]
+pub struct ReassignApplicationModel {
+    pub officer_id: i64,
+    pub reason: String,
+    pub assigned_by: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ReassignApplicationParams {
+    #[serde(rename = "officerId")]
+    pub officer_id: String,
+    #[validate(length(min = 5, max = 255, message = "reason cannot be < 5 and > 255"))]
+    pub reason: String,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineApplicationModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    pub id: i64,
+    pub application_number: Option<String>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub customer_id: i64,
+    pub status: Option<String>,
+    pub requested_principal: i64,
+    pub age_days: i64,
+    pub assigned_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineStageModel {
+    pub stage: String,
+    pub count: usize,
+    pub oldest_age_days: i64,
+    pub applications: Vec<PipelineApplicationModel>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct OfficerPipelineModel {
+    #[serde_as(as = "DisplayFromStr")]
+    pub officer_id: i64,
+    pub open_applications: usize,
+    pub stages: Vec<PipelineStageModel>,
+}
@@ -0,0 +1,21 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loan_assignments::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loan-assignments")
+            .route(
+                "/applications/{id}",
+                web::put()
+                    .to(controllers::reassign_application)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/officers/{id}/pipeline",
+                web::get()
+                    .to(controllers::get_pipeline)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
@@ -0,0 +1,334 @@
+use std::collections::{BTreeMap, HashMap};
+
+use actix_web::web;
+use entity::sea_orm_active_enums::{LoanApplicationStatus, StaffEmploymentEnum};
+use migration::Expr;
+use sea_orm::{
+    ActiveEnum, ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+    prelude::DateTimeWithTimeZone,
+};
+use serde_json::json;
+
+use crate::{
+    AppState,
+    app::{
+        loan_applications::{
+            models::LoanApplicationResponseModel,
+            services::{get_application, record_status_change},
+        },
+        loan_assignments::models::{
+            OfficerPipelineModel, PipelineApplicationModel, PipelineStageModel,
+            ReassignApplicationModel,
+        },
+    },
+    utils::permissions::holds_explicit_permission,
+};
+
+pub const LOAN_OFFICER_PERMISSION: &str = "LOAN_OFFICER";
+
+fn open_statuses() -> Vec<LoanApplicationStatus> {
+    vec![
+        LoanApplicationStatus::Draft,
+        LoanApplicationStatus::Pending,
+        LoanApplicationStatus::Approved,
+    ]
+}
+
+async fn is_loan_officer<C: ConnectionTrait>(
+    conn: &C,
+    staff: &entity::staff::Model,
+) -> Result<bool, DbErr> {
+    if staff.employment_status != Some(StaffEmploymentEnum::Active) {
+        return Ok(false);
+    }
+
+    let role = match staff.role_id {
+        Some(role_id) => {
+            entity::staff_roles::Entity::find_by_id(role_id)
+                .one(conn)
+                .await?
+        }
+        None => None,
+    };
+
+    // Admins and wildcard holders are not loan officers unless they also
+    // hold the loan-officer role or permission
+    let officer_role = role.as_ref().is_some_and(|role| {
+        role.is_active.unwrap_or(true)
+            && role.role_code.eq_ignore_ascii_case(LOAN_OFFICER_PERMISSION)
+    });
+
+    Ok(officer_role || holds_explicit_permission(staff, role.as_ref(), LOAN_OFFICER_PERMISSION))
+}
+
+async fn branch_officers<C: ConnectionTrait>(
+    conn: &C,
+    institution_id: i64,
+    branch_id: i64,
+) -> Result<Vec<i64>, DbErr> {
+    let staff = entity::staff::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::staff::Column::InstitutionId.eq(institution_id))
+                .add(entity::staff::Column::BranchId.eq(branch_id))
+                .add(entity::staff::Column::EmploymentStatus.eq(StaffEmploymentEnum::Active)),
+        )
+        .order_by_asc(entity::staff::Column::Id)
+        .all(conn)
+        .await?;
+
+    let mut officers = vec![];
+
+    for member in &staff {
+        if is_loan_officer(conn, member).await? {
+            officers.push(member.id);
+        }
+    }
+
+    Ok(officers)
+}
+
+// Round-robin hands the application to the officer who has gone longest
+// without an assignment; least-open picks the smallest open pipeline
+async fn pick_officer<C: ConnectionTrait>(
+    conn: &C,
+    strategy: &str,
+    officers: &[i64],
+) -> Result<Option<i64>, DbErr> {
+    if strategy == "LEAST_OPEN" {
+        let open: HashMap<i64, i64> = entity::loan_applications::Entity::find()
+            .select_only()
+            .column(entity::loan_applications::Column::AssignedOfficer)
+            .column_as(entity::loan_applications::Column::Id.count(), "open")
+            .filter(
+                Condition::all()
+                    .add(
+                        entity::loan_applications::Column::AssignedOfficer.is_in(officers.to_vec()),
+                    )
+                    .add(entity::loan_applications::Column::Status.is_in(open_statuses())),
+            )
+            .group_by(entity::loan_applications::Column::AssignedOfficer)
+            .into_tuple::<(Option<i64>, i64)>()
+            .all(conn)
+            .await?
+            .into_iter()
+            .filter_map(|(officer, count)| officer.map(|officer| (officer, count)))
+            .collect();
+
+        return Ok(officers
+            .iter()
+            .min_by_key(|officer| (open.get(officer).copied().unwrap_or(0), **officer))
+            .copied());
+    }
+
+    let last_assigned: HashMap<i64, DateTimeWithTimeZone> =
+        entity::loan_applications::Entity::find()
+            .select_only()
+            .column(entity::loan_applications::Column::AssignedOfficer)
+            .column_as(
+                entity::loan_applications::Column::AssignedAt.max(),
+                "last_assigned_at",
+            )
+            .filter(entity::loan_applications::Column::AssignedOfficer.is_in(officers.to_vec()))
+            .group_by(entity::loan_applications::Column::AssignedOfficer)
+            .into_tuple::<(Option<i64>, Option<DateTimeWithTimeZone>)>()
+            .all(conn)
+            .await?
+            .into_iter()
+            .filter_map(|(officer, at)| officer.zip(at))
+            .collect();
+
+    Ok(officers
+        .iter()
+        .min_by_key(|officer| (last_assigned.get(officer).copied(), **officer))
+        .copied())
+}
+
+// New applications go to a loan officer in the branch they were captured at;
+// they stay unassigned when the branch has no active officers
+pub async fn auto_assign<C: ConnectionTrait>(
+    conn: &C,
+    application_id: i64,
+    institution_id: i64,
+    branch_id: Option<i64>,
+    state: &web::Data<AppState>,
+) -> Result<Option<i64>, DbErr> {
+    let Some(branch_id) = branch_id else {
+        return Ok(None);
+    };
+
+    let strategy = state
+        .config
+        .get::<String>("loans.officer_assignment")
+        .unwrap_or_else(|_| "ROUND_ROBIN".to_string());
+
+    let officers = branch_officers(conn, institution_id, branch_id).await?;
+
+    let Some(officer_id) = pick_officer(conn, &strategy, &officers).await? else {
+        log::warn!(
+            "No loan officers in branch {}, application {} left unassigned",
+            branch_id,
+            application_id
+        );
+
+        return Ok(None);
+    };
+
+    entity::loan_applications::Entity::update_many()
+        .col_expr(
+            entity::loan_applications::Column::AssignedOfficer,
+            Expr::value(officer_id),
+        )
+        .col_expr(
+            entity::loan_applications::Column::AssignedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(entity::loan_applications::Column::Id.eq(application_id))
+        .exec(conn)
+        .await?;
+
+    Ok(Some(officer_id))
+}
+
+pub async fn reassign_application(
+    id: &i64,
+    model: &ReassignApplicationModel,
+    state: &web::Data<AppState>,
+) -> Result<LoanApplicationResponseModel, DbErr> {
+    let data = model.clone();
+
+    let application = entity::loan_applications::Entity::find_by_id(*id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan application not found".into()))?;
+
+    if !application
+        .status
+        .as_ref()
+        .is_some_and(|status| open_statuses().contains(status))
+    {
+        return Err(DbErr::Custom(
+            "Only open applications can be reassigned".to_string(),
+        ));
+    }
+
+    if application.assigned_officer == Some(data.officer_id) {
+        return Err(DbErr::Custom(
+            "Application is already assigned to this officer".to_string(),
+        ));
+    }
+
+    let officer = entity::staff::Entity::find_by_id(data.officer_id)
+        .filter(entity::staff::Column::InstitutionId.eq(application.institution_id))
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Officer not found".into()))?;
+
+    if !is_loan_officer(state.pgdb.get_ref(), &officer).await? {
+        return Err(DbErr::Custom(
+            "Staff member is not an active loan officer".to_string(),
+        ));
+    }
+
+    let txn = state.pgdb.begin().await?;
+
+    let status = application.status.clone();
+    let previous_officer = application.assigned_officer;
+
+    let mut active_application: entity::loan_applications::ActiveModel = application.into();
+    active_application.assigned_officer = Set(Some(officer.id));
+    active_application.assigned_at = Set(Some(chrono::Utc::now().into()));
+    active_application.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_application.update(&txn).await?;
+
+    if let Some(status) = status {
+        record_status_change(
+            &txn,
+            *id,
+            Some(&status),
+            &status,
+            json!({
+                "reason": "Officer reassigned",
+                "from_officer": previous_officer.map(|officer| officer.to_string()),
+                "to_officer": officer.id.to_string(),
+                "note": data.reason,
+            }),
+            data.assigned_by,
+        )
+        .await?;
+    }
+
+    txn.commit().await?;
+
+    get_application(id, state).await
+}
+
+pub async fn get_pipeline(
+    officer_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<OfficerPipelineModel, DbErr> {
+    let now = chrono::Utc::now();
+
+    let applications = entity::loan_applications::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_applications::Column::AssignedOfficer.eq(*officer_id))
+                .add(entity::loan_applications::Column::Status.is_in(open_statuses())),
+        )
+        .order_by_asc(entity::loan_applications::Column::CreatedAt)
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let open_applications = applications.len();
+
+    let mut stages: BTreeMap<String, Vec<PipelineApplicationModel>> = BTreeMap::new();
+
+    for application in applications {
+        let status = application.status.as_ref().map(|status| status.to_value());
+
+        let stage = application
+            .current_stage
+            .clone()
+            .or_else(|| status.clone())
+            .unwrap_or_else(|| "UNKNOWN".to_string());
+
+        let age_days = application
+            .created_at
+            .map(|created_at| (now - created_at.to_utc()).num_days().max(0))
+            .unwrap_or(0);
+
+        stages
+            .entry(stage)
+            .or_default()
+            .push(PipelineApplicationModel {
+                id: application.id,
+                application_number: application.application_number,
+                customer_id: application.customer_id,
+                status,
+                requested_principal: application.requested_principal,
+                age_days,
+                assigned_at: application.assigned_at,
+            });
+    }
+
+    let stages = stages
+        .into_iter()
+        .map(|(stage, applications)| PipelineStageModel {
+            stage,
+            count: applications.len(),
+            oldest_age_days: applications
+                .iter()
+                .map(|application| application.age_days)
+                .max()
+                .unwrap_or(0),
+            applications,
+        })
+        .collect();
+
+    Ok(OfficerPipelineModel {
+        officer_id: *officer_id,
+        open_applications,
+        stages,
+    })
+}
@@ -0,0 +1,77 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::loan_calculator::{
+        models::{
+            CalculateLoanModel, CalculateLoanParams, CompareProductsModel, CompareProductsParams,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+    },
+};
+
+pub async fn calculate(
+    _req: HttpRequest,
+    payload: web::Json<CalculateLoanParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let loan_product_id = id_parser(&data.loan_product_id, "Loan Product Id").await?;
+
+    let calculation = CalculateLoanModel {
+        loan_product_id,
+        segment: data.segment,
+        principal: data.principal,
+        tenure_days: data.tenure_days,
+        repayment_freq: data.repayment_freq,
+    };
+
+    match services::calculate(&calculation, &state).await {
+        Ok(quote) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            quote,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn compare(
+    _req: HttpRequest,
+    payload: web::Json<CompareProductsParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let institution_id = id_parser(&data.institution_id, "Institution Id").await?;
+
+    let comparison = CompareProductsModel {
+        institution_id,
+        segment: data.segment,
+        principal: data.principal,
+        tenure_days: data.tenure_days,
+    };
+
+    match services::compare(&comparison, &state).await {
+        Ok(products) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            products,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
@@ -0,0 +1,89 @@
+use entity::sea_orm_active_enums::{CustomerType, LoanProductCalcMethod, LoanRepaymentFreq};
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+use crate::utils::finance::ScheduledInstallment;
+
+#[derive(Debug, Clone)]
+pub struct CalculateLoanModel {
+    pub loan_product_id: i64,
+    pub segment: Option<CustomerType>,
+    pub principal: i64,
+    pub tenure_days: i32,
+    pub repayment_freq: Option<LoanRepaymentFreq>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompareProductsModel {
+    pub institution_id: i64,
+    pub segment: CustomerType,
+    pub principal: i64,
+    pub tenure_days: i32,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CalculateLoanParams {
+    #[serde(rename = "loanProductId")]
+    pub loan_product_id: String,
+    pub segment: Option<CustomerType>,
+    #[validate(range(min = 1, message = "principal must be greater than 0"))]
+    pub principal: i64,
+    #[validate(range(min = 1, message = "tenureDays must be greater than 0"))]
+    #[serde(rename = "tenureDays")]
+    pub tenure_days: i32,
+    #[serde(rename = "repaymentFreq")]
+    pub repayment_freq: Option<LoanRepaymentFreq>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CompareProductsParams {
+    #[serde(rename = "institutionId")]
+    pub institution_id: String,
+    pub segment: CustomerType,
+    #[validate(range(min = 1, message = "principal must be greater than 0"))]
+    pub principal: i64,
+    #[validate(range(min = 1, message = "tenureDays must be greater than 0"))]
+    #[serde(rename = "tenureDays")]
+    pub tenure_days: i32,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct LoanQuoteModel {
+    #[serde_as(as = "DisplayFromStr")]
+    pub loan_product_id: i64,
+    pub product_name: Option<String>,
+    pub principal: i64,
+    pub tenure_days: i32,
+    pub interest_rate: Decimal,
+    pub interest_calc_method: LoanProductCalcMethod,
+    pub repayment_freq: LoanRepaymentFreq,
+    pub installment_count: usize,
+    pub installment_amount: i64,
+    pub total_interest: i64,
+    pub processing_fee: i64,
+    pub insurance_fee: i64,
+    pub total_fees: i64,
+    pub total_repayable: i64,
+    pub schedule: Vec<ScheduledInstallment>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct ProductComparisonModel {
+    #[serde_as(as = "DisplayFromStr")]
+    pub loan_product_id: i64,
+    pub product_name: Option<String>,
+    pub interest_rate: Decimal,
+    pub repayment_freq: LoanRepaymentFreq,
+    pub installment_count: usize,
+    pub installment_amount: i64,
+    pub total_interest: i64,
+    pub total_fees: i64,
+    pub total_cost: i64,
+    pub total_repayable: i64,
+}
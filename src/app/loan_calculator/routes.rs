@@ -0,0 +1,22 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loan_calculator::controllers, middlewares::rate_limit::rate_limit};
+
+// Public endpoints for the website and mobile app; no staff session needed
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loan-calculator")
+            .route(
+                "",
+                web::post()
+                    .to(controllers::calculate)
+                    .wrap(from_fn(rate_limit)),
+            )
+            .route(
+                "/compare",
+                web::post()
+                    .to(controllers::compare)
+                    .wrap(from_fn(rate_limit)),
+            ),
+    );
+}
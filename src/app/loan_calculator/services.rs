@@ -0,0 +1,256 @@
+use actix_web::web;
+use entity::sea_orm_active_enums::{LoanProductCalcMethod, LoanRepaymentFreq};
+use sea_orm::{ActiveEnum, ColumnTrait, Condition, DbErr, EntityTrait, QueryFilter};
+use serde_json::Value;
+
+use crate::{
+    AppState,
+    app::loan_calculator::models::{
+        CalculateLoanModel, CompareProductsModel, LoanQuoteModel, ProductComparisonModel,
+    },
+    utils::finance::{fee_amount, repayment_schedule},
+};
+
+// `repayment_freq` may list several frequencies separated by commas; the
+// first is the product's default
+fn offered_freqs(product: &entity::loan_products::Model) -> Vec<LoanRepaymentFreq> {
+    product
+        .repayment_freq
+        .split(',')
+        .filter_map(|freq| LoanRepaymentFreq::try_from_value(&freq.trim().to_uppercase()).ok())
+        .collect()
+}
+
+fn product_freq(product: &entity::loan_products::Model) -> LoanRepaymentFreq {
+    offered_freqs(product)
+        .into_iter()
+        .next()
+        .unwrap_or(LoanRepaymentFreq::Monthly)
+}
+
+fn listed(values: Option<&Value>) -> Option<&Vec<Value>> {
+    match values {
+        Some(Value::Array(items)) if !items.is_empty() => Some(items),
+        _ => None,
+    }
+}
+
+fn includes(items: &[Value], value: &str) -> bool {
+    items.iter().any(|item| item.as_str() == Some(value))
+}
+
+// Products without a customer type list are open to every segment; a
+// product that lists types is only open to a caller naming one of them
+fn serves_segment(product: &entity::loan_products::Model, segment: Option<&str>) -> bool {
+    listed(product.allowed_customer_types.as_ref())
+        .is_none_or(|types| segment.is_some_and(|segment| includes(types, segment)))
+}
+
+// A product's visibility rules can list the segments it is shown to; rules
+// that target branches, KYC tiers, ages or credit scores need a known
+// customer, so those products stay out of the public calculator
+fn visible_to_segment(
+    rule: &entity::product_visibility_rules::Model,
+    segment: Option<&str>,
+) -> bool {
+    listed(rule.visible_to_customers.as_ref())
+        .is_none_or(|types| segment.is_some_and(|segment| includes(types, segment)))
+        && listed(rule.visible_to_branches.as_ref()).is_none()
+        && listed(rule.visible_to_kyc_tiers.as_ref()).is_none()
+        && rule.min_customer_age.is_none()
+        && rule.max_customer_age.is_none()
+        && rule.min_credit_score.is_none()
+}
+
+fn quote(
+    product: &entity::loan_products::Model,
+    principal: i64,
+    tenure_days: i32,
+    repayment_freq: Option<LoanRepaymentFreq>,
+) -> Result<LoanQuoteModel, DbErr> {
+    if principal < product.minimum_principal || principal > product.maximum_principal {
+        return Err(DbErr::Custom(format!(
+            "Principal must be between {} and {}",
+            product.minimum_principal, product.maximum_principal
+        )));
+    }
+
+    if tenure_days < product.minimum_tenure_days || tenure_days > product.maximum_tenure_days {
+        return Err(DbErr::Custom(format!(
+            "Tenure must be between {} and {} days",
+            product.minimum_tenure_days, product.maximum_tenure_days
+        )));
+    }
+
+    let freq = match repayment_freq {
+        Some(freq) if !offered_freqs(product).contains(&freq) => {
+            return Err(DbErr::Custom(format!(
+                "Product does not offer {} repayments",
+                freq.to_value()
+            )));
+        }
+        Some(freq) => freq,
+        None => product_freq(product),
+    };
+    let method = product
+        .interest_calc_method
+        .clone()
+        .unwrap_or(LoanProductCalcMethod::Flat);
+
+    let schedule = repayment_schedule(
+        principal,
+        product.interest_rate,
+        &method,
+        &freq,
+        chrono::Utc::now().date_naive(),
+        tenure_days,
+    );
+
+    let total_interest: i64 = schedule
+        .iter()
+        .map(|installment| installment.interest_due)
+        .sum();
+    let processing_fee = fee_amount(
+        principal,
+        product.processing_fee_rate,
+        product.processing_fee_flat,
+    );
+    let insurance_fee = fee_amount(
+        principal,
+        product.insurance_fee_rate,
+        product.insurance_fee_flat,
+    );
+    let total_fees = processing_fee + insurance_fee;
+
+    Ok(LoanQuoteModel {
+        loan_product_id: product.id,
+        product_name: product.name.clone(),
+        principal,
+        tenure_days,
+        interest_rate: product.interest_rate,
+        interest_calc_method: method,
+        repayment_freq: freq,
+        installment_count: schedule.len(),
+        installment_amount: schedule
+            .first()
+            .map(|installment| installment.total_due)
+            .unwrap_or(0),
+        total_interest,
+        processing_fee,
+        insurance_fee,
+        total_fees,
+        total_repayable: principal + total_interest + total_fees,
+        schedule,
+    })
+}
+
+fn is_public(
+    product: &entity::loan_products::Model,
+    rules: &[entity::product_visibility_rules::Model],
+    segment: Option<&str>,
+) -> bool {
+    serves_segment(product, segment)
+        && rules
+            .iter()
+            .filter(|rule| rule.product_id == product.id)
+            .all(|rule| visible_to_segment(rule, segment))
+}
+
+async fn visibility_rules(
+    institution_id: i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<entity::product_visibility_rules::Model>, DbErr> {
+    let now = chrono::Utc::now();
+
+    entity::product_visibility_rules::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::product_visibility_rules::Column::InstitutionId.eq(institution_id))
+                .add(entity::product_visibility_rules::Column::ProductType.eq("LOAN"))
+                .add(entity::product_visibility_rules::Column::IsActive.eq(true))
+                .add(
+                    Condition::any()
+                        .add(entity::product_visibility_rules::Column::EffectiveFrom.is_null())
+                        .add(entity::product_visibility_rules::Column::EffectiveFrom.lte(now)),
+                )
+                .add(
+                    Condition::any()
+                        .add(entity::product_visibility_rules::Column::EffectiveTo.is_null())
+                        .add(entity::product_visibility_rules::Column::EffectiveTo.gt(now)),
+                ),
+        )
+        .all(state.pgdb.get_ref())
+        .await
+}
+
+// Products hidden from the comparison are reported as not found, so the
+// calculator cannot be used to discover them
+pub async fn calculate(
+    model: &CalculateLoanModel,
+    state: &web::Data<AppState>,
+) -> Result<LoanQuoteModel, DbErr> {
+    let data = model.clone();
+    let segment = data.segment.as_ref().map(|segment| segment.to_value());
+
+    let product = entity::loan_products::Entity::find_by_id(data.loan_product_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .filter(|product| product.is_active.unwrap_or(true))
+        .ok_or_else(|| DbErr::RecordNotFound("Loan product not found".into()))?;
+
+    let rules = visibility_rules(product.institution_id, state).await?;
+
+    if !is_public(&product, &rules, segment.as_deref()) {
+        return Err(DbErr::RecordNotFound("Loan product not found".into()));
+    }
+
+    quote(
+        &product,
+        data.principal,
+        data.tenure_days,
+        data.repayment_freq,
+    )
+}
+
+// Ranks every active product the segment can take at this principal and
+// tenure by total cost of credit; products outside their bounds are skipped
+pub async fn compare(
+    model: &CompareProductsModel,
+    state: &web::Data<AppState>,
+) -> Result<Vec<ProductComparisonModel>, DbErr> {
+    let data = model.clone();
+    let segment = data.segment.to_value();
+
+    let products = entity::loan_products::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_products::Column::InstitutionId.eq(data.institution_id))
+                .add(entity::loan_products::Column::IsActive.eq(true)),
+        )
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let rules = visibility_rules(data.institution_id, state).await?;
+
+    let mut comparison: Vec<ProductComparisonModel> = products
+        .iter()
+        .filter(|product| is_public(product, &rules, Some(&segment)))
+        .filter_map(|product| quote(product, data.principal, data.tenure_days, None).ok())
+        .map(|quote| ProductComparisonModel {
+            loan_product_id: quote.loan_product_id,
+            product_name: quote.product_name,
+            interest_rate: quote.interest_rate,
+            repayment_freq: quote.repayment_freq,
+            installment_count: quote.installment_count,
+            installment_amount: quote.installment_amount,
+            total_interest: quote.total_interest,
+            total_fees: quote.total_fees,
+            total_cost: quote.total_interest + quote.total_fees,
+            total_repayable: quote.total_repayable,
+        })
+        .collect();
+
+    comparison.sort_by_key(|product| (product.total_cost, product.loan_product_id));
+
+    Ok(comparison)
+}
@@ -0,0 +1,200 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::loan_collaterals::{
+        models::{
+            PledgeCollateralModel, PledgeCollateralParams, RecordValuationModel,
+            RecordValuationParams, ReleaseCollateralModel, ReleaseCollateralParams,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn pledge_collateral(
+    req: HttpRequest,
+    payload: web::Json<PledgeCollateralParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "LOAN_COLLATERAL_MANAGE", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let loan_application_id = id_parser(&data.loan_application_id, "Loan Application Id").await?;
+
+    let collateral = PledgeCollateralModel {
+        loan_application_id,
+        collateral_type: data.collateral_type,
+        description: data.description,
+        estimated_value: data.estimated_value,
+        valuation_date: data.valuation_date,
+        valuator_name: data.valuator_name,
+        valuation_report_url: data.valuation_report_url,
+        document_urls: data.document_urls,
+        ownership_documents: data.ownership_documents,
+    };
+
+    match services::pledge_collateral(&collateral, &state).await {
+        Ok(collateral) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Collateral Pledged",
+            collateral,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn record_valuation(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<RecordValuationParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "LOAN_COLLATERAL_MANAGE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Collateral Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let valuation = RecordValuationModel {
+        valuation_amount: data.valuation_amount,
+        valuation_date: data.valuation_date,
+        valuator_name: data.valuator_name,
+        valuation_report_url: data.valuation_report_url,
+    };
+
+    match services::record_valuation(&id, &valuation, &state).await {
+        Ok(valuation) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Valuation Recorded",
+            valuation,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn collateral_valuations(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Collateral Id").await?;
+
+    match services::get_valuations(&id, &state).await {
+        Ok(valuations) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            valuations,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn verify_collateral(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_COLLATERAL_VERIFY", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Collateral Id").await?;
+
+    match services::verify_collateral(&id, staff.id, &state).await {
+        Ok(collateral) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Collateral Verified",
+            collateral,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn release_collateral(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<ReleaseCollateralParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "LOAN_COLLATERAL_RELEASE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Collateral Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let release = ReleaseCollateralModel {
+        reason: payload.into_inner().reason,
+    };
+
+    match services::release_collateral(&id, &release, &state).await {
+        Ok(collateral) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Collateral Released",
+            collateral,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn application_collaterals(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Application Id").await?;
+
+    match services::get_application_collaterals(&id, &state).await {
+        Ok(collaterals) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            collaterals,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
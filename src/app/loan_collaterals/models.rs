@@ -0,0 +1,164 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use entity::sea_orm_active_enums::LoanCollateralTypes;
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[derive(Debug, Clone)]
+pub struct PledgeCollateralModel {
+    pub loan_application_id: i64,
+    pub collateral_type: LoanCollateralTypes,
+    pub description: Option<String>,
+    pub estimated_value: i64,
+    pub valuation_date: NaiveDate,
+    pub valuator_name: Option<String>,
+    pub valuation_report_url: Option<String>,
+    pub document_urls: Option<Value>,
+    pub ownership_documents: Option<Value>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordValuationModel {
+    pub valuation_amount: i64,
+    pub valuation_date: NaiveDate,
+    pub valuator_name: Option<String>,
+    pub valuation_report_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReleaseCollateralModel {
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PledgeCollateralParams {
+    #[serde(rename = "loanApplicationId")]
+    pub loan_application_id: String,
+    #[serde(rename = "collateralType")]
+    pub collateral_type: LoanCollateralTypes,
+    #[validate(length(min = 2, max = 500, message = "description cannot be < 2 and > 500"))]
+    pub description: Option<String>,
+    #[validate(range(min = 1, message = "estimatedValue must be greater than 0"))]
+    #[serde(rename = "estimatedValue")]
+    pub estimated_value: i64,
+    #[serde(rename = "valuationDate")]
+    pub valuation_date: NaiveDate,
+    #[validate(length(min = 2, max = 255, message = "valuatorName cannot be < 2 and > 255"))]
+    #[serde(rename = "valuatorName")]
+    pub valuator_name: Option<String>,
+    #[validate(url(message = "valuationReportUrl must be a valid url"))]
+    #[serde(rename = "valuationReportUrl")]
+    pub valuation_report_url: Option<String>,
+    #[serde(rename = "documentUrls")]
+    pub document_urls: Option<Value>,
+    #[serde(rename = "ownershipDocuments")]
+    pub ownership_documents: Option<Value>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RecordValuationParams {
+    #[validate(range(min = 1, message = "valuationAmount must be greater than 0"))]
+    #[serde(rename = "valuationAmount")]
+    pub valuation_amount: i64,
+    #[serde(rename = "valuationDate")]
+    pub valuation_date: NaiveDate,
+    #[validate(length(min = 2, max = 255, message = "valuatorName cannot be < 2 and > 255"))]
+    #[serde(rename = "valuatorName")]
+    pub valuator_name: Option<String>,
+    #[validate(url(message = "valuationReportUrl must be a valid url"))]
+    #[serde(rename = "valuationReportUrl")]
+    pub valuation_report_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ReleaseCollateralParams {
+    #[validate(length(min = 5, max = 255, message = "reason cannot be < 5 and > 255"))]
+    pub reason: String,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_collaterals::Entity")]
+pub struct CollateralResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "loan_application_id")]
+    pub loan_application_id: i64,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "loan_id")]
+    pub loan_id: Option<i64>,
+    #[sea_orm(from_col = "collateral_type")]
+    pub collateral_type: Option<LoanCollateralTypes>,
+    #[sea_orm(from_col = "description")]
+    pub description: Option<String>,
+    #[sea_orm(from_col = "estimated_value")]
+    pub estimated_value: i64,
+    #[sea_orm(from_col = "valuation_date")]
+    pub valuation_date: NaiveDate,
+    #[sea_orm(from_col = "document_urls")]
+    pub document_urls: Option<Value>,
+    #[sea_orm(from_col = "ownership_documents")]
+    pub ownership_documents: Option<Value>,
+    #[sea_orm(from_col = "is_verified")]
+    pub is_verified: Option<bool>,
+    #[sea_orm(from_col = "verified_at")]
+    pub verified_at: Option<DateTime<FixedOffset>>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "verified_by")]
+    pub verified_by: Option<i64>,
+    #[sea_orm(from_col = "is_released")]
+    pub is_released: Option<bool>,
+    #[sea_orm(from_col = "released_at")]
+    pub released_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "release_reason")]
+    pub release_reason: Option<String>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_collateral_valuations::Entity")]
+pub struct ValuationResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "collateral_id")]
+    pub collateral_id: i64,
+    #[sea_orm(from_col = "valuation_amount")]
+    pub valuation_amount: i64,
+    #[sea_orm(from_col = "valuation_date")]
+    pub valuation_date: NaiveDate,
+    #[sea_orm(from_col = "valuator_name")]
+    pub valuator_name: Option<String>,
+    #[sea_orm(from_col = "valuation_report_url")]
+    pub valuation_report_url: Option<String>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CollateralCoverageModel {
+    pub principal: i64,
+    pub collateral_value: i64,
+    pub coverage_ratio: Decimal,
+    pub minimum_ratio: Decimal,
+    pub is_collateral_required: bool,
+    pub is_sufficient: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ApplicationCollateralsModel {
+    pub coverage: CollateralCoverageModel,
+    pub collaterals: Vec<CollateralResponseModel>,
+}
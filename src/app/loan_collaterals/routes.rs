@@ -0,0 +1,45 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loan_collaterals::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loan-collaterals")
+            .route(
+                "",
+                web::post()
+                    .to(controllers::pledge_collateral)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/valuations",
+                web::post()
+                    .to(controllers::record_valuation)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/valuations",
+                web::get()
+                    .to(controllers::collateral_valuations)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/verify",
+                web::put()
+                    .to(controllers::verify_collateral)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/release",
+                web::put()
+                    .to(controllers::release_collateral)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/applications/{id}",
+                web::get()
+                    .to(controllers::application_collaterals)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
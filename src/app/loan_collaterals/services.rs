@@ -0,0 +1,483 @@
+use std::collections::HashMap;
+
+use actix_web::web;
+use entity::sea_orm_active_enums::LoanApplicationStatus;
+use migration::Expr;
+use sea_orm::{
+    ActiveEnum, ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait, prelude::Decimal,
+};
+use serde_json::Value;
+
+use crate::{
+    AppState,
+    app::{
+        loan_collaterals::models::{
+            ApplicationCollateralsModel, CollateralCoverageModel, CollateralResponseModel,
+            PledgeCollateralModel, RecordValuationModel, ReleaseCollateralModel,
+            ValuationResponseModel,
+        },
+        loans::services::active_statuses,
+    },
+    utils::gen_snow_ids::gen_snowflake_slug,
+};
+
+async fn find_application(
+    id: i64,
+    state: &web::Data<AppState>,
+) -> Result<entity::loan_applications::Model, DbErr> {
+    entity::loan_applications::Entity::find_by_id(id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan application not found".into()))
+}
+
+async fn find_product(
+    id: i64,
+    state: &web::Data<AppState>,
+) -> Result<entity::loan_products::Model, DbErr> {
+    entity::loan_products::Entity::find_by_id(id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan product not found".into()))
+}
+
+async fn find_collateral(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<entity::loan_collaterals::Model, DbErr> {
+    entity::loan_collaterals::Entity::find_by_id(*id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Collateral not found".into()))
+}
+
+async fn get_collateral(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<CollateralResponseModel, DbErr> {
+    entity::loan_collaterals::Entity::find_by_id(*id)
+        .into_model::<CollateralResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Collateral not found".into()))
+}
+
+// An empty or missing list means the product accepts any collateral type
+fn is_type_allowed(allowed: Option<&Value>, collateral_type: &str) -> bool {
+    match allowed {
+        Some(Value::Array(items)) if !items.is_empty() => items
+            .iter()
+            .any(|item| item.as_str() == Some(collateral_type)),
+        _ => true,
+    }
+}
+
+pub async fn pledge_collateral(
+    model: &PledgeCollateralModel,
+    state: &web::Data<AppState>,
+) -> Result<CollateralResponseModel, DbErr> {
+    let data = model.clone();
+
+    let application = find_application(data.loan_application_id, state).await?;
+
+    if !matches!(
+        application.status,
+        Some(LoanApplicationStatus::Draft) | Some(LoanApplicationStatus::Pending)
+    ) {
+        return Err(DbErr::Custom(
+            "Collateral can only be pledged to draft or pending applications".to_string(),
+        ));
+    }
+
+    let product = find_product(application.loan_product_id, state).await?;
+
+    if !is_type_allowed(
+        product.allowed_collateral_types.as_ref(),
+        &data.collateral_type.to_value(),
+    ) {
+        return Err(DbErr::Custom(format!(
+            "Collateral type {} is not allowed for this product",
+            data.collateral_type.to_value()
+        )));
+    }
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let (valuation_id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let txn = state.pgdb.begin().await?;
+
+    entity::loan_collaterals::ActiveModel {
+        id: Set(id),
+        loan_application_id: Set(application.id),
+        collateral_type: Set(Some(data.collateral_type)),
+        description: Set(data.description),
+        estimated_value: Set(data.estimated_value),
+        valuation_date: Set(data.valuation_date),
+        document_urls: Set(data.document_urls),
+        ownership_documents: Set(data.ownership_documents),
+        is_verified: Set(Some(false)),
+        is_released: Set(Some(false)),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+
+    entity::loan_collateral_valuations::ActiveModel {
+        id: Set(valuation_id),
+        collateral_id: Set(id),
+        valuation_amount: Set(data.estimated_value),
+        valuation_date: Set(data.valuation_date),
+        valuator_name: Set(data.valuator_name),
+        valuation_report_url: Set(data.valuation_report_url),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+
+    txn.commit().await?;
+
+    get_collateral(&id, state).await
+}
+
+pub async fn record_valuation(
+    id: &i64,
+    model: &RecordValuationModel,
+    state: &web::Data<AppState>,
+) -> Result<ValuationResponseModel, DbErr> {
+    let data = model.clone();
+
+    let collateral = find_collateral(id, state).await?;
+
+    if collateral.is_released.unwrap_or(false) {
+        return Err(DbErr::Custom(
+            "Released collateral cannot be revalued".to_string(),
+        ));
+    }
+
+    let (valuation_id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let txn = state.pgdb.begin().await?;
+
+    entity::loan_collateral_valuations::ActiveModel {
+        id: Set(valuation_id),
+        collateral_id: Set(collateral.id),
+        valuation_amount: Set(data.valuation_amount),
+        valuation_date: Set(data.valuation_date),
+        valuator_name: Set(data.valuator_name),
+        valuation_report_url: Set(data.valuation_report_url),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+
+    // Back-dated valuations are kept for history but do not replace a newer one
+    if data.valuation_date >= collateral.valuation_date {
+        let mut active_collateral: entity::loan_collaterals::ActiveModel = collateral.into();
+        active_collateral.estimated_value = Set(data.valuation_amount);
+        active_collateral.valuation_date = Set(data.valuation_date);
+        active_collateral.updated_at = Set(Some(chrono::Utc::now().into()));
+        active_collateral.update(&txn).await?;
+    }
+
+    txn.commit().await?;
+
+    entity::loan_collateral_valuations::Entity::find_by_id(valuation_id)
+        .into_model::<ValuationResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Valuation not found".into()))
+}
+
+pub async fn get_valuations(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<ValuationResponseModel>, DbErr> {
+    find_collateral(id, state).await?;
+
+    entity::loan_collateral_valuations::Entity::find()
+        .filter(entity::loan_collateral_valuations::Column::CollateralId.eq(*id))
+        .order_by_desc(entity::loan_collateral_valuations::Column::ValuationDate)
+        .order_by_desc(entity::loan_collateral_valuations::Column::CreatedAt)
+        .into_model::<ValuationResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await
+}
+
+pub async fn verify_collateral(
+    id: &i64,
+    verified_by: i64,
+    state: &web::Data<AppState>,
+) -> Result<CollateralResponseModel, DbErr> {
+    let collateral = find_collateral(id, state).await?;
+
+    if collateral.is_released.unwrap_or(false) {
+        return Err(DbErr::Custom(
+            "Released collateral cannot be verified".to_string(),
+        ));
+    }
+
+    if collateral.is_verified.unwrap_or(false) {
+        return Err(DbErr::Custom("Collateral is already verified".to_string()));
+    }
+
+    let mut active_collateral: entity::loan_collaterals::ActiveModel = collateral.into();
+    active_collateral.is_verified = Set(Some(true));
+    active_collateral.verified_at = Set(Some(chrono::Utc::now().into()));
+    active_collateral.verified_by = Set(Some(verified_by));
+    active_collateral.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_collateral.update(state.pgdb.get_ref()).await?;
+
+    get_collateral(id, state).await
+}
+
+pub async fn release_collateral(
+    id: &i64,
+    model: &ReleaseCollateralModel,
+    state: &web::Data<AppState>,
+) -> Result<CollateralResponseModel, DbErr> {
+    let data = model.clone();
+
+    let collateral = find_collateral(id, state).await?;
+
+    let txn = state.pgdb.begin().await?;
+
+    // Approval locks the application too, so a release either lands before
+    // the approval's coverage check or sees the application approved
+    let application = entity::loan_applications::Entity::find_by_id(collateral.loan_application_id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan application not found".into()))?;
+
+    let collateral = entity::loan_collaterals::Entity::find_by_id(collateral.id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Collateral not found".into()))?;
+
+    if collateral.is_released.unwrap_or(false) {
+        return Err(DbErr::Custom("Collateral is already released".to_string()));
+    }
+
+    // Approved collateral secures the loan about to be disbursed; once
+    // disbursed it is held until the loan it is linked to closes
+    let loan_active = match collateral.loan_id {
+        Some(loan_id) => entity::loans::Entity::find_by_id(loan_id)
+            .one(&txn)
+            .await?
+            .is_some_and(|loan| {
+                loan.status
+                    .as_ref()
+                    .is_some_and(|status| active_statuses().contains(status))
+            }),
+        None => false,
+    };
+
+    let held = match application.status {
+        Some(LoanApplicationStatus::Approved) => true,
+        Some(LoanApplicationStatus::Disbursed) => collateral.loan_id.is_none() || loan_active,
+        _ => loan_active,
+    };
+
+    if held {
+        return Err(DbErr::Custom(
+            "Collateral securing an approved application or active loan cannot be released"
+                .to_string(),
+        ));
+    }
+
+    let mut active_collateral: entity::loan_collaterals::ActiveModel = collateral.into();
+    active_collateral.is_released = Set(Some(true));
+    active_collateral.released_at = Set(Some(chrono::Utc::now().into()));
+    active_collateral.release_reason = Set(Some(data.reason));
+    active_collateral.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_collateral.update(&txn).await?;
+
+    txn.commit().await?;
+
+    get_collateral(id, state).await
+}
+
+// Links the application's unreleased collateral to the loan it secures
+pub async fn link_to_loan<C: ConnectionTrait>(
+    conn: &C,
+    application_id: i64,
+    loan_id: i64,
+) -> Result<u64, DbErr> {
+    let result = entity::loan_collaterals::Entity::update_many()
+        .filter(
+            Condition::all()
+                .add(entity::loan_collaterals::Column::LoanApplicationId.eq(application_id))
+                .add(
+                    Condition::any()
+                        .add(entity::loan_collaterals::Column::IsReleased.eq(false))
+                        .add(entity::loan_collaterals::Column::IsReleased.is_null()),
+                ),
+        )
+        .col_expr(
+            entity::loan_collaterals::Column::LoanId,
+            Expr::value(loan_id),
+        )
+        .col_expr(
+            entity::loan_collaterals::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
+
+// Collateral securing a refinanced loan carry over to the loan that replaced it,
+// including any captured before the old loan was linked
+pub async fn transfer_to_loan<C: ConnectionTrait>(
+    conn: &C,
+    from_loan: &entity::loans::Model,
+    loan_id: i64,
+) -> Result<u64, DbErr> {
+    let result = entity::loan_collaterals::Entity::update_many()
+        .filter(
+            Condition::all()
+                .add(
+                    Condition::any()
+                        .add(entity::loan_collaterals::Column::LoanId.eq(from_loan.id))
+                        .add(
+                            Condition::all()
+                                .add(entity::loan_collaterals::Column::LoanId.is_null())
+                                .add(
+                                    entity::loan_collaterals::Column::LoanApplicationId
+                                        .eq(from_loan.loan_application_id),
+                                ),
+                        ),
+                )
+                .add(
+                    Condition::any()
+                        .add(entity::loan_collaterals::Column::IsReleased.eq(false))
+                        .add(entity::loan_collaterals::Column::IsReleased.is_null()),
+                ),
+        )
+        .col_expr(
+            entity::loan_collaterals::Column::LoanId,
+            Expr::value(loan_id),
+        )
+        .col_expr(
+            entity::loan_collaterals::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
+
+// Only verified, unreleased collateral counts, valued at its most recent valuation
+pub async fn collateral_coverage<C: ConnectionTrait>(
+    conn: &C,
+    application: &entity::loan_applications::Model,
+    product: &entity::loan_products::Model,
+    principal: i64,
+) -> Result<CollateralCoverageModel, DbErr> {
+    let collaterals = entity::loan_collaterals::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_collaterals::Column::LoanApplicationId.eq(application.id))
+                .add(entity::loan_collaterals::Column::IsVerified.eq(true))
+                .add(
+                    Condition::any()
+                        .add(entity::loan_collaterals::Column::IsReleased.eq(false))
+                        .add(entity::loan_collaterals::Column::IsReleased.is_null()),
+                ),
+        )
+        .all(conn)
+        .await?;
+
+    let valuations = entity::loan_collateral_valuations::Entity::find()
+        .filter(
+            entity::loan_collateral_valuations::Column::CollateralId
+                .is_in(collaterals.iter().map(|collateral| collateral.id)),
+        )
+        .order_by_desc(entity::loan_collateral_valuations::Column::ValuationDate)
+        .order_by_desc(entity::loan_collateral_valuations::Column::CreatedAt)
+        .all(conn)
+        .await?;
+
+    let mut latest: HashMap<i64, i64> = HashMap::new();
+
+    for valuation in valuations {
+        latest
+            .entry(valuation.collateral_id)
+            .or_insert(valuation.valuation_amount);
+    }
+
+    let collateral_value: i64 = collaterals
+        .iter()
+        .map(|collateral| {
+            latest
+                .get(&collateral.id)
+                .copied()
+                .unwrap_or(collateral.estimated_value)
+        })
+        .sum();
+
+    let coverage_ratio = if principal > 0 {
+        (Decimal::from(collateral_value) * Decimal::ONE_HUNDRED / Decimal::from(principal))
+            .round_dp(2)
+    } else {
+        Decimal::ZERO
+    };
+
+    let minimum_ratio = product.minimum_collateral_ratio.unwrap_or(Decimal::ZERO);
+    let is_collateral_required =
+        product.is_collateral_required.unwrap_or(false) || minimum_ratio > Decimal::ZERO;
+
+    let is_sufficient =
+        !is_collateral_required || (!collaterals.is_empty() && coverage_ratio >= minimum_ratio);
+
+    Ok(CollateralCoverageModel {
+        principal,
+        collateral_value,
+        coverage_ratio,
+        minimum_ratio,
+        is_collateral_required,
+        is_sufficient,
+    })
+}
+
+pub async fn get_application_collaterals(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<ApplicationCollateralsModel, DbErr> {
+    let application = find_application(*id, state).await?;
+
+    let product = find_product(application.loan_product_id, state).await?;
+
+    let principal = application
+        .approved_principal
+        .unwrap_or(application.requested_principal);
+
+    let coverage =
+        collateral_coverage(state.pgdb.get_ref(), &application, &product, principal).await?;
+
+    let collaterals = entity::loan_collaterals::Entity::find()
+        .filter(entity::loan_collaterals::Column::LoanApplicationId.eq(application.id))
+        .order_by_asc(entity::loan_collaterals::Column::CreatedAt)
+        .into_model::<CollateralResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(ApplicationCollateralsModel {
+        coverage,
+        collaterals,
+    })
+}
@@ -0,0 +1,147 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::loan_documents::{
+        models::{
+            SaveChecklistItemModel, SaveChecklistItemParams, UploadDocumentModel,
+            UploadDocumentParams,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn save_checklist_item(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<SaveChecklistItemParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_DOCUMENT_CONFIGURE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Product Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let item = SaveChecklistItemModel {
+        loan_product_id: id,
+        document_code: data.document_code.to_uppercase(),
+        document_name: data.document_name,
+        description: data.description,
+        is_mandatory: data.is_mandatory.unwrap_or(true),
+        is_active: data.is_active.unwrap_or(true),
+        created_by: staff.id,
+    };
+
+    match services::save_checklist_item(&item, &state).await {
+        Ok(item) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            item,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn get_checklist(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Product Id").await?;
+
+    match services::get_checklist(&id, &state).await {
+        Ok(checklist) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            checklist,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn upload_document(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<UploadDocumentParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_DOCUMENT_UPLOAD", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Application Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let document = UploadDocumentModel {
+        loan_application_id: id,
+        document_code: data.document_code.to_uppercase(),
+        document_url: data.document_url,
+        file_name: data.file_name,
+        uploaded_by: staff.id,
+    };
+
+    match services::upload_document(&document, &state).await {
+        Ok(documents) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Document Uploaded",
+            documents,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn get_application_documents(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Application Id").await?;
+
+    match services::get_application_documents(&id, &state).await {
+        Ok(documents) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            documents,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
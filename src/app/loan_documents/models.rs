@@ -0,0 +1,112 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[derive(Debug, Clone)]
+pub struct SaveChecklistItemModel {
+    pub loan_product_id: i64,
+    pub document_code: String,
+    pub document_name: String,
+    pub description: Option<String>,
+    pub is_mandatory: bool,
+    pub is_active: bool,
+    pub created_by: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadDocumentModel {
+    pub loan_application_id: i64,
+    pub document_code: String,
+    pub document_url: String,
+    pub file_name: Option<String>,
+    pub uploaded_by: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SaveChecklistItemParams {
+    #[validate(length(min = 2, max = 50, message = "documentCode cannot be < 2 and > 50"))]
+    #[serde(rename = "documentCode")]
+    pub document_code: String,
+    #[validate(length(min = 2, max = 255, message = "documentName cannot be < 2 and > 255"))]
+    #[serde(rename = "documentName")]
+    pub document_name: String,
+    #[validate(length(min = 2, max = 500, message = "description cannot be < 2 and > 500"))]
+    pub description: Option<String>,
+    #[serde(rename = "isMandatory")]
+    pub is_mandatory: Option<bool>,
+    #[serde(rename = "isActive")]
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct UploadDocumentParams {
+    #[validate(length(min = 2, max = 50, message = "documentCode cannot be < 2 and > 50"))]
+    #[serde(rename = "documentCode")]
+    pub document_code: String,
+    #[validate(url(message = "documentUrl must be a valid url"))]
+    #[serde(rename = "documentUrl")]
+    pub document_url: String,
+    #[validate(length(min = 1, max = 255, message = "fileName cannot be < 1 and > 255"))]
+    #[serde(rename = "fileName")]
+    pub file_name: Option<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_product_documents::Entity")]
+pub struct ChecklistItemResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "loan_product_id")]
+    pub loan_product_id: i64,
+    #[sea_orm(from_col = "document_code")]
+    pub document_code: String,
+    #[sea_orm(from_col = "document_name")]
+    pub document_name: String,
+    #[sea_orm(from_col = "description")]
+    pub description: Option<String>,
+    #[sea_orm(from_col = "is_mandatory")]
+    pub is_mandatory: bool,
+    #[sea_orm(from_col = "is_active")]
+    pub is_active: Option<bool>,
+    #[sea_orm(from_col = "updated_at")]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_application_documents::Entity")]
+pub struct ApplicationDocumentResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "loan_application_id")]
+    pub loan_application_id: i64,
+    #[sea_orm(from_col = "document_code")]
+    pub document_code: String,
+    #[sea_orm(from_col = "document_url")]
+    pub document_url: String,
+    #[sea_orm(from_col = "file_name")]
+    pub file_name: Option<String>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "uploaded_by")]
+    pub uploaded_by: Option<i64>,
+    #[sea_orm(from_col = "updated_at")]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ApplicationDocumentsModel {
+    pub documents: Vec<ApplicationDocumentResponseModel>,
+    pub documents_missing: Value,
+}
@@ -0,0 +1,33 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loan_documents::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loan-documents")
+            .route(
+                "/products/{id}/checklist",
+                web::post()
+                    .to(controllers::save_checklist_item)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/products/{id}/checklist",
+                web::get()
+                    .to(controllers::get_checklist)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/applications/{id}",
+                web::post()
+                    .to(controllers::upload_document)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/applications/{id}",
+                web::get()
+                    .to(controllers::get_application_documents)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
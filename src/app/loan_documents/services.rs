@@ -0,0 +1,313 @@
+use std::collections::HashSet;
+
+use actix_web::web;
+use entity::sea_orm_active_enums::LoanApplicationStatus;
+use migration::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, TransactionTrait,
+};
+use serde_json::{Value, json};
+
+use crate::{
+    AppState,
+    app::loan_documents::models::{
+        ApplicationDocumentResponseModel, ApplicationDocumentsModel, ChecklistItemResponseModel,
+        SaveChecklistItemModel, UploadDocumentModel,
+    },
+    utils::gen_snow_ids::gen_snowflake_slug,
+};
+
+pub async fn save_checklist_item(
+    model: &SaveChecklistItemModel,
+    state: &web::Data<AppState>,
+) -> Result<ChecklistItemResponseModel, DbErr> {
+    let data = model.clone();
+
+    entity::loan_products::Entity::find_by_id(data.loan_product_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan product not found".into()))?;
+
+    let existing = entity::loan_product_documents::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_product_documents::Column::LoanProductId.eq(data.loan_product_id))
+                .add(entity::loan_product_documents::Column::DocumentCode.eq(&data.document_code)),
+        )
+        .one(state.pgdb.get_ref())
+        .await?;
+
+    let id = match existing {
+        Some(item) => {
+            let id = item.id;
+            let mut active_item: entity::loan_product_documents::ActiveModel = item.into();
+
+            active_item.document_name = Set(data.document_name);
+            active_item.description = Set(data.description);
+            active_item.is_mandatory = Set(data.is_mandatory);
+            active_item.is_active = Set(Some(data.is_active));
+            active_item.updated_at = Set(Some(chrono::Utc::now().into()));
+
+            ActiveModelTrait::update(active_item, state.pgdb.get_ref()).await?;
+
+            id
+        }
+        None => {
+            let (id, _) = match gen_snowflake_slug() {
+                Ok(res) => res,
+                Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+            };
+
+            entity::loan_product_documents::ActiveModel {
+                id: Set(id),
+                loan_product_id: Set(data.loan_product_id),
+                document_code: Set(data.document_code),
+                document_name: Set(data.document_name),
+                description: Set(data.description),
+                is_mandatory: Set(data.is_mandatory),
+                is_active: Set(Some(data.is_active)),
+                created_by: Set(Some(data.created_by)),
+                ..Default::default()
+            }
+            .insert(state.pgdb.get_ref())
+            .await?;
+
+            id
+        }
+    };
+
+    entity::loan_product_documents::Entity::find_by_id(id)
+        .into_model::<ChecklistItemResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Checklist item not found".into()))
+}
+
+pub async fn get_checklist(
+    loan_product_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<ChecklistItemResponseModel>, DbErr> {
+    let checklist = entity::loan_product_documents::Entity::find()
+        .filter(entity::loan_product_documents::Column::LoanProductId.eq(*loan_product_id))
+        .order_by_asc(entity::loan_product_documents::Column::DocumentCode)
+        .into_model::<ChecklistItemResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(checklist)
+}
+
+async fn active_checklist<C: ConnectionTrait>(
+    conn: &C,
+    loan_product_id: i64,
+) -> Result<Vec<entity::loan_product_documents::Model>, DbErr> {
+    entity::loan_product_documents::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_product_documents::Column::LoanProductId.eq(loan_product_id))
+                .add(entity::loan_product_documents::Column::IsActive.eq(true)),
+        )
+        .order_by_asc(entity::loan_product_documents::Column::DocumentCode)
+        .all(conn)
+        .await
+}
+
+// Rebuilds documents_provided and documents_missing on the application
+// from the product checklist and the uploads, returning what is missing
+pub async fn refresh_documents<C: ConnectionTrait>(
+    conn: &C,
+    loan_application_id: i64,
+    loan_product_id: i64,
+) -> Result<Vec<entity::loan_product_documents::Model>, DbErr> {
+    let checklist = active_checklist(conn, loan_product_id).await?;
+
+    let uploads = entity::loan_application_documents::Entity::find()
+        .filter(
+            entity::loan_application_documents::Column::LoanApplicationId.eq(loan_application_id),
+        )
+        .order_by_asc(entity::loan_application_documents::Column::DocumentCode)
+        .all(conn)
+        .await?;
+
+    let uploaded: HashSet<&str> = uploads
+        .iter()
+        .map(|upload| upload.document_code.as_str())
+        .collect();
+
+    let missing: Vec<entity::loan_product_documents::Model> = checklist
+        .into_iter()
+        .filter(|item| item.is_mandatory && !uploaded.contains(item.document_code.as_str()))
+        .collect();
+
+    let provided: Vec<Value> = uploads
+        .iter()
+        .map(|upload| {
+            json!({
+                "code": upload.document_code,
+                "url": upload.document_url,
+                "file_name": upload.file_name,
+            })
+        })
+        .collect();
+
+    let missing_items: Vec<Value> = missing
+        .iter()
+        .map(|item| json!({ "code": item.document_code, "name": item.document_name }))
+        .collect();
+
+    entity::loan_applications::Entity::update_many()
+        .col_expr(
+            entity::loan_applications::Column::DocumentsProvided,
+            Expr::value(Value::Array(provided)),
+        )
+        .col_expr(
+            entity::loan_applications::Column::DocumentsMissing,
+            Expr::value(Value::Array(missing_items)),
+        )
+        .col_expr(
+            entity::loan_applications::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(entity::loan_applications::Column::Id.eq(loan_application_id))
+        .exec(conn)
+        .await?;
+
+    Ok(missing)
+}
+
+// Mandatory documents must all be uploaded before review
+pub async fn ensure_documents_complete<C: ConnectionTrait>(
+    conn: &C,
+    loan_application_id: i64,
+    loan_product_id: i64,
+) -> Result<(), DbErr> {
+    let missing = refresh_documents(conn, loan_application_id, loan_product_id).await?;
+
+    if !missing.is_empty() {
+        let names: Vec<&str> = missing
+            .iter()
+            .map(|item| item.document_name.as_str())
+            .collect();
+
+        return Err(DbErr::Custom(format!(
+            "Mandatory documents missing: {}",
+            names.join(", ")
+        )));
+    }
+
+    Ok(())
+}
+
+pub async fn upload_document(
+    model: &UploadDocumentModel,
+    state: &web::Data<AppState>,
+) -> Result<ApplicationDocumentsModel, DbErr> {
+    let data = model.clone();
+
+    let application = entity::loan_applications::Entity::find_by_id(data.loan_application_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan application not found".into()))?;
+
+    if !matches!(
+        application.status,
+        Some(LoanApplicationStatus::Draft) | Some(LoanApplicationStatus::Pending)
+    ) {
+        return Err(DbErr::Custom(
+            "Documents can only be uploaded to draft or pending applications".to_string(),
+        ));
+    }
+
+    let checklist = active_checklist(state.pgdb.get_ref(), application.loan_product_id).await?;
+
+    if !checklist
+        .iter()
+        .any(|item| item.document_code == data.document_code)
+    {
+        return Err(DbErr::Custom(format!(
+            "Document {} is not on the product checklist",
+            data.document_code
+        )));
+    }
+
+    let existing = entity::loan_application_documents::Entity::find()
+        .filter(
+            Condition::all()
+                .add(
+                    entity::loan_application_documents::Column::LoanApplicationId
+                        .eq(application.id),
+                )
+                .add(
+                    entity::loan_application_documents::Column::DocumentCode
+                        .eq(&data.document_code),
+                ),
+        )
+        .one(state.pgdb.get_ref())
+        .await?;
+
+    let txn = state.pgdb.begin().await?;
+
+    // Re-uploading a document replaces the previous file
+    match existing {
+        Some(document) => {
+            let mut active_document: entity::loan_application_documents::ActiveModel =
+                document.into();
+
+            active_document.document_url = Set(data.document_url);
+            active_document.file_name = Set(data.file_name);
+            active_document.uploaded_by = Set(Some(data.uploaded_by));
+            active_document.updated_at = Set(Some(chrono::Utc::now().into()));
+
+            active_document.update(&txn).await?;
+        }
+        None => {
+            let (id, _) = match gen_snowflake_slug() {
+                Ok(res) => res,
+                Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+            };
+
+            entity::loan_application_documents::ActiveModel {
+                id: Set(id),
+                loan_application_id: Set(application.id),
+                document_code: Set(data.document_code),
+                document_url: Set(data.document_url),
+                file_name: Set(data.file_name),
+                uploaded_by: Set(Some(data.uploaded_by)),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await?;
+        }
+    }
+
+    refresh_documents(&txn, application.id, application.loan_product_id).await?;
+
+    txn.commit().await?;
+
+    get_application_documents(&application.id, state).await
+}
+
+pub async fn get_application_documents(
+    loan_application_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<ApplicationDocumentsModel, DbErr> {
+    let application = entity::loan_applications::Entity::find_by_id(*loan_application_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan application not found".into()))?;
+
+    let documents = entity::loan_application_documents::Entity::find()
+        .filter(
+            entity::loan_application_documents::Column::LoanApplicationId.eq(*loan_application_id),
+        )
+        .order_by_asc(entity::loan_application_documents::Column::DocumentCode)
+        .into_model::<ApplicationDocumentResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(ApplicationDocumentsModel {
+        documents,
+        documents_missing: application.documents_missing.unwrap_or(json!([])),
+    })
+}
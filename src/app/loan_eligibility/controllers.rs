@@ -0,0 +1,36 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::loan_eligibility::{models::EligibilityParams, services},
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+    },
+};
+
+pub async fn check_eligibility(
+    _req: HttpRequest,
+    payload: web::Json<EligibilityParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let customer_id = id_parser(&data.customer_id, "Customer Id").await?;
+
+    let loan_product_id = id_parser(&data.loan_product_id, "Loan Product Id").await?;
+
+    match services::evaluate(&customer_id, &loan_product_id, None, &state).await {
+        Ok(result) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            result,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct EligibilityParams {
+    #[serde(rename = "customerId")]
+    pub customer_id: String,
+    #[serde(rename = "loanProductId")]
+    pub loan_product_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EligibilityRuleModel {
+    pub rule: String,
+    pub passed: bool,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EligibilityResultModel {
+    pub eligible: bool,
+    pub rules: Vec<EligibilityRuleModel>,
+}
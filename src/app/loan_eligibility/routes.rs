@@ -0,0 +1,14 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loan_eligibility::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loan-eligibility").route(
+            "",
+            web::post()
+                .to(controllers::check_eligibility)
+                .wrap(from_fn(jwt_auth)),
+        ),
+    );
+}
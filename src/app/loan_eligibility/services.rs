@@ -0,0 +1,379 @@
+use actix_web::web;
+use sea_orm::{ActiveEnum, ColumnTrait, Condition, DbErr, EntityTrait, QueryFilter, QueryOrder};
+use serde_json::Value;
+
+use crate::{
+    AppState,
+    app::{
+        loan_eligibility::models::{EligibilityResultModel, EligibilityRuleModel},
+        loans::services::active_statuses,
+    },
+};
+
+fn rule(name: &str, passed: bool, reason: String) -> EligibilityRuleModel {
+    EligibilityRuleModel {
+        rule: name.to_string(),
+        passed,
+        reason,
+    }
+}
+
+// KYC tiers are stored as JSON and may be a number, a label such as "TIER_2",
+// or an object carrying a "tier" or "level" key
+fn kyc_level(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(number) => number.as_i64(),
+        Value::String(text) => text
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect::<String>()
+            .parse()
+            .ok(),
+        Value::Object(map) => map
+            .get("tier")
+            .or_else(|| map.get("level"))
+            .and_then(kyc_level),
+        _ => None,
+    }
+}
+
+fn check_kyc(required: Option<&Value>, customer_tier: Option<&Value>) -> EligibilityRuleModel {
+    let customer_level = customer_tier.and_then(kyc_level);
+
+    match required {
+        Some(Value::Array(tiers)) if !tiers.is_empty() => {
+            let allowed: Vec<i64> = tiers.iter().filter_map(kyc_level).collect();
+
+            match customer_level {
+                Some(level) if allowed.contains(&level) => {
+                    rule("kyc_tier", true, format!("KYC tier {} is accepted", level))
+                }
+                Some(level) => rule(
+                    "kyc_tier",
+                    false,
+                    format!("KYC tier {} is not one of {:?}", level, allowed),
+                ),
+                None => rule("kyc_tier", false, "Customer has no KYC tier".to_string()),
+            }
+        }
+        Some(value) => match (kyc_level(value), customer_level) {
+            (Some(minimum), Some(level)) if level >= minimum => rule(
+                "kyc_tier",
+                true,
+                format!("KYC tier {} meets required tier {}", level, minimum),
+            ),
+            (Some(minimum), Some(level)) => rule(
+                "kyc_tier",
+                false,
+                format!("KYC tier {} is below required tier {}", level, minimum),
+            ),
+            (Some(minimum), None) => rule(
+                "kyc_tier",
+                false,
+                format!("Customer has no KYC tier, tier {} is required", minimum),
+            ),
+            (None, _) => rule(
+                "kyc_tier",
+                true,
+                "Product has no KYC requirement".to_string(),
+            ),
+        },
+        None => rule(
+            "kyc_tier",
+            true,
+            "Product has no KYC requirement".to_string(),
+        ),
+    }
+}
+
+fn check_age(
+    product: &entity::loan_products::Model,
+    customer: &entity::customers::Model,
+) -> EligibilityRuleModel {
+    if product.min_age.is_none() && product.max_age.is_none() {
+        return rule("age", true, "Product has no age limits".to_string());
+    }
+
+    let Some(age) = customer
+        .date_of_birth
+        .and_then(|dob| chrono::Utc::now().date_naive().years_since(dob))
+        .map(|age| age as i32)
+    else {
+        return rule(
+            "age",
+            false,
+            "Customer date of birth is missing".to_string(),
+        );
+    };
+
+    if product.min_age.is_some_and(|min_age| age < min_age) {
+        return rule(
+            "age",
+            false,
+            format!(
+                "Customer is {} years old, minimum age is {}",
+                age,
+                product.min_age.unwrap_or(0)
+            ),
+        );
+    }
+
+    if product.max_age.is_some_and(|max_age| age > max_age) {
+        return rule(
+            "age",
+            false,
+            format!(
+                "Customer is {} years old, maximum age is {}",
+                age,
+                product.max_age.unwrap_or(0)
+            ),
+        );
+    }
+
+    rule(
+        "age",
+        true,
+        format!("Customer age {} is within product limits", age),
+    )
+}
+
+fn check_customer_type(
+    product: &entity::loan_products::Model,
+    customer: &entity::customers::Model,
+) -> EligibilityRuleModel {
+    let customer_type = customer
+        .customer_type
+        .as_ref()
+        .map(|customer_type| customer_type.to_value());
+
+    match &product.allowed_customer_types {
+        Some(Value::Array(types)) if !types.is_empty() => match customer_type {
+            Some(customer_type)
+                if types
+                    .iter()
+                    .any(|item| item.as_str() == Some(&customer_type)) =>
+            {
+                rule(
+                    "customer_type",
+                    true,
+                    format!("Customer type {} is allowed", customer_type),
+                )
+            }
+            Some(customer_type) => rule(
+                "customer_type",
+                false,
+                format!(
+                    "Customer type {} is not allowed for this product",
+                    customer_type
+                ),
+            ),
+            None => rule(
+                "customer_type",
+                false,
+                "Customer type is not set".to_string(),
+            ),
+        },
+        _ => rule(
+            "customer_type",
+            true,
+            "Product accepts all customer types".to_string(),
+        ),
+    }
+}
+
+// Either the customer's own flag or an active blacklist record blocks lending
+fn check_blacklist(
+    customer: &entity::customers::Model,
+    record: Option<&entity::customer_blacklist_records::Model>,
+) -> EligibilityRuleModel {
+    if customer.is_black_listed.unwrap_or(false) {
+        return rule(
+            "blacklist",
+            false,
+            format!(
+                "Customer is blacklisted: {}",
+                customer
+                    .black_list_reason
+                    .clone()
+                    .unwrap_or_else(|| "no reason recorded".to_string())
+            ),
+        );
+    }
+
+    match record {
+        Some(record) => rule(
+            "blacklist",
+            false,
+            format!(
+                "Customer has an active blacklist record: {}",
+                record
+                    .blacklist_reason
+                    .clone()
+                    .unwrap_or_else(|| "no reason recorded".to_string())
+            ),
+        ),
+        None => rule("blacklist", true, "Customer is not blacklisted".to_string()),
+    }
+}
+
+fn check_arrears(loans_in_arrears: &[entity::loans::Model]) -> EligibilityRuleModel {
+    if loans_in_arrears.is_empty() {
+        return rule(
+            "arrears",
+            true,
+            "Customer has no loans in arrears".to_string(),
+        );
+    }
+
+    let days = loans_in_arrears
+        .iter()
+        .filter_map(|loan| loan.days_in_arrears)
+        .max()
+        .unwrap_or(0);
+
+    rule(
+        "arrears",
+        false,
+        format!(
+            "Customer has {} loan(s) in arrears, up to {} days",
+            loans_in_arrears.len(),
+            days
+        ),
+    )
+}
+
+fn failure_summary(result: &EligibilityResultModel) -> String {
+    result
+        .rules
+        .iter()
+        .filter(|rule| !rule.passed)
+        .map(|rule| rule.reason.clone())
+        .collect::<Vec<String>>()
+        .join("; ")
+}
+
+// A known application score is preferred over the latest bureau report on file
+pub async fn evaluate(
+    customer_id: &i64,
+    product_id: &i64,
+    credit_score: Option<i32>,
+    state: &web::Data<AppState>,
+) -> Result<EligibilityResultModel, DbErr> {
+    let customer = entity::customers::Entity::find_by_id(*customer_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Customer not found".into()))?;
+
+    let product = entity::loan_products::Entity::find_by_id(*product_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan product not found".into()))?;
+
+    let loans_in_arrears = entity::loans::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loans::Column::CustomerId.eq(customer.id))
+                .add(entity::loans::Column::Status.is_in(active_statuses()))
+                .add(entity::loans::Column::DaysInArrears.gt(0)),
+        )
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let blacklist_record = entity::customer_blacklist_records::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::customer_blacklist_records::Column::CustomerId.eq(customer.id))
+                .add(
+                    Condition::any()
+                        .add(entity::customer_blacklist_records::Column::IsActive.eq(true))
+                        .add(entity::customer_blacklist_records::Column::IsActive.is_null()),
+                )
+                .add(entity::customer_blacklist_records::Column::RemovedAt.is_null()),
+        )
+        .order_by_desc(entity::customer_blacklist_records::Column::CreatedAt)
+        .one(state.pgdb.get_ref())
+        .await?;
+
+    let mut rules = vec![
+        if customer.institution_id == product.institution_id {
+            rule(
+                "institution",
+                true,
+                "Customer belongs to the product's institution".to_string(),
+            )
+        } else {
+            rule(
+                "institution",
+                false,
+                "Customer does not belong to the product's institution".to_string(),
+            )
+        },
+        if product.is_active.unwrap_or(true) {
+            rule("product", true, "Product is active".to_string())
+        } else {
+            rule("product", false, "Product is not active".to_string())
+        },
+        check_age(&product, &customer),
+        check_kyc(product.required_kyc.as_ref(), customer.kyc_tier.as_ref()),
+        check_customer_type(&product, &customer),
+        check_blacklist(&customer, blacklist_record.as_ref()),
+        check_arrears(&loans_in_arrears),
+    ];
+
+    if let Some(minimum_score) = product.minimum_credit_score {
+        let score = match credit_score {
+            Some(score) => Some(score),
+            None => entity::credit_bureau_reports::Entity::find()
+                .filter(entity::credit_bureau_reports::Column::CustomerId.eq(customer.id))
+                .filter(entity::credit_bureau_reports::Column::CreditScore.is_not_null())
+                .order_by_desc(entity::credit_bureau_reports::Column::CreatedAt)
+                .one(state.pgdb.get_ref())
+                .await?
+                .and_then(|report| report.credit_score),
+        };
+
+        rules.push(match score {
+            Some(score) if score >= minimum_score => rule(
+                "credit_score",
+                true,
+                format!("Credit score {} meets minimum {}", score, minimum_score),
+            ),
+            Some(score) => rule(
+                "credit_score",
+                false,
+                format!("Credit score {} is below minimum {}", score, minimum_score),
+            ),
+            None => rule(
+                "credit_score",
+                false,
+                format!(
+                    "No credit score on record, minimum {} required",
+                    minimum_score
+                ),
+            ),
+        });
+    }
+
+    Ok(EligibilityResultModel {
+        eligible: rules.iter().all(|rule| rule.passed),
+        rules,
+    })
+}
+
+pub async fn ensure_eligible(
+    customer_id: &i64,
+    product_id: &i64,
+    credit_score: Option<i32>,
+    state: &web::Data<AppState>,
+) -> Result<EligibilityResultModel, DbErr> {
+    let result = evaluate(customer_id, product_id, credit_score, state).await?;
+
+    if !result.eligible {
+        return Err(DbErr::Custom(format!(
+            "Customer is not eligible: {}",
+            failure_summary(&result)
+        )));
+    }
+
+    Ok(result)
+}
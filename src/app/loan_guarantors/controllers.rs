@@ -0,0 +1,201 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::loan_guarantors::{
+        models::{
+            AcceptGuarantorModel, AcceptGuarantorParams, AddGuarantorModel, AddGuarantorParams,
+            ReleaseGuarantorModel, ReleaseGuarantorParams,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn add_guarantor(
+    req: HttpRequest,
+    payload: web::Json<AddGuarantorParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "LOAN_GUARANTOR_MANAGE", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let loan_application_id = id_parser(&data.loan_application_id, "Loan Application Id").await?;
+
+    let customer_id = match data.customer_id {
+        Some(customer_id) => Some(id_parser(&customer_id, "Customer Id").await?),
+        None => None,
+    };
+
+    let guarantor = AddGuarantorModel {
+        loan_application_id,
+        customer_id,
+        guarantor_name: data.guarantor_name,
+        guarantor_id_number: data.guarantor_id_number,
+        guarantor_phone: data.guarantor_phone,
+        guarantor_email: data.guarantor_email,
+        guarantor_address: data.guarantor_address,
+        guarantor_relationship: data.guarantor_relationship,
+        guarantor_occupation: data.guarantor_occupation,
+        guarantor_income: data.guarantor_income,
+    };
+
+    match services::add_guarantor(&guarantor, &state).await {
+        Ok(guarantor) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Guarantor Added",
+            guarantor,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn request_consent(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "LOAN_GUARANTOR_MANAGE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Guarantor Id").await?;
+
+    match services::request_consent(&id, &state).await {
+        Ok(request) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Consent Request Sent",
+            request,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn accept_guarantee(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<AcceptGuarantorParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "LOAN_GUARANTOR_MANAGE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Guarantor Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let acceptance = AcceptGuarantorModel {
+        agreement_document_url: payload.into_inner().agreement_document_url,
+    };
+
+    match services::accept_guarantee(&id, &acceptance, &state).await {
+        Ok(guarantor) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Guarantee Accepted",
+            guarantor,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn release_guarantor(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<ReleaseGuarantorParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "LOAN_GUARANTOR_RELEASE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Guarantor Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let release = ReleaseGuarantorModel {
+        reason: payload.into_inner().reason,
+    };
+
+    match services::release_guarantor(&id, &release, &state).await {
+        Ok(guarantor) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Guarantor Released",
+            guarantor,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn application_guarantors(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Application Id").await?;
+
+    match services::get_application_guarantors(&id, &state).await {
+        Ok(guarantors) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            guarantors,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn guarantor_exposure(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Customer Id").await?;
+
+    match services::get_guarantor_exposure(&id, &state).await {
+        Ok(exposure) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            exposure,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
@@ -0,0 +1,170 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[derive(Debug, Clone)]
+pub struct AddGuarantorModel {
+    pub loan_application_id: i64,
+    pub customer_id: Option<i64>,
+    pub guarantor_name: Option<String>,
+    pub guarantor_id_number: Option<String>,
+    pub guarantor_phone: Option<String>,
+    pub guarantor_email: Option<String>,
+    pub guarantor_address: Option<String>,
+    pub guarantor_relationship: Option<String>,
+    pub guarantor_occupation: Option<String>,
+    pub guarantor_income: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AcceptGuarantorModel {
+    pub agreement_document_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReleaseGuarantorModel {
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct AddGuarantorParams {
+    #[serde(rename = "loanApplicationId")]
+    pub loan_application_id: String,
+    #[serde(rename = "customerId")]
+    pub customer_id: Option<String>,
+    #[validate(length(min = 2, max = 255, message = "guarantorName cannot be < 2 and > 255"))]
+    #[serde(rename = "guarantorName")]
+    pub guarantor_name: Option<String>,
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "guarantorIdNumber cannot be < 2 and > 100"
+    ))]
+    #[serde(rename = "guarantorIdNumber")]
+    pub guarantor_id_number: Option<String>,
+    #[validate(length(min = 7, max = 20, message = "guarantorPhone cannot be < 7 and > 20"))]
+    #[serde(rename = "guarantorPhone")]
+    pub guarantor_phone: Option<String>,
+    #[validate(email)]
+    #[serde(rename = "guarantorEmail")]
+    pub guarantor_email: Option<String>,
+    #[validate(length(
+        min = 2,
+        max = 500,
+        message = "guarantorAddress cannot be < 2 and > 500"
+    ))]
+    #[serde(rename = "guarantorAddress")]
+    pub guarantor_address: Option<String>,
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "guarantorRelationship cannot be < 2 and > 100"
+    ))]
+    #[serde(rename = "guarantorRelationship")]
+    pub guarantor_relationship: Option<String>,
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "guarantorOccupation cannot be < 2 and > 100"
+    ))]
+    #[serde(rename = "guarantorOccupation")]
+    pub guarantor_occupation: Option<String>,
+    #[validate(range(min = 0, message = "guarantorIncome cannot be negative"))]
+    #[serde(rename = "guarantorIncome")]
+    pub guarantor_income: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct AcceptGuarantorParams {
+    #[validate(url(message = "agreementDocumentUrl must be a valid url"))]
+    #[serde(rename = "agreementDocumentUrl")]
+    pub agreement_document_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ReleaseGuarantorParams {
+    #[validate(length(min = 5, max = 255, message = "reason cannot be < 5 and > 255"))]
+    pub reason: String,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_guarantors::Entity")]
+pub struct GuarantorResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "loan_application_id")]
+    pub loan_application_id: i64,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "loan_id")]
+    pub loan_id: Option<i64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "customer_id")]
+    pub customer_id: Option<i64>,
+    #[sea_orm(from_col = "guarantor_name")]
+    pub guarantor_name: Option<String>,
+    #[sea_orm(from_col = "guarantor_id_number")]
+    pub guarantor_id_number: Option<String>,
+    #[sea_orm(from_col = "guarantor_phone")]
+    pub guarantor_phone: Option<String>,
+    #[sea_orm(from_col = "guarantor_email")]
+    pub guarantor_email: Option<String>,
+    #[sea_orm(from_col = "guarantor_relationship")]
+    pub guarantor_relationship: Option<String>,
+    #[sea_orm(from_col = "guarantor_occupation")]
+    pub guarantor_occupation: Option<String>,
+    #[sea_orm(from_col = "guarantor_income")]
+    pub guarantor_income: Option<i64>,
+    #[sea_orm(from_col = "agreement_document_url")]
+    pub agreement_document_url: Option<String>,
+    #[sea_orm(from_col = "is_consent_provided")]
+    pub is_consent_provided: Option<bool>,
+    #[sea_orm(from_col = "consent_date")]
+    pub consent_date: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "accepted_at")]
+    pub accepted_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "is_active")]
+    pub is_active: Option<bool>,
+    #[sea_orm(from_col = "released_at")]
+    pub released_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "release_reason")]
+    pub release_reason: Option<String>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsentRequestModel {
+    #[serde_as(as = "DisplayFromStr")]
+    pub guarantor_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub notification_id: i64,
+    pub recipient_contact: String,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct GuaranteeExposureModel {
+    #[serde_as(as = "DisplayFromStr")]
+    pub guarantor_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub loan_application_id: i64,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub loan_id: Option<i64>,
+    pub exposure: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GuarantorExposureModel {
+    pub total_exposure: i64,
+    pub guarantees: Vec<GuaranteeExposureModel>,
+}
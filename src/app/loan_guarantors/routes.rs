@@ -0,0 +1,45 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loan_guarantors::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loan-guarantors")
+            .route(
+                "",
+                web::post()
+                    .to(controllers::add_guarantor)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/consent-request",
+                web::post()
+                    .to(controllers::request_consent)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/accept",
+                web::put()
+                    .to(controllers::accept_guarantee)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/release",
+                web::put()
+                    .to(controllers::release_guarantor)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/applications/{id}",
+                web::get()
+                    .to(controllers::application_guarantors)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/customers/{id}/exposure",
+                web::get()
+                    .to(controllers::guarantor_exposure)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
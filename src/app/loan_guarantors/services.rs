@@ -0,0 +1,483 @@
+use actix_web::web;
+use entity::sea_orm_active_enums::{LoanApplicationStatus, NotificationType};
+use migration::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+};
+use serde_json::json;
+
+use crate::{
+    AppState,
+    app::{
+        loan_guarantors::models::{
+            AcceptGuarantorModel, AddGuarantorModel, ConsentRequestModel, GuaranteeExposureModel,
+            GuarantorExposureModel, GuarantorResponseModel, ReleaseGuarantorModel,
+        },
+        loans::services::active_statuses,
+        notifications::{self, models::QueueNotificationModel},
+    },
+    utils::gen_snow_ids::gen_snowflake_slug,
+};
+
+async fn find_application(
+    id: i64,
+    state: &web::Data<AppState>,
+) -> Result<entity::loan_applications::Model, DbErr> {
+    entity::loan_applications::Entity::find_by_id(id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan application not found".into()))
+}
+
+async fn find_guarantor(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<entity::loan_guarantors::Model, DbErr> {
+    entity::loan_guarantors::Entity::find_by_id(*id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Guarantor not found".into()))
+}
+
+async fn get_guarantor(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<GuarantorResponseModel, DbErr> {
+    entity::loan_guarantors::Entity::find_by_id(*id)
+        .into_model::<GuarantorResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Guarantor not found".into()))
+}
+
+fn is_released(guarantor: &entity::loan_guarantors::Model) -> bool {
+    guarantor.released_at.is_some() || guarantor.is_active == Some(false)
+}
+
+pub async fn add_guarantor(
+    model: &AddGuarantorModel,
+    state: &web::Data<AppState>,
+) -> Result<GuarantorResponseModel, DbErr> {
+    let mut data = model.clone();
+
+    let application = find_application(data.loan_application_id, state).await?;
+
+    if !matches!(
+        application.status,
+        Some(LoanApplicationStatus::Draft) | Some(LoanApplicationStatus::Pending)
+    ) {
+        return Err(DbErr::Custom(
+            "Guarantors can only be added to draft or pending applications".to_string(),
+        ));
+    }
+
+    // Customer guarantors inherit contact details from their customer record
+    if let Some(customer_id) = data.customer_id {
+        if customer_id == application.customer_id {
+            return Err(DbErr::Custom(
+                "Borrower cannot guarantee their own application".to_string(),
+            ));
+        }
+
+        let customer = entity::customers::Entity::find_by_id(customer_id)
+            .filter(entity::customers::Column::InstitutionId.eq(application.institution_id))
+            .one(state.pgdb.get_ref())
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound("Customer not found".into()))?;
+
+        if customer.is_black_listed.unwrap_or(false) {
+            return Err(DbErr::Custom(
+                "Blacklisted customers cannot act as guarantors".to_string(),
+            ));
+        }
+
+        let existing = entity::loan_guarantors::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(entity::loan_guarantors::Column::LoanApplicationId.eq(application.id))
+                    .add(entity::loan_guarantors::Column::CustomerId.eq(customer_id))
+                    .add(entity::loan_guarantors::Column::ReleasedAt.is_null()),
+            )
+            .one(state.pgdb.get_ref())
+            .await?;
+
+        if existing.is_some() {
+            return Err(DbErr::Custom(
+                "Customer is already a guarantor on this application".to_string(),
+            ));
+        }
+
+        if data.guarantor_name.is_none() {
+            let name = [
+                customer.first_name.clone(),
+                customer.middle_name.clone(),
+                customer.last_name.clone(),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<String>>()
+            .join(" ");
+
+            data.guarantor_name = Some(name);
+        }
+
+        if data.guarantor_phone.is_none() {
+            data.guarantor_phone = match (customer.phone_country_code, customer.phone_number) {
+                (Some(code), Some(number)) => Some(format!("{}{}", code, number)),
+                (None, Some(number)) => Some(number),
+                _ => None,
+            };
+        }
+
+        if data.guarantor_email.is_none() {
+            data.guarantor_email = customer.email;
+        }
+
+        if data.guarantor_occupation.is_none() {
+            data.guarantor_occupation = customer.occupation;
+        }
+    } else if data.guarantor_name.is_none()
+        || (data.guarantor_phone.is_none() && data.guarantor_email.is_none())
+    {
+        return Err(DbErr::Custom(
+            "External guarantors require a name and a phone number or email".to_string(),
+        ));
+    }
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::loan_guarantors::ActiveModel {
+        id: Set(id),
+        loan_application_id: Set(application.id),
+        customer_id: Set(data.customer_id),
+        guarantor_name: Set(data.guarantor_name),
+        guarantor_id_number: Set(data.guarantor_id_number),
+        guarantor_phone: Set(data.guarantor_phone),
+        guarantor_email: Set(data.guarantor_email),
+        guarantor_address: Set(data.guarantor_address),
+        guarantor_relationship: Set(data.guarantor_relationship),
+        guarantor_occupation: Set(data.guarantor_occupation),
+        guarantor_income: Set(data.guarantor_income),
+        is_consent_provided: Set(Some(false)),
+        is_active: Set(Some(true)),
+        ..Default::default()
+    }
+    .insert(state.pgdb.get_ref())
+    .await?;
+
+    get_guarantor(&id, state).await
+}
+
+pub async fn request_consent(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<ConsentRequestModel, DbErr> {
+    let guarantor = find_guarantor(id, state).await?;
+
+    if is_released(&guarantor) {
+        return Err(DbErr::Custom("Guarantor has been released".to_string()));
+    }
+
+    if guarantor.is_consent_provided.unwrap_or(false) {
+        return Err(DbErr::Custom(
+            "Guarantor has already provided consent".to_string(),
+        ));
+    }
+
+    let application = find_application(guarantor.loan_application_id, state).await?;
+
+    let (recipient_contact, notification_type) = match (
+        guarantor.guarantor_phone.clone(),
+        guarantor.guarantor_email.clone(),
+    ) {
+        (Some(phone), _) => (phone, NotificationType::Sms),
+        (None, Some(email)) => (email, NotificationType::Email),
+        (None, None) => {
+            return Err(DbErr::Custom(
+                "Guarantor has no phone number or email to contact".to_string(),
+            ));
+        }
+    };
+
+    let notification = notifications::services::enqueue(
+        state.pgdb.get_ref(),
+        &QueueNotificationModel {
+            institution_id: application.institution_id,
+            customer_id: guarantor.customer_id,
+            staff_id: None,
+            agent_id: None,
+            recipient_contact: recipient_contact.clone(),
+            notification_type,
+            template_code: "LOAN_GUARANTOR_CONSENT".to_string(),
+            default_subject: Some("Guarantor consent request".to_string()),
+            default_body: "Dear {{guarantor_name}}, you have been named as a guarantor for loan application {{application_number}} of {{requested_principal}}. Please contact us to confirm your consent.".to_string(),
+            variables: json!({
+                "guarantor_id": guarantor.id.to_string(),
+                "guarantor_name": guarantor.guarantor_name.unwrap_or_default(),
+                "application_number": application.application_number.unwrap_or_default(),
+                "requested_principal": application.requested_principal,
+            }),
+        },
+    )
+    .await?;
+
+    Ok(ConsentRequestModel {
+        guarantor_id: guarantor.id,
+        notification_id: notification.id,
+        recipient_contact,
+    })
+}
+
+pub async fn accept_guarantee(
+    id: &i64,
+    model: &AcceptGuarantorModel,
+    state: &web::Data<AppState>,
+) -> Result<GuarantorResponseModel, DbErr> {
+    let data = model.clone();
+
+    let guarantor = find_guarantor(id, state).await?;
+
+    if is_released(&guarantor) {
+        return Err(DbErr::Custom("Guarantor has been released".to_string()));
+    }
+
+    if guarantor.is_consent_provided.unwrap_or(false) {
+        return Err(DbErr::Custom(
+            "Guarantor has already provided consent".to_string(),
+        ));
+    }
+
+    let mut active_guarantor: entity::loan_guarantors::ActiveModel = guarantor.into();
+    active_guarantor.is_consent_provided = Set(Some(true));
+    active_guarantor.consent_date = Set(Some(chrono::Utc::now().into()));
+    active_guarantor.accepted_at = Set(Some(chrono::Utc::now().into()));
+    if data.agreement_document_url.is_some() {
+        active_guarantor.agreement_document_url = Set(data.agreement_document_url);
+    }
+    active_guarantor.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_guarantor.update(state.pgdb.get_ref()).await?;
+
+    get_guarantor(id, state).await
+}
+
+pub async fn release_guarantor(
+    id: &i64,
+    model: &ReleaseGuarantorModel,
+    state: &web::Data<AppState>,
+) -> Result<GuarantorResponseModel, DbErr> {
+    let data = model.clone();
+
+    let guarantor = find_guarantor(id, state).await?;
+
+    if is_released(&guarantor) {
+        return Err(DbErr::Custom("Guarantor is already released".to_string()));
+    }
+
+    let application = find_application(guarantor.loan_application_id, state).await?;
+
+    // A guarantee stands from approval until the loan it backs closes
+    let loan_active = match guarantor.loan_id {
+        Some(loan_id) => entity::loans::Entity::find_by_id(loan_id)
+            .one(state.pgdb.get_ref())
+            .await?
+            .is_some_and(|loan| is_active_loan(&loan)),
+        None => false,
+    };
+
+    let held = match application.status {
+        Some(LoanApplicationStatus::Approved) => true,
+        Some(LoanApplicationStatus::Disbursed) => guarantor.loan_id.is_none() || loan_active,
+        _ => loan_active,
+    };
+
+    if held {
+        return Err(DbErr::Custom(
+            "Guarantor of an approved application or active loan cannot be released".to_string(),
+        ));
+    }
+
+    let mut active_guarantor: entity::loan_guarantors::ActiveModel = guarantor.into();
+    active_guarantor.is_active = Set(Some(false));
+    active_guarantor.released_at = Set(Some(chrono::Utc::now().into()));
+    active_guarantor.release_reason = Set(Some(data.reason));
+    active_guarantor.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_guarantor.update(state.pgdb.get_ref()).await?;
+
+    get_guarantor(id, state).await
+}
+
+pub async fn get_application_guarantors(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<GuarantorResponseModel>, DbErr> {
+    find_application(*id, state).await?;
+
+    entity::loan_guarantors::Entity::find()
+        .filter(entity::loan_guarantors::Column::LoanApplicationId.eq(*id))
+        .order_by_asc(entity::loan_guarantors::Column::CreatedAt)
+        .into_model::<GuarantorResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await
+}
+
+pub async fn consented_guarantor_count<C: ConnectionTrait>(
+    conn: &C,
+    application_id: i64,
+) -> Result<u64, DbErr> {
+    entity::loan_guarantors::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_guarantors::Column::LoanApplicationId.eq(application_id))
+                .add(entity::loan_guarantors::Column::IsConsentProvided.eq(true))
+                .add(entity::loan_guarantors::Column::IsActive.eq(true))
+                .add(entity::loan_guarantors::Column::ReleasedAt.is_null()),
+        )
+        .count(conn)
+        .await
+}
+
+fn is_active_loan(loan: &entity::loans::Model) -> bool {
+    loan.status
+        .as_ref()
+        .is_some_and(|status| active_statuses().contains(status))
+}
+
+// Links the application's standing guarantees to the loan they back
+pub async fn link_to_loan<C: ConnectionTrait>(
+    conn: &C,
+    application_id: i64,
+    loan_id: i64,
+) -> Result<u64, DbErr> {
+    let result = entity::loan_guarantors::Entity::update_many()
+        .filter(
+            Condition::all()
+                .add(entity::loan_guarantors::Column::LoanApplicationId.eq(application_id))
+                .add(entity::loan_guarantors::Column::ReleasedAt.is_null()),
+        )
+        .col_expr(
+            entity::loan_guarantors::Column::LoanId,
+            Expr::value(loan_id),
+        )
+        .col_expr(
+            entity::loan_guarantors::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
+
+// Guarantees securing a refinanced loan carry over to the loan that replaced it,
+// including any captured before the old loan was linked
+pub async fn transfer_to_loan<C: ConnectionTrait>(
+    conn: &C,
+    from_loan: &entity::loans::Model,
+    loan_id: i64,
+) -> Result<u64, DbErr> {
+    let result = entity::loan_guarantors::Entity::update_many()
+        .filter(
+            Condition::all()
+                .add(
+                    Condition::any()
+                        .add(entity::loan_guarantors::Column::LoanId.eq(from_loan.id))
+                        .add(
+                            Condition::all()
+                                .add(entity::loan_guarantors::Column::LoanId.is_null())
+                                .add(
+                                    entity::loan_guarantors::Column::LoanApplicationId
+                                        .eq(from_loan.loan_application_id),
+                                ),
+                        ),
+                )
+                .add(entity::loan_guarantors::Column::ReleasedAt.is_null()),
+        )
+        .col_expr(
+            entity::loan_guarantors::Column::LoanId,
+            Expr::value(loan_id),
+        )
+        .col_expr(
+            entity::loan_guarantors::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
+
+// Disbursed guarantees count at the loan's outstanding balance, approved
+// applications awaiting disbursement at their approved principal
+pub async fn get_guarantor_exposure(
+    customer_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<GuarantorExposureModel, DbErr> {
+    let guarantees = entity::loan_guarantors::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_guarantors::Column::CustomerId.eq(*customer_id))
+                .add(entity::loan_guarantors::Column::IsConsentProvided.eq(true))
+                .add(entity::loan_guarantors::Column::IsActive.eq(true))
+                .add(entity::loan_guarantors::Column::ReleasedAt.is_null()),
+        )
+        .find_also_related(entity::loan_applications::Entity)
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let mut exposures = Vec::new();
+
+    for (guarantee, application) in guarantees {
+        let status = application
+            .as_ref()
+            .and_then(|application| application.status.clone());
+
+        // Guarantees given before loans were linked are found through the
+        // application the loan was disbursed from
+        let loan = match (guarantee.loan_id, &status) {
+            (Some(loan_id), _) => {
+                entity::loans::Entity::find_by_id(loan_id)
+                    .one(state.pgdb.get_ref())
+                    .await?
+            }
+            (None, Some(LoanApplicationStatus::Disbursed)) => {
+                entity::loans::Entity::find()
+                    .filter(
+                        entity::loans::Column::LoanApplicationId.eq(guarantee.loan_application_id),
+                    )
+                    .one(state.pgdb.get_ref())
+                    .await?
+            }
+            _ => None,
+        };
+
+        let exposure = match (loan, status) {
+            (Some(loan), _) if is_active_loan(&loan) => {
+                loan.outstanding_principal
+                    + loan.outstanding_interest.unwrap_or(0)
+                    + loan.outstanding_penalty.unwrap_or(0)
+            }
+            (None, Some(LoanApplicationStatus::Approved)) => application
+                .and_then(|application| application.approved_principal)
+                .unwrap_or(0),
+            _ => 0,
+        };
+
+        if exposure > 0 {
+            exposures.push(GuaranteeExposureModel {
+                guarantor_id: guarantee.id,
+                loan_application_id: guarantee.loan_application_id,
+                loan_id: guarantee.loan_id,
+                exposure,
+            });
+        }
+    }
+
+    Ok(GuarantorExposureModel {
+        total_exposure: exposures.iter().map(|exposure| exposure.exposure).sum(),
+        guarantees: exposures,
+    })
+}
@@ -0,0 +1,108 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::loan_provisioning::{
+        models::{SaveRuleModel, SaveRuleParams},
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn save_rule(
+    req: HttpRequest,
+    payload: web::Json<SaveRuleParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_PROVISIONING_CONFIGURE", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let institution_id = id_parser(&data.institution_id, "Institution Id").await?;
+
+    let expense_account_id = match data.provision_expense_gl_account_id {
+        Some(id) => Some(id_parser(&id, "Provision Expense GL Account Id").await?),
+        None => None,
+    };
+
+    let reserve_account_id = match data.provision_reserve_gl_account_id {
+        Some(id) => Some(id_parser(&id, "Provision Reserve GL Account Id").await?),
+        None => None,
+    };
+
+    let rule = SaveRuleModel {
+        institution_id,
+        classification: data.classification,
+        min_days_in_arrears: data.min_days_in_arrears,
+        provision_rate: data.provision_rate,
+        is_npa: data.is_npa,
+        provision_expense_gl_account_id: expense_account_id,
+        provision_reserve_gl_account_id: reserve_account_id,
+        created_by: staff.id,
+    };
+
+    match services::save_rule(&rule, &state).await {
+        Ok(rule) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            rule,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn get_rules(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Institution Id").await?;
+
+    match services::get_rules(&id, &state).await {
+        Ok(rules) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            rules,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn get_history(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Id").await?;
+
+    match services::get_history(&id, &state).await {
+        Ok(history) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            history,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
@@ -0,0 +1,98 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use entity::sea_orm_active_enums::LoanClassification;
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+use crate::utils::validators::validate_percentage;
+
+#[derive(Debug, Clone)]
+pub struct SaveRuleModel {
+    pub institution_id: i64,
+    pub classification: LoanClassification,
+    pub min_days_in_arrears: i32,
+    pub provision_rate: Decimal,
+    pub is_npa: bool,
+    pub provision_expense_gl_account_id: Option<i64>,
+    pub provision_reserve_gl_account_id: Option<i64>,
+    pub created_by: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SaveRuleParams {
+    #[serde(rename = "institutionId")]
+    pub institution_id: String,
+    pub classification: LoanClassification,
+    #[validate(range(min = 0, message = "minDaysInArrears cannot be < 0"))]
+    #[serde(rename = "minDaysInArrears")]
+    pub min_days_in_arrears: i32,
+    #[validate(custom(function = "validate_percentage"))]
+    #[serde(rename = "provisionRate")]
+    pub provision_rate: Decimal,
+    #[serde(rename = "isNpa")]
+    pub is_npa: bool,
+    #[serde(rename = "provisionExpenseGlAccountId")]
+    pub provision_expense_gl_account_id: Option<String>,
+    #[serde(rename = "provisionReserveGlAccountId")]
+    pub provision_reserve_gl_account_id: Option<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_classification_rules::Entity")]
+pub struct RuleResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "institution_id")]
+    pub institution_id: i64,
+    #[sea_orm(from_col = "classification")]
+    pub classification: LoanClassification,
+    #[sea_orm(from_col = "min_days_in_arrears")]
+    pub min_days_in_arrears: i32,
+    #[sea_orm(from_col = "provision_rate")]
+    pub provision_rate: Decimal,
+    #[sea_orm(from_col = "is_npa")]
+    pub is_npa: bool,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "provision_expense_gl_account_id")]
+    pub provision_expense_gl_account_id: Option<i64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "provision_reserve_gl_account_id")]
+    pub provision_reserve_gl_account_id: Option<i64>,
+    #[sea_orm(from_col = "is_active")]
+    pub is_active: Option<bool>,
+    #[sea_orm(from_col = "updated_at")]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_provisioning::Entity")]
+pub struct ProvisionResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "loan_id")]
+    pub loan_id: i64,
+    #[sea_orm(from_col = "provision_date")]
+    pub provision_date: Option<NaiveDate>,
+    #[sea_orm(from_col = "provision_rate")]
+    pub provision_rate: Option<Decimal>,
+    #[sea_orm(from_col = "provision_amount")]
+    pub provision_amount: Option<i64>,
+    #[sea_orm(from_col = "outstanding_balance")]
+    pub outstanding_balance: Option<i64>,
+    #[sea_orm(from_col = "days_in_arrears")]
+    pub days_in_arrears: Option<i32>,
+    #[sea_orm(from_col = "calculation_method")]
+    pub classification: Option<String>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
@@ -0,0 +1,27 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loan_provisioning::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loan-provisioning")
+            .route(
+                "/rules",
+                web::post()
+                    .to(controllers::save_rule)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/rules/{id}",
+                web::get()
+                    .to(controllers::get_rules)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/loans/{id}",
+                web::get()
+                    .to(controllers::get_history)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
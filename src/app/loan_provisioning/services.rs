@@ -0,0 +1,352 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_web::web;
+use entity::sea_orm_active_enums::{AccrualReferenceType, AccrualStatus, AccrualType};
+use sea_orm::{
+    ActiveEnum, ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, TransactionTrait,
+};
+
+use crate::{
+    AppState,
+    app::{
+        ledger::{self, models::GlEntryModel},
+        loan_provisioning::models::{ProvisionResponseModel, RuleResponseModel, SaveRuleModel},
+        loans::services::{active_statuses, unpaid_schedule_statuses},
+    },
+    utils::{finance::percent_of, gen_snow_ids::gen_snowflake_slug},
+};
+
+pub async fn save_rule(
+    model: &SaveRuleModel,
+    state: &web::Data<AppState>,
+) -> Result<RuleResponseModel, DbErr> {
+    let data = model.clone();
+
+    let existing = entity::loan_classification_rules::Entity::find()
+        .filter(
+            Condition::all()
+                .add(
+                    entity::loan_classification_rules::Column::InstitutionId
+                        .eq(data.institution_id),
+                )
+                .add(
+                    entity::loan_classification_rules::Column::Classification
+                        .eq(data.classification.clone()),
+                ),
+        )
+        .one(state.pgdb.get_ref())
+        .await?;
+
+    let id = match existing {
+        Some(rule) => {
+            let id = rule.id;
+            let mut active_rule: entity::loan_classification_rules::ActiveModel = rule.into();
+
+            active_rule.min_days_in_arrears = Set(data.min_days_in_arrears);
+            active_rule.provision_rate = Set(data.provision_rate);
+            active_rule.is_npa = Set(data.is_npa);
+            active_rule.provision_expense_gl_account_id = Set(data.provision_expense_gl_account_id);
+            active_rule.provision_reserve_gl_account_id = Set(data.provision_reserve_gl_account_id);
+            active_rule.is_active = Set(Some(true));
+            active_rule.updated_at = Set(Some(chrono::Utc::now().into()));
+
+            ActiveModelTrait::update(active_rule, state.pgdb.get_ref()).await?;
+
+            id
+        }
+        None => {
+            let (id, _) = match gen_snowflake_slug() {
+                Ok(res) => res,
+                Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+            };
+
+            entity::loan_classification_rules::ActiveModel {
+                id: Set(id),
+                institution_id: Set(data.institution_id),
+                classification: Set(data.classification),
+                min_days_in_arrears: Set(data.min_days_in_arrears),
+                provision_rate: Set(data.provision_rate),
+                is_npa: Set(data.is_npa),
+                provision_expense_gl_account_id: Set(data.provision_expense_gl_account_id),
+                provision_reserve_gl_account_id: Set(data.provision_reserve_gl_account_id),
+                is_active: Set(Some(true)),
+                created_by: Set(Some(data.created_by)),
+                ..Default::default()
+            }
+            .insert(state.pgdb.get_ref())
+            .await?;
+
+            id
+        }
+    };
+
+    entity::loan_classification_rules::Entity::find_by_id(id)
+        .into_model::<RuleResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Rule not found".into()))
+}
+
+pub async fn get_rules(
+    institution_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<RuleResponseModel>, DbErr> {
+    let rules = entity::loan_classification_rules::Entity::find()
+        .filter(entity::loan_classification_rules::Column::InstitutionId.eq(*institution_id))
+        .order_by_asc(entity::loan_classification_rules::Column::MinDaysInArrears)
+        .into_model::<RuleResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(rules)
+}
+
+pub async fn get_history(
+    loan_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<ProvisionResponseModel>, DbErr> {
+    let history = entity::loan_provisioning::Entity::find()
+        .filter(entity::loan_provisioning::Column::LoanId.eq(*loan_id))
+        .order_by_desc(entity::loan_provisioning::Column::ProvisionDate)
+        .into_model::<ProvisionResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(history)
+}
+
+pub async fn age_loans(state: &web::Data<AppState>) -> Result<u64, DbErr> {
+    let today = chrono::Utc::now().date_naive();
+
+    let loans = entity::loans::Entity::find()
+        .filter(entity::loans::Column::Status.is_in(active_statuses()))
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    if loans.is_empty() {
+        return Ok(0);
+    }
+
+    // Highest threshold first so the first match is the loan's bucket
+    let mut rules: HashMap<i64, Vec<entity::loan_classification_rules::Model>> = HashMap::new();
+
+    for rule in entity::loan_classification_rules::Entity::find()
+        .filter(entity::loan_classification_rules::Column::IsActive.eq(true))
+        .order_by_desc(entity::loan_classification_rules::Column::MinDaysInArrears)
+        .all(state.pgdb.get_ref())
+        .await?
+    {
+        rules.entry(rule.institution_id).or_default().push(rule);
+    }
+
+    let loan_ids: Vec<i64> = loans.iter().map(|loan| loan.id).collect();
+
+    // Oldest unpaid due date and total unpaid amount per loan
+    let mut arrears: HashMap<i64, (chrono::NaiveDate, i64)> = HashMap::new();
+
+    for installment in entity::loan_repayment_schedules::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_repayment_schedules::Column::LoanId.is_in(loan_ids.clone()))
+                .add(
+                    entity::loan_repayment_schedules::Column::Status
+                        .is_in(unpaid_schedule_statuses()),
+                )
+                .add(entity::loan_repayment_schedules::Column::DueDate.lt(today)),
+        )
+        .all(state.pgdb.get_ref())
+        .await?
+    {
+        let unpaid = installment.total_due
+            - installment.principal_paid.unwrap_or(0)
+            - installment.interest_paid.unwrap_or(0);
+
+        if unpaid <= 0 {
+            continue;
+        }
+
+        let entry = arrears
+            .entry(installment.loan_id)
+            .or_insert((installment.due_date, 0));
+
+        entry.0 = entry.0.min(installment.due_date);
+        entry.1 += unpaid;
+    }
+
+    let provisioned: HashSet<i64> = entity::loan_provisioning::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_provisioning::Column::LoanId.is_in(loan_ids))
+                .add(entity::loan_provisioning::Column::ProvisionDate.eq(today)),
+        )
+        .all(state.pgdb.get_ref())
+        .await?
+        .into_iter()
+        .map(|row| row.loan_id)
+        .collect();
+
+    let mut unconfigured: HashSet<i64> = HashSet::new();
+    let mut aged = 0;
+
+    for loan in loans {
+        if provisioned.contains(&loan.id) {
+            continue;
+        }
+
+        let Some(institution_rules) = rules.get(&loan.institution_id) else {
+            if unconfigured.insert(loan.institution_id) {
+                log::warn!(
+                    "No loan classification rules for institution {}, skipping ageing",
+                    loan.institution_id
+                );
+            }
+            continue;
+        };
+
+        let (days_in_arrears, arrears_amount) = match arrears.get(&loan.id) {
+            Some((oldest, amount)) => ((today - *oldest).num_days() as i32, *amount),
+            None => (0, 0),
+        };
+
+        let Some(rule) = institution_rules
+            .iter()
+            .find(|rule| rule.min_days_in_arrears <= days_in_arrears)
+        else {
+            continue;
+        };
+
+        let provision = percent_of(loan.outstanding_principal, rule.provision_rate);
+        let movement = provision - loan.provision_amount.unwrap_or(0);
+
+        // The subledger only moves together with its GL posting
+        let accounts = match (
+            rule.provision_expense_gl_account_id,
+            rule.provision_reserve_gl_account_id,
+        ) {
+            (Some(expense), Some(reserve)) => Some((expense, reserve)),
+            _ if movement != 0 => {
+                log::warn!(
+                    "Skipping ageing of loan {}: provision GL accounts are not configured for {}",
+                    loan.loan_account_number,
+                    rule.classification.to_value()
+                );
+                continue;
+            }
+            _ => None,
+        };
+
+        let npa_date = match (rule.is_npa, loan.is_npa.unwrap_or(false)) {
+            (true, true) => loan.npa_classification_date,
+            (true, false) => Some(today),
+            (false, _) => None,
+        };
+
+        let recovered = !rule.is_npa && loan.is_npa.unwrap_or(false);
+        let loan_id = loan.id;
+        let institution_id = loan.institution_id;
+        let loan_product_id = loan.loan_product_id;
+
+        let (id, _) = match gen_snowflake_slug() {
+            Ok(res) => res,
+            Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+        };
+
+        let txn = state.pgdb.begin().await?;
+
+        entity::loan_provisioning::ActiveModel {
+            id: Set(id),
+            institution_id: Set(loan.institution_id),
+            loan_id: Set(loan.id),
+            provision_rate: Set(Some(rule.provision_rate)),
+            provision_date: Set(Some(today)),
+            provision_amount: Set(Some(provision)),
+            outstanding_balance: Set(Some(loan.outstanding_principal)),
+            days_in_arrears: Set(Some(days_in_arrears)),
+            calculation_method: Set(Some(rule.classification.to_value())),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        if movement != 0
+            && let Some((expense, reserve)) = accounts
+        {
+            // A higher provision is charged to expense, a lower one released back
+            let (debit, credit) = if movement > 0 {
+                (expense, reserve)
+            } else {
+                (reserve, expense)
+            };
+
+            ledger::services::post(
+                &txn,
+                &GlEntryModel {
+                    institution_id: loan.institution_id,
+                    debit_account_id: debit,
+                    credit_account_id: credit,
+                    amount: movement.abs(),
+                    narration: format!(
+                        "Loan loss provision ({}) for {}",
+                        rule.classification.to_value(),
+                        loan.loan_account_number
+                    ),
+                    reference_number: Some(format!("PRV-{}-{}", loan.loan_account_number, today)),
+                    transaction_id: None,
+                    value_date: today,
+                    posted_by: None,
+                },
+            )
+            .await?;
+        }
+
+        let mut active_loan: entity::loans::ActiveModel = loan.into();
+
+        active_loan.days_in_arrears = Set(Some(days_in_arrears));
+        active_loan.arrears_amount = Set(Some(arrears_amount));
+        active_loan.is_npa = Set(Some(rule.is_npa));
+        active_loan.npa_classification_date = Set(npa_date);
+        active_loan.classification = Set(Some(rule.classification.clone()));
+        active_loan.provision_rate = Set(Some(rule.provision_rate));
+        active_loan.provision_amount = Set(Some(provision));
+        active_loan.updated_at = Set(Some(chrono::Utc::now().into()));
+
+        ActiveModelTrait::update(active_loan, &txn).await?;
+
+        // Interest suspended while the loan was NPA is not recognised on
+        // recovery; a nil accrual marks where accrual restarts from
+        if recovered
+            && let Some(income_gl) = entity::loan_products::Entity::find_by_id(loan_product_id)
+                .one(&txn)
+                .await?
+                .and_then(|product| product.interest_gl_account_id)
+        {
+            let (boundary_id, _) = match gen_snowflake_slug() {
+                Ok(res) => res,
+                Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+            };
+
+            entity::accruals_and_provisions::ActiveModel {
+                id: Set(boundary_id),
+                institution_id: Set(institution_id),
+                accrual_type: Set(AccrualType::InterestAccrual),
+                account_id: Set(income_gl),
+                accrual_date: Set(today),
+                accrual_amount: Set(0),
+                reversal_amount: Set(Some(0)),
+                reference_type: Set(Some(AccrualReferenceType::Loan)),
+                reference_id: Set(Some(loan_id)),
+                status: Set(Some(AccrualStatus::Accrued)),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await?;
+        }
+
+        txn.commit().await?;
+
+        aged += 1;
+    }
+
+    Ok(aged)
+}
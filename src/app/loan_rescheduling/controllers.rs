@@ -0,0 +1,169 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::loan_rescheduling::{
+        models::{
+            ProposeReschedulingModel, ProposeReschedulingParams, ReviewReschedulingModel,
+            ReviewReschedulingParams,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn propose_rescheduling(
+    req: HttpRequest,
+    payload: web::Json<ProposeReschedulingParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_RESCHEDULE_REQUEST", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let loan_id = id_parser(&data.loan_id, "Loan Id").await?;
+
+    let proposal = ProposeReschedulingModel {
+        loan_id,
+        new_tenure_days: data.new_tenure_days,
+        new_installment_amount: data.new_installment_amount,
+        reason: data.reason,
+        supporting_documents: data.supporting_documents,
+        requested_by: staff.id,
+    };
+
+    match services::propose(&proposal, &state).await {
+        Ok(preview) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Successful",
+            preview,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn preview_rescheduling(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Id").await?;
+
+    match services::preview(&id, &state).await {
+        Ok(preview) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            preview,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn approve_rescheduling(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<ReviewReschedulingParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_RESCHEDULE_APPROVE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let review = ReviewReschedulingModel {
+        notes: payload.into_inner().notes,
+        reviewed_by: staff.id,
+    };
+
+    match services::approve(&id, &review, &state).await {
+        Ok(preview) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Loan Rescheduled",
+            preview,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn reject_rescheduling(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<ReviewReschedulingParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_RESCHEDULE_APPROVE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let review = ReviewReschedulingModel {
+        notes: payload.into_inner().notes,
+        reviewed_by: staff.id,
+    };
+
+    match services::reject(&id, &review, &state).await {
+        Ok(rescheduling) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            rescheduling,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn loan_reschedulings(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Id").await?;
+
+    match services::get_loan_requests(&id, &state).await {
+        Ok(requests) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            requests,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
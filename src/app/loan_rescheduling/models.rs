@@ -0,0 +1,96 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use entity::sea_orm_active_enums::LoanReschedulingStatus;
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+use crate::utils::finance::ScheduledInstallment;
+
+#[derive(Debug, Clone)]
+pub struct ProposeReschedulingModel {
+    pub loan_id: i64,
+    pub new_tenure_days: Option<i32>,
+    pub new_installment_amount: Option<i64>,
+    pub reason: String,
+    pub supporting_documents: Option<Value>,
+    pub requested_by: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReviewReschedulingModel {
+    pub notes: Option<String>,
+    pub reviewed_by: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ProposeReschedulingParams {
+    #[serde(rename = "loanId")]
+    pub loan_id: String,
+    #[validate(range(min = 1, message = "newTenureDays must be greater than 0"))]
+    #[serde(rename = "newTenureDays")]
+    pub new_tenure_days: Option<i32>,
+    #[validate(range(min = 1, message = "newInstallmentAmount must be greater than 0"))]
+    #[serde(rename = "newInstallmentAmount")]
+    pub new_installment_amount: Option<i64>,
+    #[validate(length(min = 5, max = 255, message = "reason cannot be < 5 and > 255"))]
+    pub reason: String,
+    #[serde(rename = "supportingDocuments")]
+    pub supporting_documents: Option<Value>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ReviewReschedulingParams {
+    #[validate(length(min = 2, max = 500, message = "notes cannot be < 2 and > 500"))]
+    pub notes: Option<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_rescheduling::Entity")]
+pub struct ReschedulingResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "loan_id")]
+    pub loan_id: i64,
+    #[sea_orm(from_col = "old_maturity_date")]
+    pub old_maturity_date: NaiveDate,
+    #[sea_orm(from_col = "newd_maturity_date")]
+    pub new_maturity_date: NaiveDate,
+    #[sea_orm(from_col = "old_installment_amount")]
+    pub old_installment_amount: Option<i64>,
+    #[sea_orm(from_col = "new_installment_amount")]
+    pub new_installment_amount: Option<i64>,
+    #[sea_orm(from_col = "new_tenure_days")]
+    pub new_tenure_days: Option<i32>,
+    #[sea_orm(from_col = "reason")]
+    pub reason: Option<String>,
+    #[sea_orm(from_col = "supporting_documents")]
+    pub supporting_documents: Option<Value>,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<LoanReschedulingStatus>,
+    #[sea_orm(from_col = "review_notes")]
+    pub review_notes: Option<String>,
+    #[sea_orm(from_col = "requested_at")]
+    pub requested_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "approved_at")]
+    pub approved_at: Option<DateTime<FixedOffset>>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "requested_by")]
+    pub requested_by: Option<i64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "approved_by")]
+    pub approved_by: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReschedulingPreviewModel {
+    pub rescheduling: ReschedulingResponseModel,
+    pub schedule: Vec<ScheduledInstallment>,
+}
@@ -0,0 +1,39 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loan_rescheduling::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loan-rescheduling")
+            .route(
+                "",
+                web::post()
+                    .to(controllers::propose_rescheduling)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/preview",
+                web::get()
+                    .to(controllers::preview_rescheduling)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/approve",
+                web::put()
+                    .to(controllers::approve_rescheduling)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/reject",
+                web::put()
+                    .to(controllers::reject_rescheduling)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/loans/{id}",
+                web::get()
+                    .to(controllers::loan_reschedulings)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
@@ -0,0 +1,481 @@
+use actix_web::web;
+use chrono::NaiveDate;
+use entity::sea_orm_active_enums::{
+    LoanApplicationStatus, LoanProductCalcMethod, LoanRepaymentFreq, LoanRepaymentScheduleStatus,
+    LoanReschedulingStatus,
+};
+use migration::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+
+use crate::{
+    AppState,
+    app::{
+        loan_rescheduling::models::{
+            ProposeReschedulingModel, ReschedulingPreviewModel, ReschedulingResponseModel,
+            ReviewReschedulingModel,
+        },
+        loans::services::{active_statuses, unpaid_schedule_statuses},
+    },
+    utils::{
+        finance::{
+            ScheduledInstallment, installment_count, period_days, repayment_schedule, split_evenly,
+        },
+        gen_snow_ids::gen_snowflake_slug,
+    },
+};
+
+struct LoanPosition {
+    loan: entity::loans::Model,
+    product: entity::loan_products::Model,
+    installments: Vec<entity::loan_repayment_schedules::Model>,
+}
+
+impl LoanPosition {
+    fn freq(&self) -> LoanRepaymentFreq {
+        self.loan
+            .repayment_freq
+            .clone()
+            .unwrap_or(LoanRepaymentFreq::Monthly)
+    }
+
+    fn unpaid(&self) -> impl Iterator<Item = &entity::loan_repayment_schedules::Model> {
+        let statuses = unpaid_schedule_statuses();
+
+        self.installments
+            .iter()
+            .filter(move |row| row.status.as_ref().is_some_and(|s| statuses.contains(s)))
+    }
+
+    // Replaces the unpaid rows: outstanding principal is re-amortised from
+    // today and interest already in arrears is carried into the new rows
+    fn regenerate(&self, tenure_days: i32, today: NaiveDate) -> Vec<ScheduledInstallment> {
+        let carried: i64 = self
+            .unpaid()
+            .filter(|row| row.due_date < today)
+            .map(|row| (row.interest_due - row.interest_paid.unwrap_or(0)).max(0))
+            .sum();
+
+        let offset = self
+            .installments
+            .iter()
+            .map(|row| row.installment_number)
+            .max()
+            .unwrap_or(0);
+
+        let mut schedule = repayment_schedule(
+            self.loan.outstanding_principal,
+            self.loan.interest_rate,
+            &self
+                .product
+                .interest_calc_method
+                .clone()
+                .unwrap_or(LoanProductCalcMethod::Flat),
+            &self.freq(),
+            today,
+            tenure_days,
+        );
+
+        let shares = split_evenly(carried, schedule.len() as i32);
+
+        for (installment, share) in schedule.iter_mut().zip(shares) {
+            installment.installment_number += offset;
+            installment.interest_due += share;
+            installment.total_due += share;
+        }
+
+        schedule
+    }
+
+    // Shortest tenure whose instalments fit within the requested amount
+    fn tenure_for_installment(&self, amount: i64, today: NaiveDate) -> Result<i32, DbErr> {
+        let freq = self.freq();
+
+        if freq == LoanRepaymentFreq::Bullet {
+            return Err(DbErr::Custom(
+                "Instalment targeting is not supported for bullet loans".to_string(),
+            ));
+        }
+
+        let period = period_days(&freq, 0);
+        let max_count = installment_count(&freq, today, self.product.maximum_tenure_days);
+
+        (1..=max_count)
+            .map(|count| count * period)
+            .find(|tenure| {
+                self.regenerate(*tenure, today)
+                    .iter()
+                    .all(|installment| installment.total_due <= amount)
+            })
+            .ok_or_else(|| {
+                DbErr::Custom(
+                    "No tenure within the product limit brings the instalment to that amount"
+                        .to_string(),
+                )
+            })
+    }
+}
+
+// Inside a transaction the loan stays locked until commit
+async fn load_position<C: ConnectionTrait>(conn: &C, loan_id: i64) -> Result<LoanPosition, DbErr> {
+    let loan = entity::loans::Entity::find_by_id(loan_id)
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan not found".into()))?;
+
+    let product = entity::loan_products::Entity::find_by_id(loan.loan_product_id)
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan product not found".into()))?;
+
+    let installments = entity::loan_repayment_schedules::Entity::find()
+        .filter(entity::loan_repayment_schedules::Column::LoanId.eq(loan_id))
+        .order_by_asc(entity::loan_repayment_schedules::Column::InstallmentNumber)
+        .all(conn)
+        .await?;
+
+    Ok(LoanPosition {
+        loan,
+        product,
+        installments,
+    })
+}
+
+async fn find_request<C: ConnectionTrait>(
+    conn: &C,
+    id: &i64,
+) -> Result<entity::loan_rescheduling::Model, DbErr> {
+    entity::loan_rescheduling::Entity::find_by_id(*id)
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Rescheduling request not found".into()))
+}
+
+async fn get_details(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<ReschedulingResponseModel, DbErr> {
+    entity::loan_rescheduling::Entity::find_by_id(*id)
+        .into_model::<ReschedulingResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Rescheduling request not found".into()))
+}
+
+pub async fn propose(
+    model: &ProposeReschedulingModel,
+    state: &web::Data<AppState>,
+) -> Result<ReschedulingPreviewModel, DbErr> {
+    let data = model.clone();
+    let today = chrono::Utc::now().date_naive();
+
+    let position = load_position(state.pgdb.get_ref(), data.loan_id).await?;
+
+    if !position
+        .loan
+        .status
+        .as_ref()
+        .is_some_and(|status| active_statuses().contains(status))
+    {
+        return Err(DbErr::Custom(
+            "Only active loans can be rescheduled".to_string(),
+        ));
+    }
+
+    if position.loan.outstanding_principal <= 0 {
+        return Err(DbErr::Custom(
+            "Loan has no outstanding principal".to_string(),
+        ));
+    }
+
+    let pending = entity::loan_rescheduling::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_rescheduling::Column::LoanId.eq(data.loan_id))
+                .add(entity::loan_rescheduling::Column::Status.eq(LoanReschedulingStatus::Pending)),
+        )
+        .one(state.pgdb.get_ref())
+        .await?;
+
+    if pending.is_some() {
+        return Err(DbErr::Custom(
+            "Loan already has a pending rescheduling request".to_string(),
+        ));
+    }
+
+    let tenure_days = match (data.new_tenure_days, data.new_installment_amount) {
+        (Some(tenure), _) => tenure,
+        (None, Some(amount)) => position.tenure_for_installment(amount, today)?,
+        (None, None) => {
+            return Err(DbErr::Custom(
+                "Provide a new tenure or a new instalment amount".to_string(),
+            ));
+        }
+    };
+
+    if tenure_days > position.product.maximum_tenure_days {
+        return Err(DbErr::Custom(format!(
+            "Tenure cannot exceed {} days",
+            position.product.maximum_tenure_days
+        )));
+    }
+
+    let schedule = position.regenerate(tenure_days, today);
+
+    let Some(last) = schedule.last() else {
+        return Err(DbErr::Custom("Failed to build schedule".to_string()));
+    };
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::loan_rescheduling::ActiveModel {
+        id: Set(id),
+        loan_id: Set(data.loan_id),
+        old_maturity_date: Set(position.loan.maturity_date),
+        newd_maturity_date: Set(last.due_date),
+        old_installment_amount: Set(position.unpaid().next().map(|row| row.total_due)),
+        new_installment_amount: Set(schedule.first().map(|row| row.total_due)),
+        new_tenure_days: Set(Some(tenure_days)),
+        reason: Set(Some(data.reason)),
+        supporting_documents: Set(data.supporting_documents),
+        status: Set(Some(LoanReschedulingStatus::Pending)),
+        requested_at: Set(Some(chrono::Utc::now().into())),
+        requested_by: Set(Some(data.requested_by)),
+        ..Default::default()
+    }
+    .insert(state.pgdb.get_ref())
+    .await?;
+
+    Ok(ReschedulingPreviewModel {
+        rescheduling: get_details(&id, state).await?,
+        schedule,
+    })
+}
+
+pub async fn preview(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<ReschedulingPreviewModel, DbErr> {
+    let request = find_request(state.pgdb.get_ref(), id).await?;
+
+    let position = load_position(state.pgdb.get_ref(), request.loan_id).await?;
+
+    // Completed requests show the open rows they produced rather than a fresh projection
+    let schedule = match request.status {
+        Some(LoanReschedulingStatus::Pending) => position.regenerate(
+            request.new_tenure_days.unwrap_or(0),
+            chrono::Utc::now().date_naive(),
+        ),
+        Some(LoanReschedulingStatus::Completed) => position
+            .unpaid()
+            .map(|row| ScheduledInstallment {
+                installment_number: row.installment_number,
+                due_date: row.due_date,
+                principal_due: row.principal_due,
+                interest_due: row.interest_due,
+                total_due: row.total_due,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    Ok(ReschedulingPreviewModel {
+        rescheduling: get_details(id, state).await?,
+        schedule,
+    })
+}
+
+pub async fn approve(
+    id: &i64,
+    model: &ReviewReschedulingModel,
+    state: &web::Data<AppState>,
+) -> Result<ReschedulingPreviewModel, DbErr> {
+    let data = model.clone();
+    let today = chrono::Utc::now().date_naive();
+
+    let txn = state.pgdb.begin().await?;
+
+    // The request and loan stay locked until commit so a second approval
+    // waits and then finds the request completed
+    let request = find_request(&txn, id).await?;
+
+    if request.status != Some(LoanReschedulingStatus::Pending) {
+        return Err(DbErr::Custom(
+            "Rescheduling request is not pending".to_string(),
+        ));
+    }
+
+    if request.requested_by == Some(data.reviewed_by) {
+        return Err(DbErr::Custom(
+            "Rescheduling must be approved by a different staff member".to_string(),
+        ));
+    }
+
+    let position = load_position(&txn, request.loan_id).await?;
+
+    if !position
+        .loan
+        .status
+        .as_ref()
+        .is_some_and(|status| active_statuses().contains(status))
+    {
+        return Err(DbErr::Custom("Loan is no longer active".to_string()));
+    }
+
+    let tenure_days = request.new_tenure_days.unwrap_or(0);
+    let schedule = position.regenerate(tenure_days, today);
+
+    let Some(last) = schedule.last().cloned() else {
+        return Err(DbErr::Custom("Failed to build schedule".to_string()));
+    };
+
+    // Open rows with payments against them are kept as history; the rest
+    // are replaced by the new schedule
+    let (partial, untouched): (Vec<_>, Vec<_>) = position
+        .unpaid()
+        .partition(|row| row.principal_paid.unwrap_or(0) + row.interest_paid.unwrap_or(0) > 0);
+
+    let superseded: Vec<i64> = untouched.iter().map(|row| row.id).collect();
+
+    // A partially paid row is closed at what was paid; its unpaid principal
+    // is re-amortised and its interest in arrears carried into the new rows
+    for row in partial {
+        let principal_paid = row.principal_paid.unwrap_or(0);
+        let interest_paid = row.interest_paid.unwrap_or(0);
+
+        let mut active_row: entity::loan_repayment_schedules::ActiveModel = row.clone().into();
+
+        active_row.principal_due = Set(principal_paid);
+        active_row.interest_due = Set(interest_paid);
+        active_row.total_due = Set(principal_paid + interest_paid);
+        active_row.status = Set(Some(LoanRepaymentScheduleStatus::Paid));
+        active_row.updated_at = Set(Some(chrono::Utc::now().into()));
+
+        ActiveModelTrait::update(active_row, &txn).await?;
+    }
+
+    entity::loan_repayment_schedules::Entity::update_many()
+        .filter(entity::loan_repayment_schedules::Column::Id.is_in(superseded))
+        .col_expr(
+            entity::loan_repayment_schedules::Column::Status,
+            Expr::value(LoanRepaymentScheduleStatus::Superseded),
+        )
+        .col_expr(
+            entity::loan_repayment_schedules::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .exec(&txn)
+        .await?;
+
+    for installment in &schedule {
+        let (row_id, _) = match gen_snowflake_slug() {
+            Ok(res) => res,
+            Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+        };
+
+        entity::loan_repayment_schedules::ActiveModel {
+            id: Set(row_id),
+            loan_id: Set(request.loan_id),
+            installment_number: Set(installment.installment_number),
+            due_date: Set(installment.due_date),
+            principal_due: Set(installment.principal_due),
+            interest_due: Set(installment.interest_due),
+            total_due: Set(installment.total_due),
+            principal_paid: Set(Some(0)),
+            interest_paid: Set(Some(0)),
+            penalty_paid: Set(Some(0)),
+            status: Set(Some(LoanRepaymentScheduleStatus::Pending)),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+    }
+
+    let start = position
+        .loan
+        .disbursement_date
+        .unwrap_or(position.loan.application_date);
+
+    let mut active_loan: entity::loans::ActiveModel = position.loan.into();
+
+    active_loan.status = Set(Some(LoanApplicationStatus::Rescheduled));
+    active_loan.maturity_date = Set(last.due_date);
+    active_loan.tenure_days = Set((last.due_date - start).num_days() as i32);
+    active_loan.days_in_arrears = Set(Some(0));
+    active_loan.arrears_amount = Set(Some(0));
+    active_loan.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_loan, &txn).await?;
+
+    let mut active_request: entity::loan_rescheduling::ActiveModel = request.into();
+
+    active_request.status = Set(Some(LoanReschedulingStatus::Completed));
+    active_request.newd_maturity_date = Set(last.due_date);
+    active_request.new_installment_amount = Set(schedule.first().map(|row| row.total_due));
+    active_request.approved_by = Set(Some(data.reviewed_by));
+    active_request.approved_at = Set(Some(chrono::Utc::now().into()));
+    active_request.review_notes = Set(data.notes);
+    active_request.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_request, &txn).await?;
+
+    txn.commit().await?;
+
+    Ok(ReschedulingPreviewModel {
+        rescheduling: get_details(id, state).await?,
+        schedule,
+    })
+}
+
+pub async fn reject(
+    id: &i64,
+    model: &ReviewReschedulingModel,
+    state: &web::Data<AppState>,
+) -> Result<ReschedulingResponseModel, DbErr> {
+    let data = model.clone();
+
+    let txn = state.pgdb.begin().await?;
+
+    let request = find_request(&txn, id).await?;
+
+    if request.status != Some(LoanReschedulingStatus::Pending) {
+        return Err(DbErr::Custom(
+            "Rescheduling request is not pending".to_string(),
+        ));
+    }
+
+    let mut active_request: entity::loan_rescheduling::ActiveModel = request.into();
+
+    active_request.status = Set(Some(LoanReschedulingStatus::Rejected));
+    active_request.approved_by = Set(Some(data.reviewed_by));
+    active_request.approved_at = Set(Some(chrono::Utc::now().into()));
+    active_request.review_notes = Set(data.notes);
+    active_request.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_request, &txn).await?;
+
+    txn.commit().await?;
+
+    get_details(id, state).await
+}
+
+pub async fn get_loan_requests(
+    loan_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<ReschedulingResponseModel>, DbErr> {
+    let requests = entity::loan_rescheduling::Entity::find()
+        .filter(entity::loan_rescheduling::Column::LoanId.eq(*loan_id))
+        .order_by_desc(entity::loan_rescheduling::Column::RequestedAt)
+        .into_model::<ReschedulingResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(requests)
+}
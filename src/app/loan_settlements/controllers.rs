@@ -0,0 +1,112 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::loan_settlements::{
+        models::{
+            CreatePayoffQuoteModel, CreatePayoffQuoteParams, SettleLoanModel, SettleLoanParams,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn create_quote(
+    req: HttpRequest,
+    payload: web::Json<CreatePayoffQuoteParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_PAYOFF_QUOTE", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let loan_id = id_parser(&data.loan_id, "Loan Id").await?;
+
+    let quote = CreatePayoffQuoteModel {
+        loan_id,
+        as_of_date: data.as_of_date,
+        created_by: staff.id,
+    };
+
+    match services::create_quote(&quote, &state).await {
+        Ok(quote) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Payoff Quote Created",
+            quote,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn settle_loan(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<SettleLoanParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_SETTLE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Quote Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let debit_gl_account_id = id_parser(&data.debit_gl_account_id, "Debit GL Account Id").await?;
+
+    let settlement = SettleLoanModel {
+        debit_gl_account_id,
+        reference_number: data.reference_number,
+        settled_by: staff.id,
+    };
+
+    match services::settle_loan(&id, &settlement, &state).await {
+        Ok(quote) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Loan Settled",
+            quote,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn loan_quotes(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Id").await?;
+
+    match services::get_loan_quotes(&id, &state).await {
+        Ok(quotes) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            quotes,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
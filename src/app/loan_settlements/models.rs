@@ -0,0 +1,97 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[derive(Debug, Clone)]
+pub struct CreatePayoffQuoteModel {
+    pub loan_id: i64,
+    pub as_of_date: Option<NaiveDate>,
+    pub created_by: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SettleLoanModel {
+    pub debit_gl_account_id: i64,
+    pub reference_number: Option<String>,
+    pub settled_by: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayoffAmountsModel {
+    pub outstanding_principal: i64,
+    pub accrued_interest: i64,
+    pub outstanding_penalty: i64,
+    pub settlement_fee: i64,
+    pub total_amount: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreatePayoffQuoteParams {
+    #[serde(rename = "loanId")]
+    pub loan_id: String,
+    #[serde(rename = "asOfDate")]
+    pub as_of_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct SettleLoanParams {
+    #[serde(rename = "debitGlAccountId")]
+    pub debit_gl_account_id: String,
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "referenceNumber cannot be < 2 and > 100"
+    ))]
+    #[serde(rename = "referenceNumber")]
+    pub reference_number: Option<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_payoff_quotes::Entity")]
+pub struct PayoffQuoteResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "loan_id")]
+    pub loan_id: i64,
+    #[sea_orm(from_col = "quote_number")]
+    pub quote_number: String,
+    #[sea_orm(from_col = "as_of_date")]
+    pub as_of_date: NaiveDate,
+    #[sea_orm(from_col = "expires_on")]
+    pub expires_on: NaiveDate,
+    #[sea_orm(from_col = "outstanding_principal")]
+    pub outstanding_principal: i64,
+    #[sea_orm(from_col = "accrued_interest")]
+    pub accrued_interest: i64,
+    #[sea_orm(from_col = "outstanding_penalty")]
+    pub outstanding_penalty: i64,
+    #[sea_orm(from_col = "settlement_fee")]
+    pub settlement_fee: i64,
+    #[sea_orm(from_col = "total_amount")]
+    pub total_amount: i64,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<String>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "debit_gl_account_id")]
+    pub debit_gl_account_id: Option<i64>,
+    #[sea_orm(from_col = "settlement_reference")]
+    pub settlement_reference: Option<String>,
+    #[sea_orm(from_col = "settled_at")]
+    pub settled_at: Option<DateTime<FixedOffset>>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "settled_by")]
+    pub settled_by: Option<i64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "created_by")]
+    pub created_by: Option<i64>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
@@ -0,0 +1,27 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loan_settlements::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loan-settlements")
+            .route(
+                "/quotes",
+                web::post()
+                    .to(controllers::create_quote)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/quotes/{id}/settle",
+                web::post()
+                    .to(controllers::settle_loan)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/loans/{id}",
+                web::get()
+                    .to(controllers::loan_quotes)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
@@ -0,0 +1,513 @@
+use actix_web::web;
+use chrono::{Duration, NaiveDate};
+use entity::sea_orm_active_enums::{
+    LoanApplicationStatus, LoanPenaltyStatus, LoanRepaymentScheduleStatus,
+};
+use migration::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+
+use crate::{
+    AppState,
+    app::{
+        ledger::{self, models::GlEntryModel},
+        loan_settlements::models::{
+            CreatePayoffQuoteModel, PayoffAmountsModel, PayoffQuoteResponseModel, SettleLoanModel,
+        },
+        loans::services::{active_statuses, unpaid_schedule_statuses},
+    },
+    utils::{
+        finance::{accrued_interest, percent_of},
+        gen_snow_ids::gen_snowflake_slug,
+    },
+};
+
+const QUOTE_ACTIVE: &str = "ACTIVE";
+const QUOTE_SETTLED: &str = "SETTLED";
+const QUOTE_SUPERSEDED: &str = "SUPERSEDED";
+const QUOTE_EXPIRED: &str = "EXPIRED";
+
+async fn find_active_loan<C: ConnectionTrait>(
+    conn: &C,
+    id: i64,
+) -> Result<entity::loans::Model, DbErr> {
+    let loan = entity::loans::Entity::find_by_id(id)
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan not found".into()))?;
+
+    if !loan
+        .status
+        .as_ref()
+        .is_some_and(|status| active_statuses().contains(status))
+    {
+        return Err(DbErr::Custom(
+            "Only active loans can be settled".to_string(),
+        ));
+    }
+
+    Ok(loan)
+}
+
+async fn find_product(
+    id: i64,
+    state: &web::Data<AppState>,
+) -> Result<entity::loan_products::Model, DbErr> {
+    entity::loan_products::Entity::find_by_id(id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan product not found".into()))
+}
+
+async fn get_quote(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<PayoffQuoteResponseModel, DbErr> {
+    entity::loan_payoff_quotes::Entity::find_by_id(*id)
+        .into_model::<PayoffQuoteResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Payoff quote not found".into()))
+}
+
+// Pairs every unpaid instalment with the interest it has earned by `as_of`
+// and not yet been paid; interest for later periods is forgone on payoff
+async fn unpaid_installments<C: ConnectionTrait>(
+    conn: &C,
+    loan: &entity::loans::Model,
+    as_of: NaiveDate,
+) -> Result<Vec<(entity::loan_repayment_schedules::Model, i64)>, DbErr> {
+    let rows = entity::loan_repayment_schedules::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_repayment_schedules::Column::LoanId.eq(loan.id))
+                .add(
+                    entity::loan_repayment_schedules::Column::Status
+                        .ne(LoanRepaymentScheduleStatus::Superseded),
+                ),
+        )
+        .order_by_asc(entity::loan_repayment_schedules::Column::DueDate)
+        .all(conn)
+        .await?;
+
+    let mut period_start = loan.disbursement_date.unwrap_or(loan.application_date);
+    let mut unpaid = vec![];
+
+    for row in rows {
+        let start = period_start;
+        period_start = row.due_date;
+
+        if !row
+            .status
+            .as_ref()
+            .is_some_and(|status| unpaid_schedule_statuses().contains(status))
+        {
+            continue;
+        }
+
+        let earned = accrued_interest(row.interest_due, start, row.due_date, as_of);
+        let accrued = (earned - row.interest_paid.unwrap_or(0)).max(0);
+
+        unpaid.push((row, accrued));
+    }
+
+    Ok(unpaid)
+}
+
+pub async fn payoff_amounts<C: ConnectionTrait>(
+    conn: &C,
+    loan: &entity::loans::Model,
+    product: &entity::loan_products::Model,
+    as_of: NaiveDate,
+) -> Result<PayoffAmountsModel, DbErr> {
+    let installments = unpaid_installments(conn, loan, as_of).await?;
+
+    let principal = loan.outstanding_principal;
+    let interest: i64 = installments.iter().map(|(_, accrued)| accrued).sum();
+    let penalty = loan.outstanding_penalty.unwrap_or(0);
+
+    let settlement_fee = if as_of < loan.maturity_date {
+        product
+            .early_settlement_fee_rate
+            .map(|rate| percent_of(principal, rate))
+            .unwrap_or(0)
+            + product.early_settlement_fee_flat.unwrap_or(0)
+    } else {
+        0
+    };
+
+    Ok(PayoffAmountsModel {
+        outstanding_principal: principal,
+        accrued_interest: interest,
+        outstanding_penalty: penalty,
+        settlement_fee,
+        total_amount: principal + interest + penalty + settlement_fee,
+    })
+}
+
+// Only the latest quote for a loan can be honoured, so issuing one
+// supersedes any quote still active
+async fn issue_quote<C: ConnectionTrait>(
+    conn: &C,
+    loan: &entity::loans::Model,
+    product: &entity::loan_products::Model,
+    as_of: NaiveDate,
+    expires_on: NaiveDate,
+    created_by: i64,
+) -> Result<entity::loan_payoff_quotes::Model, DbErr> {
+    let amounts = payoff_amounts(conn, loan, product, as_of).await?;
+
+    let (id, slug) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::loan_payoff_quotes::Entity::update_many()
+        .filter(
+            Condition::all()
+                .add(entity::loan_payoff_quotes::Column::LoanId.eq(loan.id))
+                .add(entity::loan_payoff_quotes::Column::Status.eq(QUOTE_ACTIVE)),
+        )
+        .col_expr(
+            entity::loan_payoff_quotes::Column::Status,
+            Expr::value(QUOTE_SUPERSEDED),
+        )
+        .col_expr(
+            entity::loan_payoff_quotes::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .exec(conn)
+        .await?;
+
+    entity::loan_payoff_quotes::ActiveModel {
+        id: Set(id),
+        institution_id: Set(loan.institution_id),
+        loan_id: Set(loan.id),
+        quote_number: Set(format!("PQ-{}", slug)),
+        as_of_date: Set(as_of),
+        expires_on: Set(expires_on),
+        outstanding_principal: Set(amounts.outstanding_principal),
+        accrued_interest: Set(amounts.accrued_interest),
+        outstanding_penalty: Set(amounts.outstanding_penalty),
+        settlement_fee: Set(amounts.settlement_fee),
+        total_amount: Set(amounts.total_amount),
+        status: Set(Some(QUOTE_ACTIVE.to_string())),
+        created_by: Set(Some(created_by)),
+        ..Default::default()
+    }
+    .insert(conn)
+    .await
+}
+
+// Posts the quoted amounts, closes the open schedule and penalties and
+// marks the loan with `closed_as`
+async fn close_loan<C: ConnectionTrait>(
+    conn: &C,
+    quote: entity::loan_payoff_quotes::Model,
+    loan: entity::loans::Model,
+    product: &entity::loan_products::Model,
+    model: &SettleLoanModel,
+    closed_as: LoanApplicationStatus,
+) -> Result<(), DbErr> {
+    let data = model.clone();
+    let today = chrono::Utc::now().date_naive();
+
+    let Some(loan_gl) = product.loan_gl_account_id else {
+        return Err(DbErr::Custom(
+            "Loan GL account is not configured on the product".to_string(),
+        ));
+    };
+
+    // Interest already accrued sits in the receivable account, only the
+    // balance of the payoff interest is new income
+    let recognised = match product.interest_receivable_gl_account_id {
+        Some(_) => loan.outstanding_interest.unwrap_or(0).max(0),
+        None => 0,
+    };
+    let collected_recognised = recognised.min(quote.accrued_interest);
+
+    // Penalties were recognised as income when charged and sit in the
+    // loan receivable with the principal
+    let components = [
+        (
+            Some(loan_gl),
+            quote.outstanding_principal + quote.outstanding_penalty,
+            "Loan",
+        ),
+        (
+            product.interest_receivable_gl_account_id,
+            collected_recognised,
+            "Interest receivable",
+        ),
+        (
+            product.interest_gl_account_id,
+            quote.accrued_interest - collected_recognised,
+            "Interest",
+        ),
+        (
+            product.fee_income_gl_account_id,
+            quote.settlement_fee,
+            "Fee income",
+        ),
+    ];
+
+    let mut entries = vec![];
+
+    for (gl_account, amount, label) in components {
+        if amount <= 0 {
+            continue;
+        }
+
+        match gl_account {
+            Some(gl_account) => entries.push((data.debit_gl_account_id, gl_account, amount)),
+            None => {
+                return Err(DbErr::Custom(format!(
+                    "{} GL account is not configured on the product",
+                    label
+                )));
+            }
+        }
+    }
+
+    // Accruals beyond what the payoff collects are reversed out of income
+    if let (Some(receivable_gl), Some(income_gl)) = (
+        product.interest_receivable_gl_account_id,
+        product.interest_gl_account_id,
+    ) && recognised > collected_recognised
+    {
+        entries.push((income_gl, receivable_gl, recognised - collected_recognised));
+    }
+
+    let reference = data
+        .reference_number
+        .clone()
+        .unwrap_or_else(|| quote.quote_number.clone());
+    let narration = format!("Early settlement of loan {}", loan.loan_account_number);
+
+    for (debit, credit, amount) in entries {
+        // A refinance funded from the same loan GL nets off
+        if debit == credit {
+            continue;
+        }
+
+        ledger::services::post(
+            conn,
+            &GlEntryModel {
+                institution_id: loan.institution_id,
+                debit_account_id: debit,
+                credit_account_id: credit,
+                amount,
+                narration: narration.clone(),
+                reference_number: Some(reference.clone()),
+                transaction_id: None,
+                value_date: today,
+                posted_by: Some(data.settled_by),
+            },
+        )
+        .await?;
+    }
+
+    for (row, accrued) in unpaid_installments(conn, &loan, quote.as_of_date).await? {
+        let principal_due = row.principal_due;
+        let interest_paid = row.interest_paid.unwrap_or(0) + accrued;
+
+        let mut active_row: entity::loan_repayment_schedules::ActiveModel = row.into();
+
+        active_row.principal_paid = Set(Some(principal_due));
+        active_row.interest_paid = Set(Some(interest_paid));
+        active_row.status = Set(Some(LoanRepaymentScheduleStatus::Paid));
+        active_row.updated_at = Set(Some(chrono::Utc::now().into()));
+
+        ActiveModelTrait::update(active_row, conn).await?;
+    }
+
+    entity::loan_penalties::Entity::update_many()
+        .filter(
+            Condition::all()
+                .add(entity::loan_penalties::Column::LoanId.eq(loan.id))
+                .add(entity::loan_penalties::Column::Status.eq(LoanPenaltyStatus::Unpaid)),
+        )
+        .col_expr(
+            entity::loan_penalties::Column::Status,
+            Expr::value(LoanPenaltyStatus::Paid),
+        )
+        .exec(conn)
+        .await?;
+
+    let mut active_loan: entity::loans::ActiveModel = loan.into();
+
+    active_loan.status = Set(Some(closed_as));
+    active_loan.outstanding_principal = Set(0);
+    active_loan.outstanding_interest = Set(Some(0));
+    active_loan.outstanding_penalty = Set(Some(0));
+    active_loan.arrears_amount = Set(Some(0));
+    active_loan.days_in_arrears = Set(Some(0));
+    active_loan.last_repayment_date = Set(Some(today));
+    active_loan.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_loan, conn).await?;
+
+    let mut active_quote: entity::loan_payoff_quotes::ActiveModel = quote.into();
+
+    active_quote.status = Set(Some(QUOTE_SETTLED.to_string()));
+    active_quote.debit_gl_account_id = Set(Some(data.debit_gl_account_id));
+    active_quote.settlement_reference = Set(Some(reference));
+    active_quote.settled_at = Set(Some(chrono::Utc::now().into()));
+    active_quote.settled_by = Set(Some(data.settled_by));
+    active_quote.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_quote, conn).await?;
+
+    Ok(())
+}
+
+pub async fn create_quote(
+    model: &CreatePayoffQuoteModel,
+    state: &web::Data<AppState>,
+) -> Result<PayoffQuoteResponseModel, DbErr> {
+    let data = model.clone();
+    let today = chrono::Utc::now().date_naive();
+
+    let loan = find_active_loan(state.pgdb.get_ref(), data.loan_id).await?;
+    let product = find_product(loan.loan_product_id, state).await?;
+
+    let as_of = data.as_of_date.unwrap_or(today);
+
+    if as_of < today {
+        return Err(DbErr::Custom(
+            "Payoff quotes cannot be issued for a past date".to_string(),
+        ));
+    }
+
+    let validity_days = state
+        .config
+        .get::<i64>("loans.payoff_quote_validity_days")
+        .unwrap_or(7);
+
+    let txn = state.pgdb.begin().await?;
+
+    let quote = issue_quote(
+        &txn,
+        &loan,
+        &product,
+        as_of,
+        as_of + Duration::days(validity_days.max(0)),
+        data.created_by,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    get_quote(&quote.id, state).await
+}
+
+pub async fn settle_loan(
+    id: &i64,
+    model: &SettleLoanModel,
+    state: &web::Data<AppState>,
+) -> Result<PayoffQuoteResponseModel, DbErr> {
+    let today = chrono::Utc::now().date_naive();
+
+    let txn = state.pgdb.begin().await?;
+
+    // The quote and loan rows stay locked until commit so two settlements of
+    // the same quote cannot both see it active and post twice
+    let quote = entity::loan_payoff_quotes::Entity::find_by_id(*id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Payoff quote not found".into()))?;
+
+    if quote.status.as_deref() != Some(QUOTE_ACTIVE) {
+        return Err(DbErr::Custom(
+            "Payoff quote is no longer active".to_string(),
+        ));
+    }
+
+    if today < quote.as_of_date {
+        return Err(DbErr::Custom(format!(
+            "Payoff quote cannot be settled before {}",
+            quote.as_of_date
+        )));
+    }
+
+    if today > quote.expires_on {
+        let mut active_quote: entity::loan_payoff_quotes::ActiveModel = quote.into();
+        active_quote.status = Set(Some(QUOTE_EXPIRED.to_string()));
+        active_quote.updated_at = Set(Some(chrono::Utc::now().into()));
+        active_quote.update(&txn).await?;
+
+        txn.commit().await?;
+
+        return Err(DbErr::Custom(
+            "Payoff quote has expired, request a new quote".to_string(),
+        ));
+    }
+
+    let loan = find_active_loan(&txn, quote.loan_id).await?;
+
+    if loan.outstanding_principal != quote.outstanding_principal
+        || loan.outstanding_penalty.unwrap_or(0) != quote.outstanding_penalty
+    {
+        return Err(DbErr::Custom(
+            "Loan balances have changed since the quote was issued, request a new quote"
+                .to_string(),
+        ));
+    }
+
+    let product = find_product(loan.loan_product_id, state).await?;
+
+    close_loan(
+        &txn,
+        quote,
+        loan,
+        &product,
+        model,
+        LoanApplicationStatus::Repaid,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    get_quote(id, state).await
+}
+
+// Settles a loan being refinanced at today's payoff, funded from
+// `debit_gl_account_id`, as part of the caller's transaction
+pub async fn settle_for_refinance<C: ConnectionTrait>(
+    conn: &C,
+    loan: entity::loans::Model,
+    product: &entity::loan_products::Model,
+    model: &SettleLoanModel,
+) -> Result<entity::loan_payoff_quotes::Model, DbErr> {
+    let today = chrono::Utc::now().date_naive();
+
+    let quote = issue_quote(conn, &loan, product, today, today, model.settled_by).await?;
+
+    close_loan(
+        conn,
+        quote.clone(),
+        loan,
+        product,
+        model,
+        LoanApplicationStatus::Refinanced,
+    )
+    .await?;
+
+    Ok(quote)
+}
+
+pub async fn get_loan_quotes(
+    loan_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<PayoffQuoteResponseModel>, DbErr> {
+    entity::loan_payoff_quotes::Entity::find()
+        .filter(entity::loan_payoff_quotes::Column::LoanId.eq(*loan_id))
+        .order_by_desc(entity::loan_payoff_quotes::Column::CreatedAt)
+        .into_model::<PayoffQuoteResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await
+}
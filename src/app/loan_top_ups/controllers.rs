@@ -0,0 +1,113 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::loan_top_ups::{
+        models::{CreateTopUpModel, CreateTopUpParams, DisburseTopUpModel, DisburseTopUpParams},
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn create_top_up(
+    req: HttpRequest,
+    payload: web::Json<CreateTopUpParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_TOP_UP_CREATE", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let loan_id = id_parser(&data.loan_id, "Loan Id").await?;
+
+    let top_up = CreateTopUpModel {
+        loan_id,
+        requested_principal: data.requested_principal,
+        requested_tenure_days: data.requested_tenure_days,
+        purpose: data.purpose,
+        created_by: staff.id,
+    };
+
+    match services::create_top_up(&top_up, &state).await {
+        Ok(application) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Top-up Application Created",
+            application,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn disburse_top_up(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<DisburseTopUpParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_TOP_UP_DISBURSE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Application Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let payout_gl_account_id =
+        id_parser(&data.payout_gl_account_id, "Payout GL Account Id").await?;
+
+    let disbursement = DisburseTopUpModel {
+        payout_gl_account_id,
+        reference_number: data.reference_number,
+        disbursed_by: staff.id,
+    };
+
+    match services::disburse_top_up(&id, &disbursement, &state).await {
+        Ok(loan) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Top-up Disbursed",
+            loan,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn loan_refinancing(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Id").await?;
+
+    match services::get_loan_refinancing(&id, &state).await {
+        Ok(refinancing) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            refinancing,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
@@ -0,0 +1,96 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use entity::sea_orm_active_enums::LoanApplicationStatus;
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[derive(Debug, Clone)]
+pub struct CreateTopUpModel {
+    pub loan_id: i64,
+    pub requested_principal: i64,
+    pub requested_tenure_days: i32,
+    pub purpose: Option<String>,
+    pub created_by: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct DisburseTopUpModel {
+    pub payout_gl_account_id: i64,
+    pub reference_number: Option<String>,
+    pub disbursed_by: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateTopUpParams {
+    #[serde(rename = "loanId")]
+    pub loan_id: String,
+    #[validate(range(min = 1, message = "requestedPrincipal must be greater than 0"))]
+    #[serde(rename = "requestedPrincipal")]
+    pub requested_principal: i64,
+    #[validate(range(min = 1, message = "requestedTenureDays must be greater than 0"))]
+    #[serde(rename = "requestedTenureDays")]
+    pub requested_tenure_days: i32,
+    #[validate(length(min = 2, max = 500, message = "purpose cannot be < 2 and > 500"))]
+    pub purpose: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct DisburseTopUpParams {
+    #[serde(rename = "payoutGlAccountId")]
+    pub payout_gl_account_id: String,
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "referenceNumber cannot be < 2 and > 100"
+    ))]
+    #[serde(rename = "referenceNumber")]
+    pub reference_number: Option<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loans::Entity")]
+pub struct TopUpLoanResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "loan_application_id")]
+    pub loan_application_id: i64,
+    #[sea_orm(from_col = "loan_account_number")]
+    pub loan_account_number: String,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "refinanced_loan_id")]
+    pub refinanced_loan_id: Option<i64>,
+    #[sea_orm(from_col = "principal_amount")]
+    pub principal_amount: i64,
+    #[sea_orm(from_col = "disbursed_amount")]
+    pub disbursed_amount: i64,
+    #[sea_orm(from_col = "outstanding_principal")]
+    pub outstanding_principal: i64,
+    #[sea_orm(from_col = "interest_rate")]
+    pub interest_rate: Decimal,
+    #[sea_orm(from_col = "tenure_days")]
+    pub tenure_days: i32,
+    #[sea_orm(from_col = "disbursement_date")]
+    pub disbursement_date: Option<NaiveDate>,
+    #[sea_orm(from_col = "maturity_date")]
+    pub maturity_date: NaiveDate,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<LoanApplicationStatus>,
+    #[sea_orm(from_col = "custom_fields")]
+    pub custom_fields: Option<Value>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LoanRefinancingModel {
+    pub refinanced_from: Option<TopUpLoanResponseModel>,
+    pub refinanced_by: Vec<TopUpLoanResponseModel>,
+}
@@ -0,0 +1,27 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loan_top_ups::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loan-top-ups")
+            .route(
+                "",
+                web::post()
+                    .to(controllers::create_top_up)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/applications/{id}/disburse",
+                web::put()
+                    .to(controllers::disburse_top_up)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/loans/{id}",
+                web::get()
+                    .to(controllers::loan_refinancing)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
@@ -0,0 +1,396 @@
+use actix_web::web;
+use entity::sea_orm_active_enums::{
+    LoanApplicationStatus, LoanClassification, LoanProductCalcMethod, LoanRepaymentFreq,
+    LoanRepaymentScheduleStatus,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, TransactionTrait,
+};
+use serde_json::json;
+
+use crate::{
+    AppState,
+    app::{
+        ledger::{self, models::GlEntryModel},
+        loan_applications::{
+            self,
+            models::{CreateApplicationModel, LoanApplicationResponseModel},
+            services::record_status_change,
+        },
+        loan_collaterals, loan_guarantors,
+        loan_settlements::{self, models::SettleLoanModel},
+        loan_top_ups::models::{
+            CreateTopUpModel, DisburseTopUpModel, LoanRefinancingModel, TopUpLoanResponseModel,
+        },
+        loans::services::active_statuses,
+    },
+    utils::{finance::repayment_schedule, gen_snow_ids::gen_snowflake_slug},
+};
+
+async fn find_loan(id: i64, state: &web::Data<AppState>) -> Result<entity::loans::Model, DbErr> {
+    entity::loans::Entity::find_by_id(id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan not found".into()))
+}
+
+async fn find_product(
+    id: i64,
+    state: &web::Data<AppState>,
+) -> Result<entity::loan_products::Model, DbErr> {
+    entity::loan_products::Entity::find_by_id(id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan product not found".into()))
+}
+
+async fn get_loan(id: &i64, state: &web::Data<AppState>) -> Result<TopUpLoanResponseModel, DbErr> {
+    entity::loans::Entity::find_by_id(*id)
+        .into_model::<TopUpLoanResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan not found".into()))
+}
+
+// Only performing loans can be topped up
+fn check_top_up_eligibility(loan: &entity::loans::Model) -> Result<(), DbErr> {
+    if !loan
+        .status
+        .as_ref()
+        .is_some_and(|status| active_statuses().contains(status))
+    {
+        return Err(DbErr::Custom(
+            "Only active loans can be topped up".to_string(),
+        ));
+    }
+
+    if loan.is_npa.unwrap_or(false) || loan.days_in_arrears.unwrap_or(0) > 0 {
+        return Err(DbErr::Custom(
+            "Loans in arrears cannot be topped up".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+pub async fn create_top_up(
+    model: &CreateTopUpModel,
+    state: &web::Data<AppState>,
+) -> Result<LoanApplicationResponseModel, DbErr> {
+    let data = model.clone();
+    let today = chrono::Utc::now().date_naive();
+
+    let loan = find_loan(data.loan_id, state).await?;
+
+    check_top_up_eligibility(&loan)?;
+
+    let open = entity::loan_applications::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_applications::Column::RefinancedLoanId.eq(loan.id))
+                .add(entity::loan_applications::Column::Status.is_in([
+                    LoanApplicationStatus::Draft,
+                    LoanApplicationStatus::Pending,
+                    LoanApplicationStatus::Approved,
+                ])),
+        )
+        .one(state.pgdb.get_ref())
+        .await?;
+
+    if open.is_some() {
+        return Err(DbErr::Custom(
+            "Loan already has an open top-up application".to_string(),
+        ));
+    }
+
+    let product = find_product(loan.loan_product_id, state).await?;
+
+    let payoff =
+        loan_settlements::services::payoff_amounts(state.pgdb.get_ref(), &loan, &product, today)
+            .await?;
+
+    if data.requested_principal <= payoff.total_amount {
+        return Err(DbErr::Custom(format!(
+            "Top-up principal must exceed the current payoff of {}",
+            payoff.total_amount
+        )));
+    }
+
+    let application = CreateApplicationModel {
+        customer_id: loan.customer_id,
+        loan_product_id: loan.loan_product_id,
+        requested_principal: data.requested_principal,
+        requested_tenure_days: data.requested_tenure_days,
+        purpose: data.purpose,
+        application_data: Some(json!({
+            "top_up": {
+                "loan_id": loan.id.to_string(),
+                "loan_account_number": loan.loan_account_number,
+                "payoff_amount": payoff.total_amount,
+                "quoted_on": today,
+            },
+        })),
+        employment_details: None,
+        financial_details: None,
+        refinanced_loan_id: Some(loan.id),
+        created_by: data.created_by,
+    };
+
+    loan_applications::services::create_application(&application, state).await
+}
+
+// The new loan's principal first pays off the old loan; only the
+// difference is paid out to the customer
+pub async fn disburse_top_up(
+    application_id: &i64,
+    model: &DisburseTopUpModel,
+    state: &web::Data<AppState>,
+) -> Result<TopUpLoanResponseModel, DbErr> {
+    let data = model.clone();
+    let today = chrono::Utc::now().date_naive();
+
+    let txn = state.pgdb.begin().await?;
+
+    // The application and old loan stay locked until commit so a second
+    // disbursement waits and then finds the application already disbursed
+    let application = entity::loan_applications::Entity::find_by_id(*application_id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan application not found".into()))?;
+
+    let Some(old_loan_id) = application.refinanced_loan_id else {
+        return Err(DbErr::Custom(
+            "Application is not a top-up application".to_string(),
+        ));
+    };
+
+    if application.status != Some(LoanApplicationStatus::Approved) {
+        return Err(DbErr::Custom(
+            "Only approved top-up applications can be disbursed".to_string(),
+        ));
+    }
+
+    let old_loan = entity::loans::Entity::find_by_id(old_loan_id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan not found".into()))?;
+
+    check_top_up_eligibility(&old_loan)?;
+
+    let product = find_product(application.loan_product_id, state).await?;
+
+    let Some(loan_gl) = product.loan_gl_account_id else {
+        return Err(DbErr::Custom(
+            "Loan GL account is not configured on the product".to_string(),
+        ));
+    };
+
+    let principal = application
+        .approved_principal
+        .unwrap_or(application.requested_principal);
+    let tenure_days = application
+        .approved_tenure_days
+        .unwrap_or(application.requested_tenure_days);
+    let interest_rate = application
+        .approved_interest_rate
+        .unwrap_or(product.interest_rate);
+    let freq = old_loan
+        .repayment_freq
+        .clone()
+        .unwrap_or(LoanRepaymentFreq::Monthly);
+
+    let schedule = repayment_schedule(
+        principal,
+        interest_rate,
+        &product
+            .interest_calc_method
+            .clone()
+            .unwrap_or(LoanProductCalcMethod::Flat),
+        &freq,
+        today,
+        tenure_days,
+    );
+
+    let (Some(first), Some(last)) = (schedule.first(), schedule.last()) else {
+        return Err(DbErr::Custom(
+            "Could not build a repayment schedule".to_string(),
+        ));
+    };
+
+    let (loan_id, slug) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let reference = data
+        .reference_number
+        .clone()
+        .unwrap_or_else(|| format!("TOP-{}", slug));
+
+    let quote = loan_settlements::services::settle_for_refinance(
+        &txn,
+        old_loan.clone(),
+        &product,
+        &SettleLoanModel {
+            debit_gl_account_id: loan_gl,
+            reference_number: Some(reference.clone()),
+            settled_by: data.disbursed_by,
+        },
+    )
+    .await?;
+
+    let net_payout = principal - quote.total_amount;
+
+    if net_payout <= 0 {
+        return Err(DbErr::Custom(format!(
+            "Top-up principal must exceed the payoff of {}",
+            quote.total_amount
+        )));
+    }
+
+    ledger::services::post(
+        &txn,
+        &GlEntryModel {
+            institution_id: application.institution_id,
+            debit_account_id: loan_gl,
+            credit_account_id: data.payout_gl_account_id,
+            amount: net_payout,
+            narration: format!("Top-up disbursement of loan {}", slug),
+            reference_number: Some(reference),
+            transaction_id: None,
+            value_date: today,
+            posted_by: Some(data.disbursed_by),
+        },
+    )
+    .await?;
+
+    entity::loans::ActiveModel {
+        id: Set(loan_id),
+        institution_id: Set(application.institution_id),
+        loan_application_id: Set(application.id),
+        loan_product_id: Set(application.loan_product_id),
+        customer_id: Set(application.customer_id),
+        account_id: Set(old_loan.account_id),
+        loan_account_number: Set(slug),
+        principal_amount: Set(principal),
+        disbursed_amount: Set(principal),
+        outstanding_principal: Set(principal),
+        outstanding_interest: Set(Some(0)),
+        outstanding_penalty: Set(Some(0)),
+        tenure_days: Set(tenure_days),
+        interest_rate: Set(interest_rate),
+        repayment_freq: Set(Some(freq)),
+        application_date: Set(application
+            .created_at
+            .map(|created_at| created_at.date_naive())
+            .unwrap_or(today)),
+        approval_date: Set(application
+            .approved_at
+            .map(|approved_at| approved_at.date_naive())),
+        disbursement_date: Set(Some(today)),
+        first_repayment_date: Set(Some(first.due_date)),
+        maturity_date: Set(last.due_date),
+        status: Set(Some(LoanApplicationStatus::Disbursed)),
+        is_npa: Set(Some(false)),
+        days_in_arrears: Set(Some(0)),
+        arrears_amount: Set(Some(0)),
+        classification: Set(Some(LoanClassification::Current)),
+        refinanced_loan_id: Set(Some(old_loan.id)),
+        custom_fields: Set(Some(json!({
+            "top_up": {
+                "settled_loan_id": old_loan.id.to_string(),
+                "payoff_quote_id": quote.id.to_string(),
+                "payoff_amount": quote.total_amount,
+                "net_payout": net_payout,
+            },
+        }))),
+        created_by: Set(Some(data.disbursed_by)),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+
+    loan_collaterals::services::link_to_loan(&txn, application.id, loan_id).await?;
+    loan_guarantors::services::link_to_loan(&txn, application.id, loan_id).await?;
+    loan_collaterals::services::transfer_to_loan(&txn, &old_loan, loan_id).await?;
+    loan_guarantors::services::transfer_to_loan(&txn, &old_loan, loan_id).await?;
+
+    for installment in &schedule {
+        let (row_id, _) = match gen_snowflake_slug() {
+            Ok(res) => res,
+            Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+        };
+
+        entity::loan_repayment_schedules::ActiveModel {
+            id: Set(row_id),
+            loan_id: Set(loan_id),
+            installment_number: Set(installment.installment_number),
+            due_date: Set(installment.due_date),
+            principal_due: Set(installment.principal_due),
+            interest_due: Set(installment.interest_due),
+            total_due: Set(installment.total_due),
+            principal_paid: Set(Some(0)),
+            interest_paid: Set(Some(0)),
+            penalty_paid: Set(Some(0)),
+            status: Set(Some(LoanRepaymentScheduleStatus::Pending)),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+    }
+
+    let mut active_application: entity::loan_applications::ActiveModel = application.into();
+
+    active_application.status = Set(Some(LoanApplicationStatus::Disbursed));
+    active_application.current_stage = Set(Some("DISBURSED".to_string()));
+    active_application.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    active_application.update(&txn).await?;
+
+    record_status_change(
+        &txn,
+        *application_id,
+        Some(&LoanApplicationStatus::Approved),
+        &LoanApplicationStatus::Disbursed,
+        json!({
+            "reason": "Top-up disbursed",
+            "loan_id": loan_id.to_string(),
+            "settled_loan_id": old_loan_id.to_string(),
+            "payoff_amount": quote.total_amount,
+            "net_payout": net_payout,
+        }),
+        data.disbursed_by,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    get_loan(&loan_id, state).await
+}
+
+pub async fn get_loan_refinancing(
+    loan_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<LoanRefinancingModel, DbErr> {
+    let loan = find_loan(*loan_id, state).await?;
+
+    let refinanced_from = match loan.refinanced_loan_id {
+        Some(id) => Some(get_loan(&id, state).await?),
+        None => None,
+    };
+
+    let refinanced_by = entity::loans::Entity::find()
+        .filter(entity::loans::Column::RefinancedLoanId.eq(loan.id))
+        .order_by_desc(entity::loans::Column::CreatedAt)
+        .into_model::<TopUpLoanResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(LoanRefinancingModel {
+        refinanced_from,
+        refinanced_by,
+    })
+}
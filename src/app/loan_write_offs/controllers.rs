@@ -0,0 +1,185 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::loan_write_offs::{
+        models::{
+            RecordRecoveryModel, RecordRecoveryParams, RequestWriteOffModel, RequestWriteOffParams,
+            ReviewWriteOffModel, ReviewWriteOffParams,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn request_write_off(
+    req: HttpRequest,
+    payload: web::Json<RequestWriteOffParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_WRITE_OFF_REQUEST", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let loan_id = id_parser(&data.loan_id, "Loan Id").await?;
+
+    let request = RequestWriteOffModel {
+        loan_id,
+        reason: data.reason,
+        requested_by: staff.id,
+    };
+
+    match services::request_write_off(&request, &state).await {
+        Ok(request) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Successful",
+            request,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn approve_write_off(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<ReviewWriteOffParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_WRITE_OFF_APPROVE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let review = ReviewWriteOffModel {
+        notes: payload.into_inner().notes,
+        reviewed_by: staff.id,
+    };
+
+    match services::approve_write_off(&id, &review, &state).await {
+        Ok(write_off) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Loan Written Off",
+            write_off,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn reject_write_off(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<ReviewWriteOffParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_WRITE_OFF_APPROVE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let review = ReviewWriteOffModel {
+        notes: payload.into_inner().notes,
+        reviewed_by: staff.id,
+    };
+
+    match services::reject_write_off(&id, &review, &state).await {
+        Ok(request) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            request,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn record_recovery(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<RecordRecoveryParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "LOAN_RECOVERY_RECORD", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Write-off Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let debit_gl_account_id = id_parser(&data.debit_gl_account_id, "Debit GL Account Id").await?;
+
+    let recovery = RecordRecoveryModel {
+        amount: data.amount,
+        debit_gl_account_id,
+        reference_number: data.reference_number,
+        notes: data.notes,
+        recorded_by: staff.id,
+    };
+
+    match services::record_recovery(&id, &recovery, &state).await {
+        Ok(write_off) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Recovery Recorded",
+            write_off,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn loan_write_off(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Loan Id").await?;
+
+    match services::get_loan_write_off(&id, &state).await {
+        Ok(write_off) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            write_off,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
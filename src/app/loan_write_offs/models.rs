@@ -0,0 +1,158 @@
+use chrono::{DateTime, FixedOffset};
+use entity::sea_orm_active_enums::{MakerCheckerCheckerAction, MakerCheckerStatus};
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[derive(Debug, Clone)]
+pub struct RequestWriteOffModel {
+    pub loan_id: i64,
+    pub reason: String,
+    pub requested_by: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReviewWriteOffModel {
+    pub notes: Option<String>,
+    pub reviewed_by: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordRecoveryModel {
+    pub amount: i64,
+    pub debit_gl_account_id: i64,
+    pub reference_number: Option<String>,
+    pub notes: Option<String>,
+    pub recorded_by: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RequestWriteOffParams {
+    #[serde(rename = "loanId")]
+    pub loan_id: String,
+    #[validate(length(min = 5, max = 255, message = "reason cannot be < 5 and > 255"))]
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct ReviewWriteOffParams {
+    #[validate(length(min = 2, max = 500, message = "notes cannot be < 2 and > 500"))]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct RecordRecoveryParams {
+    #[validate(range(min = 1, message = "amount must be greater than 0"))]
+    pub amount: i64,
+    #[serde(rename = "debitGlAccountId")]
+    pub debit_gl_account_id: String,
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "referenceNumber cannot be < 2 and > 100"
+    ))]
+    #[serde(rename = "referenceNumber")]
+    pub reference_number: Option<String>,
+    #[validate(length(min = 2, max = 500, message = "notes cannot be < 2 and > 500"))]
+    pub notes: Option<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::maker_checker_workflows::Entity")]
+pub struct WriteOffRequestResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "reference_id")]
+    pub loan_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "maker_id")]
+    pub maker_id: i64,
+    #[sea_orm(from_col = "maker_notes")]
+    pub maker_notes: Option<String>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "checker_id")]
+    pub checker_id: Option<i64>,
+    #[sea_orm(from_col = "checker_action")]
+    pub checker_action: Option<MakerCheckerCheckerAction>,
+    #[sea_orm(from_col = "checker_notes")]
+    pub checker_notes: Option<String>,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<MakerCheckerStatus>,
+    #[sea_orm(from_col = "requested_at")]
+    pub requested_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "checked_at")]
+    pub checked_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "implementation_result")]
+    pub implementation_result: Option<Value>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_write_offs::Entity")]
+pub struct WriteOffResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "loan_id")]
+    pub loan_id: i64,
+    #[sea_orm(from_col = "write_off_amount")]
+    pub write_off_amount: i64,
+    #[sea_orm(from_col = "outstanding_principal")]
+    pub outstanding_principal: i64,
+    #[sea_orm(from_col = "outstanding_interest")]
+    pub outstanding_interest: i64,
+    #[sea_orm(from_col = "outstanding_penalty")]
+    pub outstanding_penalty: i64,
+    #[sea_orm(from_col = "write_off_reason")]
+    pub write_off_reason: Option<String>,
+    #[sea_orm(from_col = "provision_amount")]
+    pub provision_amount: Option<i64>,
+    #[sea_orm(from_col = "recovered_amount")]
+    pub recovered_amount: Option<i64>,
+    #[sea_orm(from_col = "written_off_at")]
+    pub written_off_at: Option<DateTime<FixedOffset>>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "written_off_by")]
+    pub written_off_by: Option<i64>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_write_off_recoveries::Entity")]
+pub struct RecoveryResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "loan_write_off_id")]
+    pub loan_write_off_id: i64,
+    #[sea_orm(from_col = "amount")]
+    pub amount: i64,
+    #[sea_orm(from_col = "reference_number")]
+    pub reference_number: Option<String>,
+    #[sea_orm(from_col = "notes")]
+    pub notes: Option<String>,
+    #[sea_orm(from_col = "recovered_at")]
+    pub recovered_at: Option<DateTime<FixedOffset>>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "recorded_by")]
+    pub recorded_by: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WriteOffDetailsModel {
+    pub write_off: WriteOffResponseModel,
+    pub recoveries: Vec<RecoveryResponseModel>,
+}
@@ -0,0 +1,39 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loan_write_offs::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loan-write-offs")
+            .route(
+                "",
+                web::post()
+                    .to(controllers::request_write_off)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/requests/{id}/approve",
+                web::put()
+                    .to(controllers::approve_write_off)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/requests/{id}/reject",
+                web::put()
+                    .to(controllers::reject_write_off)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/{id}/recoveries",
+                web::post()
+                    .to(controllers::record_recovery)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/loans/{id}",
+                web::get()
+                    .to(controllers::loan_write_off)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
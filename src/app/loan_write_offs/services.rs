@@ -0,0 +1,498 @@
+use actix_web::web;
+use entity::sea_orm_active_enums::{
+    LoanApplicationStatus, MakerCheckerCheckerAction, MakerCheckerReferenceType, MakerCheckerStatus,
+};
+use migration::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+use serde_json::json;
+
+use crate::{
+    AppState,
+    app::{
+        ledger::{self, models::GlEntryModel},
+        loan_write_offs::models::{
+            RecordRecoveryModel, RecoveryResponseModel, RequestWriteOffModel, ReviewWriteOffModel,
+            WriteOffDetailsModel, WriteOffRequestResponseModel, WriteOffResponseModel,
+        },
+        loans::services::active_statuses,
+    },
+    utils::gen_snow_ids::gen_snowflake_slug,
+};
+
+async fn find_loan<C: ConnectionTrait>(conn: &C, id: i64) -> Result<entity::loans::Model, DbErr> {
+    entity::loans::Entity::find_by_id(id)
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan not found".into()))
+}
+
+// A loan-level threshold overrides the institution-wide default
+fn check_write_off_eligibility(
+    loan: &entity::loans::Model,
+    state: &web::Data<AppState>,
+) -> Result<(), DbErr> {
+    if !loan
+        .status
+        .as_ref()
+        .is_some_and(|status| active_statuses().contains(status))
+    {
+        return Err(DbErr::Custom(
+            "Only active loans can be written off".to_string(),
+        ));
+    }
+
+    let threshold = loan.days_to_write_off.unwrap_or(
+        state
+            .config
+            .get::<i32>("loans.days_to_write_off")
+            .unwrap_or(360),
+    );
+
+    let days_in_arrears = loan.days_in_arrears.unwrap_or(0);
+
+    if days_in_arrears < threshold {
+        return Err(DbErr::Custom(format!(
+            "Loan is {} days in arrears, write-off requires at least {}",
+            days_in_arrears, threshold
+        )));
+    }
+
+    Ok(())
+}
+
+async fn get_request(
+    id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<WriteOffRequestResponseModel, DbErr> {
+    entity::maker_checker_workflows::Entity::find_by_id(*id)
+        .into_model::<WriteOffRequestResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Write-off request not found".into()))
+}
+
+// Locks the request so concurrent reviews see each other's decision
+async fn find_pending_request<C: ConnectionTrait>(
+    conn: &C,
+    id: &i64,
+    reviewed_by: i64,
+) -> Result<entity::maker_checker_workflows::Model, DbErr> {
+    let request = entity::maker_checker_workflows::Entity::find_by_id(*id)
+        .filter(
+            entity::maker_checker_workflows::Column::ReferenceType
+                .eq(MakerCheckerReferenceType::LoanWriteOff),
+        )
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Write-off request not found".into()))?;
+
+    if request.status != Some(MakerCheckerStatus::Pending) {
+        return Err(DbErr::Custom(
+            "Write-off request is not pending".to_string(),
+        ));
+    }
+
+    if request.maker_id == reviewed_by {
+        return Err(DbErr::Custom(
+            "Write-off must be approved by a different staff member".to_string(),
+        ));
+    }
+
+    Ok(request)
+}
+
+pub async fn request_write_off(
+    model: &RequestWriteOffModel,
+    state: &web::Data<AppState>,
+) -> Result<WriteOffRequestResponseModel, DbErr> {
+    let data = model.clone();
+
+    let loan = find_loan(state.pgdb.get_ref(), data.loan_id).await?;
+
+    check_write_off_eligibility(&loan, state)?;
+
+    let pending = entity::maker_checker_workflows::Entity::find()
+        .filter(
+            Condition::all()
+                .add(
+                    entity::maker_checker_workflows::Column::ReferenceType
+                        .eq(MakerCheckerReferenceType::LoanWriteOff),
+                )
+                .add(entity::maker_checker_workflows::Column::ReferenceId.eq(loan.id))
+                .add(
+                    entity::maker_checker_workflows::Column::Status.eq(MakerCheckerStatus::Pending),
+                ),
+        )
+        .one(state.pgdb.get_ref())
+        .await?;
+
+    if pending.is_some() {
+        return Err(DbErr::Custom(
+            "Loan already has a pending write-off request".to_string(),
+        ));
+    }
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::maker_checker_workflows::ActiveModel {
+        id: Set(id),
+        institution_id: Set(loan.institution_id),
+        reference_type: Set(MakerCheckerReferenceType::LoanWriteOff),
+        reference_id: Set(loan.id),
+        maker_id: Set(data.requested_by),
+        maker_action: Set("WRITE_OFF".to_string()),
+        maker_notes: Set(Some(data.reason.clone())),
+        request_data: Set(json!({
+            "reason": data.reason,
+            "outstanding_principal": loan.outstanding_principal,
+            "outstanding_interest": loan.outstanding_interest.unwrap_or(0),
+            "outstanding_penalty": loan.outstanding_penalty.unwrap_or(0),
+            "days_in_arrears": loan.days_in_arrears.unwrap_or(0),
+        })),
+        status: Set(Some(MakerCheckerStatus::Pending)),
+        requested_at: Set(Some(chrono::Utc::now().into())),
+        ..Default::default()
+    }
+    .insert(state.pgdb.get_ref())
+    .await?;
+
+    get_request(&id, state).await
+}
+
+pub async fn approve_write_off(
+    id: &i64,
+    model: &ReviewWriteOffModel,
+    state: &web::Data<AppState>,
+) -> Result<WriteOffResponseModel, DbErr> {
+    let data = model.clone();
+    let today = chrono::Utc::now().date_naive();
+
+    let txn = state.pgdb.begin().await?;
+
+    let request = find_pending_request(&txn, id, data.reviewed_by).await?;
+
+    let loan = find_loan(&txn, request.reference_id).await?;
+
+    check_write_off_eligibility(&loan, state)?;
+
+    let product = entity::loan_products::Entity::find_by_id(loan.loan_product_id)
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan product not found".into()))?;
+
+    let Some(classification) = loan.classification.clone() else {
+        return Err(DbErr::Custom(
+            "Loan has not been classified yet".to_string(),
+        ));
+    };
+
+    let rule = entity::loan_classification_rules::Entity::find()
+        .filter(
+            Condition::all()
+                .add(
+                    entity::loan_classification_rules::Column::InstitutionId
+                        .eq(loan.institution_id),
+                )
+                .add(entity::loan_classification_rules::Column::Classification.eq(classification)),
+        )
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Classification rule not found".into()))?;
+
+    let (Some(loan_gl), Some(expense_gl), Some(reserve_gl)) = (
+        product.loan_gl_account_id,
+        rule.provision_expense_gl_account_id,
+        rule.provision_reserve_gl_account_id,
+    ) else {
+        return Err(DbErr::Custom(
+            "Loan and provision GL accounts must be configured before writing off".to_string(),
+        ));
+    };
+
+    let principal = loan.outstanding_principal;
+    let interest = loan.outstanding_interest.unwrap_or(0);
+    let penalty = loan.outstanding_penalty.unwrap_or(0);
+    let provision_used = loan.provision_amount.unwrap_or(0).min(principal).max(0);
+
+    let reference = format!("WOF-{}", loan.loan_account_number);
+    let narration = format!("Write-off of loan {}", loan.loan_account_number);
+
+    // Provisions absorb the principal first; any shortfall and the accrued
+    // interest go to provision expense, and unpaid penalties are reversed out
+    // of the penalty income they were recognised in
+    let mut entries = vec![
+        (reserve_gl, loan_gl, provision_used),
+        (expense_gl, loan_gl, principal - provision_used),
+    ];
+
+    if interest > 0 {
+        let Some(receivable_gl) = product.interest_receivable_gl_account_id else {
+            return Err(DbErr::Custom(
+                "Interest receivable GL account must be configured to write off interest"
+                    .to_string(),
+            ));
+        };
+
+        entries.push((expense_gl, receivable_gl, interest));
+    }
+
+    if penalty > 0 {
+        let Some(penalty_gl) = product.penalty_gl_account_id else {
+            return Err(DbErr::Custom(
+                "Penalty GL account must be configured to write off penalties".to_string(),
+            ));
+        };
+
+        entries.push((penalty_gl, loan_gl, penalty));
+    }
+
+    let (write_off_id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::loan_write_offs::ActiveModel {
+        id: Set(write_off_id),
+        loan_id: Set(loan.id),
+        write_off_amount: Set(principal + interest + penalty),
+        outstanding_principal: Set(principal),
+        outstanding_interest: Set(interest),
+        outstanding_penalty: Set(penalty),
+        write_off_reason: Set(request.maker_notes.clone()),
+        provision_amount: Set(Some(provision_used)),
+        recovered_amount: Set(Some(0)),
+        written_off_at: Set(Some(chrono::Utc::now().into())),
+        written_off_by: Set(Some(data.reviewed_by)),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+
+    for (debit, credit, amount) in entries {
+        if amount <= 0 {
+            continue;
+        }
+
+        ledger::services::post(
+            &txn,
+            &GlEntryModel {
+                institution_id: loan.institution_id,
+                debit_account_id: debit,
+                credit_account_id: credit,
+                amount,
+                narration: narration.clone(),
+                reference_number: Some(reference.clone()),
+                transaction_id: None,
+                value_date: today,
+                posted_by: Some(data.reviewed_by),
+            },
+        )
+        .await?;
+    }
+
+    let loan_id = loan.id;
+    let mut active_loan: entity::loans::ActiveModel = loan.into();
+
+    active_loan.status = Set(Some(LoanApplicationStatus::WrittenOff));
+    active_loan.outstanding_principal = Set(0);
+    active_loan.outstanding_interest = Set(Some(0));
+    active_loan.outstanding_penalty = Set(Some(0));
+    active_loan.provision_amount = Set(Some(0));
+    active_loan.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_loan, &txn).await?;
+
+    let mut active_request: entity::maker_checker_workflows::ActiveModel = request.into();
+
+    active_request.status = Set(Some(MakerCheckerStatus::Approved));
+    active_request.checker_id = Set(Some(data.reviewed_by));
+    active_request.checker_action = Set(Some(MakerCheckerCheckerAction::Approved));
+    active_request.checker_notes = Set(data.notes);
+    active_request.checked_at = Set(Some(chrono::Utc::now().into()));
+    active_request.implemented_at = Set(Some(chrono::Utc::now().into()));
+    active_request.implementation_result = Set(Some(json!({
+        "loan_write_off_id": write_off_id.to_string(),
+    })));
+
+    ActiveModelTrait::update(active_request, &txn).await?;
+
+    txn.commit().await?;
+
+    entity::loan_write_offs::Entity::find()
+        .filter(entity::loan_write_offs::Column::LoanId.eq(loan_id))
+        .into_model::<WriteOffResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Write-off not found".into()))
+}
+
+pub async fn reject_write_off(
+    id: &i64,
+    model: &ReviewWriteOffModel,
+    state: &web::Data<AppState>,
+) -> Result<WriteOffRequestResponseModel, DbErr> {
+    let data = model.clone();
+
+    let txn = state.pgdb.begin().await?;
+
+    let request = find_pending_request(&txn, id, data.reviewed_by).await?;
+
+    let mut active_request: entity::maker_checker_workflows::ActiveModel = request.into();
+
+    active_request.status = Set(Some(MakerCheckerStatus::Rejected));
+    active_request.checker_id = Set(Some(data.reviewed_by));
+    active_request.checker_action = Set(Some(MakerCheckerCheckerAction::Rejected));
+    active_request.checker_notes = Set(data.notes);
+    active_request.checked_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_request, &txn).await?;
+
+    txn.commit().await?;
+
+    get_request(id, state).await
+}
+
+pub async fn record_recovery(
+    id: &i64,
+    model: &RecordRecoveryModel,
+    state: &web::Data<AppState>,
+) -> Result<WriteOffDetailsModel, DbErr> {
+    let data = model.clone();
+
+    let write_off = entity::loan_write_offs::Entity::find_by_id(*id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Write-off not found".into()))?;
+
+    let loan = find_loan(state.pgdb.get_ref(), write_off.loan_id).await?;
+
+    let product = entity::loan_products::Entity::find_by_id(loan.loan_product_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan product not found".into()))?;
+
+    let Some(recovery_gl) = product.recovery_gl_account_id else {
+        return Err(DbErr::Custom(
+            "Recovery income GL account is not configured on the product".to_string(),
+        ));
+    };
+
+    let remaining = write_off.write_off_amount - write_off.recovered_amount.unwrap_or(0);
+
+    if data.amount > remaining {
+        return Err(DbErr::Custom(format!(
+            "Recovery cannot exceed the unrecovered balance of {}",
+            remaining
+        )));
+    }
+
+    let (recovery_id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let txn = state.pgdb.begin().await?;
+
+    // The cap is enforced by the update itself so concurrent recoveries
+    // cannot together recover more than was written off
+    let result = entity::loan_write_offs::Entity::update_many()
+        .col_expr(
+            entity::loan_write_offs::Column::RecoveredAmount,
+            Expr::col(entity::loan_write_offs::Column::RecoveredAmount)
+                .if_null(0)
+                .add(data.amount),
+        )
+        .col_expr(
+            entity::loan_write_offs::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(
+            Condition::all()
+                .add(entity::loan_write_offs::Column::Id.eq(write_off.id))
+                .add(
+                    Expr::expr(
+                        Expr::col(entity::loan_write_offs::Column::RecoveredAmount)
+                            .if_null(0)
+                            .add(data.amount),
+                    )
+                    .lte(Expr::col(entity::loan_write_offs::Column::WriteOffAmount)),
+                ),
+        )
+        .exec(&txn)
+        .await?;
+
+    if result.rows_affected == 0 {
+        return Err(DbErr::Custom(
+            "Recovery cannot exceed the unrecovered balance".to_string(),
+        ));
+    }
+
+    entity::loan_write_off_recoveries::ActiveModel {
+        id: Set(recovery_id),
+        loan_write_off_id: Set(write_off.id),
+        loan_id: Set(loan.id),
+        amount: Set(data.amount),
+        debit_gl_account_id: Set(data.debit_gl_account_id),
+        credit_gl_account_id: Set(recovery_gl),
+        reference_number: Set(data.reference_number.clone()),
+        notes: Set(data.notes),
+        recovered_at: Set(Some(chrono::Utc::now().into())),
+        recorded_by: Set(Some(data.recorded_by)),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+
+    // The loan is off balance sheet, so recoveries are recognised as income
+    ledger::services::post(
+        &txn,
+        &GlEntryModel {
+            institution_id: loan.institution_id,
+            debit_account_id: data.debit_gl_account_id,
+            credit_account_id: recovery_gl,
+            amount: data.amount,
+            narration: format!("Recovery on written-off loan {}", loan.loan_account_number),
+            reference_number: data.reference_number,
+            transaction_id: None,
+            value_date: chrono::Utc::now().date_naive(),
+            posted_by: Some(data.recorded_by),
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    get_loan_write_off(&loan.id, state).await
+}
+
+pub async fn get_loan_write_off(
+    loan_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<WriteOffDetailsModel, DbErr> {
+    let write_off = entity::loan_write_offs::Entity::find()
+        .filter(entity::loan_write_offs::Column::LoanId.eq(*loan_id))
+        .into_model::<WriteOffResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Write-off not found".into()))?;
+
+    let recoveries = entity::loan_write_off_recoveries::Entity::find()
+        .filter(entity::loan_write_off_recoveries::Column::LoanWriteOffId.eq(write_off.id))
+        .order_by_desc(entity::loan_write_off_recoveries::Column::RecoveredAt)
+        .into_model::<RecoveryResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(WriteOffDetailsModel {
+        write_off,
+        recoveries,
+    })
+}
@@ -0,0 +1,56 @@
+use chrono::{DateTime, FixedOffset};
+use entity::sea_orm_active_enums::{LoanPenaltyStatus, LoanPenaltyType};
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::loan_penalties::Entity")]
+pub struct PenaltyResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "loan_id")]
+    pub loan_id: i64,
+    #[sea_orm(from_col = "installment_number")]
+    pub installment_number: i32,
+    #[sea_orm(from_col = "penalty_type")]
+    pub penalty_type: Option<LoanPenaltyType>,
+    #[sea_orm(from_col = "penalty_amount")]
+    pub penalty_amount: Option<i64>,
+    #[sea_orm(from_col = "calculated_at")]
+    pub calculated_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "waived_amount")]
+    pub waived_amount: Option<i64>,
+    #[sea_orm(from_col = "waived_at")]
+    pub waived_at: Option<DateTime<FixedOffset>>,
+    #[sea_orm(from_col = "waive_reason")]
+    pub waive_reason: Option<String>,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<LoanPenaltyStatus>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "waived_by")]
+    pub waived_by: Option<i64>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WaivePenaltyModel {
+    pub amount: Option<i64>,
+    pub reason: String,
+    pub waived_by: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct WaivePenaltyParams {
+    #[validate(range(min = 1, message = "amount must be greater than 0"))]
+    pub amount: Option<i64>,
+    #[validate(length(min = 5, max = 255, message = "reason cannot be < 5 and > 255"))]
+    pub reason: String,
+}
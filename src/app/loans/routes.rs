@@ -0,0 +1,21 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::loans::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/loans")
+            .route(
+                "/{id}/penalties",
+                web::get()
+                    .to(controllers::loan_penalties)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/penalties/{id}/waive",
+                web::put()
+                    .to(controllers::waive_penalty)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
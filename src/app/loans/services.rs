@@ -0,0 +1,504 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_web::web;
+use chrono::Duration;
+use entity::sea_orm_active_enums::{
+    AccrualReferenceType, AccrualStatus, AccrualType, LoanApplicationStatus, LoanPenaltyStatus,
+    LoanPenaltyType, LoanProductCalcMethod, LoanProductFreq, LoanRepaymentScheduleStatus,
+};
+use migration::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, TransactionTrait,
+};
+
+use crate::{
+    AppState,
+    app::{
+        ledger::{self, models::GlEntryModel},
+        loans::models::{PenaltyResponseModel, WaivePenaltyModel},
+    },
+    utils::{
+        finance::{next_accrual_date, percent_of, simple_interest},
+        gen_snow_ids::gen_snowflake_slug,
+    },
+};
+
+pub fn active_statuses() -> Vec<LoanApplicationStatus> {
+    vec![
+        LoanApplicationStatus::Disbursed,
+        LoanApplicationStatus::Rescheduled,
+    ]
+}
+
+pub fn unpaid_schedule_statuses() -> Vec<LoanRepaymentScheduleStatus> {
+    vec![
+        LoanRepaymentScheduleStatus::Pending,
+        LoanRepaymentScheduleStatus::Partial,
+        LoanRepaymentScheduleStatus::Overdue,
+    ]
+}
+
+pub async fn apply_late_penalties(state: &web::Data<AppState>) -> Result<u64, DbErr> {
+    let today = chrono::Utc::now().date_naive();
+
+    let overdue = entity::loan_repayment_schedules::Entity::find()
+        .find_also_related(entity::loans::Entity)
+        .filter(
+            Condition::all()
+                .add(
+                    entity::loan_repayment_schedules::Column::Status
+                        .is_in(unpaid_schedule_statuses()),
+                )
+                .add(entity::loan_repayment_schedules::Column::DueDate.lt(today))
+                .add(entity::loans::Column::Status.is_in(active_statuses())),
+        )
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    if overdue.is_empty() {
+        return Ok(0);
+    }
+
+    let loan_ids: HashSet<i64> = overdue.iter().map(|(row, _)| row.loan_id).collect();
+
+    let product_ids: HashSet<i64> = overdue
+        .iter()
+        .filter_map(|(_, loan)| loan.as_ref().map(|l| l.loan_product_id))
+        .collect();
+
+    let products: HashMap<i64, entity::loan_products::Model> =
+        entity::loan_products::Entity::find()
+            .filter(entity::loan_products::Column::Id.is_in(product_ids))
+            .all(state.pgdb.get_ref())
+            .await?
+            .into_iter()
+            .map(|product| (product.id, product))
+            .collect();
+
+    let charged: HashSet<(i64, i32)> = entity::loan_penalties::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loan_penalties::Column::LoanId.is_in(loan_ids))
+                .add(entity::loan_penalties::Column::PenaltyType.eq(LoanPenaltyType::LatePayment)),
+        )
+        .all(state.pgdb.get_ref())
+        .await?
+        .into_iter()
+        .map(|penalty| (penalty.loan_id, penalty.installment_number))
+        .collect();
+
+    let mut applied = 0;
+
+    for (installment, loan) in overdue {
+        let Some(loan) = loan else { continue };
+        let Some(product) = products.get(&loan.loan_product_id) else {
+            continue;
+        };
+
+        if charged.contains(&(loan.id, installment.installment_number)) {
+            continue;
+        }
+
+        let grace = i64::from(product.penalty_grace_period_days.unwrap_or(0).max(0));
+
+        if installment.due_date + Duration::days(grace) >= today {
+            continue;
+        }
+
+        let overdue_amount = installment.total_due
+            - installment.principal_paid.unwrap_or(0)
+            - installment.interest_paid.unwrap_or(0);
+
+        if overdue_amount <= 0 {
+            continue;
+        }
+
+        let penalty = product.late_payment_penalty_flat.unwrap_or(0)
+            + product
+                .late_payment_penalty_rate
+                .map(|rate| percent_of(overdue_amount, rate))
+                .unwrap_or(0);
+
+        if penalty <= 0 {
+            continue;
+        }
+
+        // Penalties are recognised as income when charged
+        let (Some(loan_gl), Some(penalty_gl)) =
+            (product.loan_gl_account_id, product.penalty_gl_account_id)
+        else {
+            log::warn!(
+                "Skipping penalty on loan {}: loan or penalty GL account is not configured",
+                loan.loan_account_number
+            );
+            continue;
+        };
+
+        let (id, _) = match gen_snowflake_slug() {
+            Ok(res) => res,
+            Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+        };
+
+        let txn = state.pgdb.begin().await?;
+
+        entity::loan_penalties::ActiveModel {
+            id: Set(id),
+            loan_id: Set(loan.id),
+            installment_number: Set(installment.installment_number),
+            penalty_type: Set(Some(LoanPenaltyType::LatePayment)),
+            penalty_amount: Set(Some(penalty)),
+            calculated_at: Set(Some(chrono::Utc::now().into())),
+            waived_amount: Set(Some(0)),
+            status: Set(Some(LoanPenaltyStatus::Unpaid)),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        ledger::services::post(
+            &txn,
+            &GlEntryModel {
+                institution_id: loan.institution_id,
+                debit_account_id: loan_gl,
+                credit_account_id: penalty_gl,
+                amount: penalty,
+                narration: format!("Late payment penalty on loan {}", loan.loan_account_number),
+                reference_number: Some(format!("PEN-{}", loan.loan_account_number)),
+                transaction_id: None,
+                value_date: today,
+                posted_by: None,
+            },
+        )
+        .await?;
+
+        entity::loan_repayment_schedules::Entity::update_many()
+            .filter(entity::loan_repayment_schedules::Column::Id.eq(installment.id))
+            .col_expr(
+                entity::loan_repayment_schedules::Column::Status,
+                Expr::value(LoanRepaymentScheduleStatus::Overdue),
+            )
+            .col_expr(
+                entity::loan_repayment_schedules::Column::UpdatedAt,
+                Expr::value(chrono::Utc::now()),
+            )
+            .exec(&txn)
+            .await?;
+
+        entity::loans::Entity::update_many()
+            .filter(entity::loans::Column::Id.eq(loan.id))
+            .col_expr(
+                entity::loans::Column::OutstandingPenalty,
+                Expr::col(entity::loans::Column::OutstandingPenalty)
+                    .if_null(0)
+                    .add(penalty),
+            )
+            .col_expr(
+                entity::loans::Column::UpdatedAt,
+                Expr::value(chrono::Utc::now()),
+            )
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        applied += 1;
+    }
+
+    Ok(applied)
+}
+
+// Recognises interest earned since the last accrual for every performing
+// loan. NPA loans are skipped, which suspends accrual until they recover;
+// ageing records the recovery date so the suspended period is not accrued.
+pub async fn accrue_interest(state: &web::Data<AppState>) -> Result<u64, DbErr> {
+    let today = chrono::Utc::now().date_naive();
+
+    let loans = entity::loans::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::loans::Column::Status.is_in(active_statuses()))
+                .add(
+                    Condition::any()
+                        .add(entity::loans::Column::IsNpa.is_null())
+                        .add(entity::loans::Column::IsNpa.eq(false)),
+                ),
+        )
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    if loans.is_empty() {
+        return Ok(0);
+    }
+
+    let loan_ids: Vec<i64> = loans.iter().map(|loan| loan.id).collect();
+
+    let product_ids: HashSet<i64> = loans.iter().map(|loan| loan.loan_product_id).collect();
+
+    let products: HashMap<i64, entity::loan_products::Model> =
+        entity::loan_products::Entity::find()
+            .filter(entity::loan_products::Column::Id.is_in(product_ids))
+            .all(state.pgdb.get_ref())
+            .await?
+            .into_iter()
+            .map(|product| (product.id, product))
+            .collect();
+
+    // Date each loan has already been accrued up to
+    let mut accrued_to: HashMap<i64, chrono::NaiveDate> = HashMap::new();
+
+    for accrual in entity::accruals_and_provisions::Entity::find()
+        .filter(
+            Condition::all()
+                .add(
+                    entity::accruals_and_provisions::Column::AccrualType
+                        .eq(AccrualType::InterestAccrual),
+                )
+                .add(
+                    entity::accruals_and_provisions::Column::ReferenceType
+                        .eq(AccrualReferenceType::Loan),
+                )
+                .add(entity::accruals_and_provisions::Column::ReferenceId.is_in(loan_ids))
+                .add(entity::accruals_and_provisions::Column::Status.ne(AccrualStatus::Reversed)),
+        )
+        .all(state.pgdb.get_ref())
+        .await?
+    {
+        let Some(loan_id) = accrual.reference_id else {
+            continue;
+        };
+
+        let entry = accrued_to.entry(loan_id).or_insert(accrual.accrual_date);
+        *entry = (*entry).max(accrual.accrual_date);
+    }
+
+    let mut accrued = 0;
+
+    for loan in loans {
+        let Some(product) = products.get(&loan.loan_product_id) else {
+            continue;
+        };
+
+        let (Some(income_gl), Some(receivable_gl)) = (
+            product.interest_gl_account_id,
+            product.interest_receivable_gl_account_id,
+        ) else {
+            log::warn!(
+                "Skipping interest accrual on loan {}: interest GL accounts are not configured on product {}",
+                loan.loan_account_number,
+                product.id
+            );
+            continue;
+        };
+
+        let Some(last) = accrued_to.get(&loan.id).copied().or(loan.disbursement_date) else {
+            continue;
+        };
+
+        let freq = product
+            .interest_accural_freq
+            .clone()
+            .unwrap_or(LoanProductFreq::Daily);
+
+        if next_accrual_date(&freq, last, loan.maturity_date) > today {
+            continue;
+        }
+
+        let accrue_to = today.min(loan.maturity_date);
+
+        let base = match product.interest_calc_method {
+            Some(LoanProductCalcMethod::Flat) => loan.principal_amount,
+            _ => loan.outstanding_principal,
+        };
+
+        let amount = simple_interest(base, loan.interest_rate, (accrue_to - last).num_days());
+
+        if amount <= 0 {
+            continue;
+        }
+
+        let (id, _) = match gen_snowflake_slug() {
+            Ok(res) => res,
+            Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+        };
+
+        let txn = state.pgdb.begin().await?;
+
+        entity::accruals_and_provisions::ActiveModel {
+            id: Set(id),
+            institution_id: Set(loan.institution_id),
+            accrual_type: Set(AccrualType::InterestAccrual),
+            account_id: Set(income_gl),
+            accrual_date: Set(accrue_to),
+            posting_date: Set(Some(chrono::Utc::now().into())),
+            accrual_amount: Set(amount),
+            reversal_amount: Set(Some(0)),
+            reference_type: Set(Some(AccrualReferenceType::Loan)),
+            reference_id: Set(Some(loan.id)),
+            status: Set(Some(AccrualStatus::Posted)),
+            posted_at: Set(Some(chrono::Utc::now().into())),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        ledger::services::post(
+            &txn,
+            &GlEntryModel {
+                institution_id: loan.institution_id,
+                debit_account_id: receivable_gl,
+                credit_account_id: income_gl,
+                amount,
+                narration: format!("Interest accrual on loan {}", loan.loan_account_number),
+                reference_number: Some(format!("ACR-{}", loan.loan_account_number)),
+                transaction_id: None,
+                value_date: accrue_to,
+                posted_by: None,
+            },
+        )
+        .await?;
+
+        entity::loans::Entity::update_many()
+            .filter(entity::loans::Column::Id.eq(loan.id))
+            .col_expr(
+                entity::loans::Column::OutstandingInterest,
+                Expr::col(entity::loans::Column::OutstandingInterest)
+                    .if_null(0)
+                    .add(amount),
+            )
+            .col_expr(
+                entity::loans::Column::UpdatedAt,
+                Expr::value(chrono::Utc::now()),
+            )
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        accrued += 1;
+    }
+
+    Ok(accrued)
+}
+
+pub async fn get_penalties(
+    loan_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<PenaltyResponseModel>, DbErr> {
+    let penalties = entity::loan_penalties::Entity::find()
+        .filter(entity::loan_penalties::Column::LoanId.eq(*loan_id))
+        .order_by_asc(entity::loan_penalties::Column::InstallmentNumber)
+        .into_model::<PenaltyResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(penalties)
+}
+
+pub async fn waive_penalty(
+    id: &i64,
+    model: &WaivePenaltyModel,
+    state: &web::Data<AppState>,
+) -> Result<PenaltyResponseModel, DbErr> {
+    let txn = state.pgdb.begin().await?;
+
+    // Locked so concurrent waivers see each other's waived amount
+    let penalty = entity::loan_penalties::Entity::find_by_id(*id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Penalty not found".into()))?;
+
+    if penalty.status != Some(LoanPenaltyStatus::Unpaid) {
+        return Err(DbErr::Custom("Penalty is not outstanding".to_string()));
+    }
+
+    let already_waived = penalty.waived_amount.unwrap_or(0);
+    let remaining = penalty.penalty_amount.unwrap_or(0) - already_waived;
+
+    let amount = model.amount.unwrap_or(remaining);
+
+    if amount <= 0 || amount > remaining {
+        return Err(DbErr::Custom(format!(
+            "Waiver amount must be between 1 and {}",
+            remaining
+        )));
+    }
+
+    let data = model.clone();
+    let loan_id = penalty.loan_id;
+
+    let loan = entity::loans::Entity::find_by_id(loan_id)
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan not found".into()))?;
+
+    let product = entity::loan_products::Entity::find_by_id(loan.loan_product_id)
+        .one(&txn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Loan product not found".into()))?;
+
+    let (Some(loan_gl), Some(penalty_gl)) =
+        (product.loan_gl_account_id, product.penalty_gl_account_id)
+    else {
+        return Err(DbErr::Custom(
+            "Loan and penalty GL accounts must be configured before waiving".to_string(),
+        ));
+    };
+
+    let mut active_penalty: entity::loan_penalties::ActiveModel = penalty.into();
+
+    active_penalty.waived_amount = Set(Some(already_waived + amount));
+    active_penalty.waived_at = Set(Some(chrono::Utc::now().into()));
+    active_penalty.waived_by = Set(Some(data.waived_by));
+    active_penalty.waive_reason = Set(Some(data.reason));
+
+    // A fully waived penalty drops out of collections
+    if amount == remaining {
+        active_penalty.status = Set(Some(LoanPenaltyStatus::Waived));
+    }
+
+    active_penalty.updated_at = Set(Some(chrono::Utc::now().into()));
+
+    ActiveModelTrait::update(active_penalty, &txn).await?;
+
+    // Reverses the income recognised when the penalty was charged
+    ledger::services::post(
+        &txn,
+        &GlEntryModel {
+            institution_id: loan.institution_id,
+            debit_account_id: penalty_gl,
+            credit_account_id: loan_gl,
+            amount,
+            narration: format!("Penalty waiver on loan {}", loan.loan_account_number),
+            reference_number: Some(format!("PWV-{}", loan.loan_account_number)),
+            transaction_id: None,
+            value_date: chrono::Utc::now().date_naive(),
+            posted_by: Some(data.waived_by),
+        },
+    )
+    .await?;
+
+    entity::loans::Entity::update_many()
+        .filter(entity::loans::Column::Id.eq(loan_id))
+        .col_expr(
+            entity::loans::Column::OutstandingPenalty,
+            Expr::col(entity::loans::Column::OutstandingPenalty)
+                .if_null(0)
+                .sub(amount),
+        )
+        .col_expr(
+            entity::loans::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .exec(&txn)
+        .await?;
+
+    txn.commit().await?;
+
+    entity::loan_penalties::Entity::find_by_id(*id)
+        .into_model::<PenaltyResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Penalty not found".into()))
+}
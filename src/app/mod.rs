@@ -2,11 +2,37 @@ use actix_web::web::{self, ServiceConfig};
 
 use crate::AppState;
 
+pub mod agent_audits;
+pub mod agent_commissions;
+pub mod agent_performance;
+pub mod agent_settlements;
+pub mod agent_transactions;
+pub mod agents;
 pub mod branches;
+pub mod contribution_cycles;
 pub mod countries;
+pub mod credit_bureau;
 pub mod customers;
+pub mod group_savings;
 pub mod health;
 pub mod institutions;
+pub mod ledger;
+pub mod loan_applications;
+pub mod loan_approvals;
+pub mod loan_assignments;
+pub mod loan_calculator;
+pub mod loan_collaterals;
+pub mod loan_documents;
+pub mod loan_eligibility;
+pub mod loan_guarantors;
+pub mod loan_provisioning;
+pub mod loan_rescheduling;
+pub mod loan_settlements;
+pub mod loan_top_ups;
+pub mod loan_write_offs;
+pub mod loans;
+pub mod notifications;
+pub mod savings;
 pub mod staffs;
 
 pub fn app_routes(state: web::Data<AppState>) -> impl FnOnce(&mut ServiceConfig) + Clone {
@@ -17,5 +43,29 @@ pub fn app_routes(state: web::Data<AppState>) -> impl FnOnce(&mut ServiceConfig)
         cfg.configure(|c| branches::routes::init(c, state.clone()));
         cfg.configure(|c| customers::routes::init(c, state.clone()));
         cfg.configure(|c| staffs::routes::init(c, state.clone()));
+        cfg.configure(|c| loans::routes::init(c, state.clone()));
+        cfg.configure(|c| loan_provisioning::routes::init(c, state.clone()));
+        cfg.configure(|c| loan_rescheduling::routes::init(c, state.clone()));
+        cfg.configure(|c| loan_write_offs::routes::init(c, state.clone()));
+        cfg.configure(|c| loan_applications::routes::init(c, state.clone()));
+        cfg.configure(|c| loan_collaterals::routes::init(c, state.clone()));
+        cfg.configure(|c| loan_guarantors::routes::init(c, state.clone()));
+        cfg.configure(|c| loan_eligibility::routes::init(c, state.clone()));
+        cfg.configure(|c| credit_bureau::routes::init(c, state.clone()));
+        cfg.configure(|c| loan_settlements::routes::init(c, state.clone()));
+        cfg.configure(|c| loan_top_ups::routes::init(c, state.clone()));
+        cfg.configure(|c| loan_approvals::routes::init(c, state.clone()));
+        cfg.configure(|c| loan_documents::routes::init(c, state.clone()));
+        cfg.configure(|c| loan_assignments::routes::init(c, state.clone()));
+        cfg.configure(|c| loan_calculator::routes::init(c, state.clone()));
+        cfg.configure(|c| savings::routes::init(c, state.clone()));
+        cfg.configure(|c| group_savings::routes::init(c, state.clone()));
+        cfg.configure(|c| contribution_cycles::routes::init(c, state.clone()));
+        cfg.configure(|c| agents::routes::init(c, state.clone()));
+        cfg.configure(|c| agent_transactions::routes::init(c, state.clone()));
+        cfg.configure(|c| agent_commissions::routes::init(c, state.clone()));
+        cfg.configure(|c| agent_settlements::routes::init(c, state.clone()));
+        cfg.configure(|c| agent_performance::routes::init(c, state.clone()));
+        cfg.configure(|c| agent_audits::routes::init(c, state.clone()));
     }
 }
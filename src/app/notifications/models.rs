@@ -0,0 +1,16 @@
+use entity::sea_orm_active_enums::NotificationType;
+use serde_json::Value;
+
+#[derive(Debug, Clone)]
+pub struct QueueNotificationModel {
+    pub institution_id: i64,
+    pub customer_id: Option<i64>,
+    pub staff_id: Option<i64>,
+    pub agent_id: Option<i64>,
+    pub recipient_contact: String,
+    pub notification_type: NotificationType,
+    pub template_code: String,
+    pub default_subject: Option<String>,
+    pub default_body: String,
+    pub variables: Value,
+}
@@ -0,0 +1,89 @@
+use entity::sea_orm_active_enums::NotificationQueueStatus;
+use sea_orm::{
+    ActiveEnum, ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter,
+};
+use serde_json::Value;
+
+use crate::{
+    app::notifications::models::QueueNotificationModel, utils::gen_snow_ids::gen_snowflake_slug,
+};
+
+fn render(template: &str, variables: &Value) -> String {
+    let mut rendered = template.to_string();
+
+    if let Value::Object(map) = variables {
+        for (key, value) in map {
+            let text = match value {
+                Value::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+
+            rendered = rendered.replace(&format!("{{{{{}}}}}", key), &text);
+        }
+    }
+
+    rendered
+}
+
+// Uses the institution's active template for the code when one exists, otherwise the default text
+pub async fn enqueue<C: ConnectionTrait>(
+    conn: &C,
+    notification: &QueueNotificationModel,
+) -> Result<entity::notification_queue::Model, DbErr> {
+    let data = notification.clone();
+
+    let template = entity::notification_templates::Entity::find()
+        .filter(
+            Condition::all()
+                .add(entity::notification_templates::Column::InstitutionId.eq(data.institution_id))
+                .add(entity::notification_templates::Column::TemplateCode.eq(&data.template_code))
+                .add(
+                    entity::notification_templates::Column::NotificationType
+                        .eq(data.notification_type.clone()),
+                )
+                .add(entity::notification_templates::Column::IsActive.eq(true)),
+        )
+        .one(conn)
+        .await?;
+
+    let (template_id, subject, body) = match template {
+        Some(template) => (
+            Some(template.id),
+            template
+                .subject_template
+                .map(|subject| render(&subject, &data.variables)),
+            render(&template.body_template, &data.variables),
+        ),
+        None => (
+            None,
+            data.default_subject
+                .map(|subject| render(&subject, &data.variables)),
+            render(&data.default_body, &data.variables),
+        ),
+    };
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::notification_queue::ActiveModel {
+        id: Set(id),
+        institution_id: Set(data.institution_id),
+        customer_id: Set(data.customer_id),
+        staff_id: Set(data.staff_id),
+        agent_id: Set(data.agent_id),
+        recipient_contact: Set(Some(data.recipient_contact)),
+        template_id: Set(template_id),
+        notification_type: Set(data.notification_type.to_value()),
+        subject: Set(subject),
+        body: Set(body),
+        variables: Set(Some(data.variables)),
+        status: Set(Some(NotificationQueueStatus::Pending)),
+        scheduled_at: Set(Some(chrono::Utc::now().into())),
+        ..Default::default()
+    }
+    .insert(conn)
+    .await
+}
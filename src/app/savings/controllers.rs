@@ -0,0 +1,262 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use validator::Validate;
+
+use crate::{
+    AppState,
+    app::savings::{
+        models::{
+            AmountParams, ContributeModel, CreateGoalModel, CreateGoalParams, CreateProductModel,
+            CreateProductParams, WithdrawModel,
+        },
+        services,
+    },
+    utils::{
+        errors::{ApiCode, ApiError, ApiResponse},
+        gen_snow_ids::id_parser,
+        models::PathParamsModel,
+        permissions::require_permission,
+    },
+};
+
+pub async fn create_product(
+    req: HttpRequest,
+    payload: web::Json<CreateProductParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "SAVINGS_PRODUCT_CREATE", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let institution_id = id_parser(&data.institution_id, "Institution Id").await?;
+
+    let gl_account_id = match data.gl_account_id {
+        Some(id) => Some(id_parser(&id, "GL Account Id").await?),
+        None => None,
+    };
+
+    let penalty_income_gl_account_id = match data.penalty_income_gl_account_id {
+        Some(id) => Some(id_parser(&id, "Penalty Income GL Account Id").await?),
+        None => None,
+    };
+
+    let deposit_gl_account_id = match data.deposit_gl_account_id {
+        Some(id) => Some(id_parser(&id, "Deposit GL Account Id").await?),
+        None => None,
+    };
+
+    let product = CreateProductModel {
+        institution_id,
+        name: data.name,
+        code: data.code.to_uppercase(),
+        description: data.description,
+        savings_type: data.savings_type,
+        min_contribution: data.min_contribution,
+        max_contribution: data.max_contribution,
+        default_contribution: data.default_contribution,
+        contribution_freq: data.contribution_freq,
+        interest_rate: data.interest_rate,
+        lock_period_days: data.lock_period_days,
+        withdrawal_restriction_days: data.withdrawal_restriction_days,
+        is_early_withdrawal_allowed: data.is_early_withdrawal_allowed,
+        early_withdrawal_penalty_rate: data.early_withdrawal_penalty_rate,
+        is_target_amount_enabled: data.is_target_amount_enabled,
+        min_target_amount: data.min_target_amount,
+        max_target_amount: data.max_target_amount,
+        is_group_savings_allowed: data.is_group_savings_allowed,
+        min_group_members: data.min_group_members,
+        max_group_members: data.max_group_members,
+        missed_contribution_penalty: data.missed_contribution_penalty,
+        gl_account_id,
+        penalty_income_gl_account_id,
+        deposit_gl_account_id,
+        created_by: staff.id,
+    };
+
+    match services::create_product(&product, &state).await {
+        Ok(product) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Savings Product Created",
+            product,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn institution_products(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Institution Id").await?;
+
+    match services::get_products(&id, &state).await {
+        Ok(products) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            products,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn create_goal(
+    req: HttpRequest,
+    payload: web::Json<CreateGoalParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "SAVING_GOAL_CREATE", &state).await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let data = payload.into_inner();
+
+    let customer_id = id_parser(&data.customer_id, "Customer Id").await?;
+    let savings_product_id = id_parser(&data.savings_product_id, "Savings Product Id").await?;
+    let account_id = id_parser(&data.account_id, "Account Id").await?;
+
+    let goal = CreateGoalModel {
+        customer_id,
+        savings_product_id,
+        account_id,
+        goal_name: data.goal_name,
+        target_amount: data.target_amount,
+        target_completion_date: data.target_completion_date,
+        contribution_amount: data.contribution_amount,
+    };
+
+    match services::create_goal(&goal, &state).await {
+        Ok(goal) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Saving Goal Created",
+            goal,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn contribute(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<AmountParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    require_permission(&req, "SAVING_GOAL_CONTRIBUTE", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Saving Goal Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let contribution = ContributeModel {
+        amount: payload.into_inner().amount,
+    };
+
+    match services::contribute(&id, &contribution, &state).await {
+        Ok(contribution) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Contribution Recorded",
+            contribution,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn withdraw(
+    req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    payload: web::Json<AmountParams>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let staff = require_permission(&req, "SAVING_GOAL_WITHDRAW", &state).await?;
+
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Saving Goal Id").await?;
+
+    payload
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let withdrawal = WithdrawModel {
+        amount: payload.into_inner().amount,
+        withdrawn_by: staff.id,
+    };
+
+    match services::withdraw(&id, &withdrawal, &state).await {
+        Ok(withdrawal) => Ok(HttpResponse::Created().json(ApiResponse::success(
+            ApiCode::ResourceCreated,
+            "Withdrawal Completed",
+            withdrawal,
+        ))),
+        Err(err) => Err(ApiError::BadRequest(err.to_string())),
+    }
+}
+
+pub async fn goal_details(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Saving Goal Id").await?;
+
+    match services::get_goal(&id, &state).await {
+        Ok(goal) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            goal,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
+
+pub async fn customer_goals(
+    _req: HttpRequest,
+    params: web::Path<PathParamsModel>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    params
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let path = params.into_inner();
+
+    let id = id_parser(&path.id, "Customer Id").await?;
+
+    match services::get_customer_goals(&id, &state).await {
+        Ok(goals) => Ok(HttpResponse::Ok().json(ApiResponse::success(
+            ApiCode::OperationSuccess,
+            "Successful",
+            goals,
+        ))),
+        Err(_) => Err(ApiError::NotFound),
+    }
+}
@@ -0,0 +1,335 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use entity::sea_orm_active_enums::{
+    ContributionStatus, ContributionType, SavingGoalsStatus, SavingsProductFreq, SavingsProductType,
+};
+use sea_orm::{FromQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DisplayFromStr, serde_as};
+use validator::Validate;
+
+use crate::utils::validators::validate_percentage;
+
+#[derive(Debug, Clone)]
+pub struct CreateProductModel {
+    pub institution_id: i64,
+    pub name: String,
+    pub code: String,
+    pub description: Option<String>,
+    pub savings_type: SavingsProductType,
+    pub min_contribution: Option<i64>,
+    pub max_contribution: Option<i64>,
+    pub default_contribution: Option<i64>,
+    pub contribution_freq: SavingsProductFreq,
+    pub interest_rate: Option<Decimal>,
+    pub lock_period_days: Option<i32>,
+    pub withdrawal_restriction_days: Option<i32>,
+    pub is_early_withdrawal_allowed: bool,
+    pub early_withdrawal_penalty_rate: Option<Decimal>,
+    pub is_target_amount_enabled: bool,
+    pub min_target_amount: Option<i64>,
+    pub max_target_amount: Option<i64>,
+    pub is_group_savings_allowed: bool,
+    pub min_group_members: Option<i32>,
+    pub max_group_members: Option<i32>,
+    pub missed_contribution_penalty: Option<i64>,
+    pub gl_account_id: Option<i64>,
+    pub penalty_income_gl_account_id: Option<i64>,
+    pub deposit_gl_account_id: Option<i64>,
+    pub created_by: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateGoalModel {
+    pub customer_id: i64,
+    pub savings_product_id: i64,
+    pub account_id: i64,
+    pub goal_name: String,
+    pub target_amount: Option<i64>,
+    pub target_completion_date: Option<NaiveDate>,
+    pub contribution_amount: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContributeModel {
+    pub amount: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct WithdrawModel {
+    pub amount: i64,
+    pub withdrawn_by: i64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateProductParams {
+    #[serde(rename = "institutionId")]
+    pub institution_id: String,
+    #[validate(length(min = 2, max = 100, message = "name cannot be < 2 and > 100"))]
+    pub name: String,
+    #[validate(length(min = 2, max = 30, message = "code cannot be < 2 and > 30"))]
+    pub code: String,
+    #[validate(length(min = 2, max = 500, message = "description cannot be < 2 and > 500"))]
+    pub description: Option<String>,
+    #[serde(rename = "savingsType")]
+    pub savings_type: SavingsProductType,
+    #[validate(range(min = 1, message = "minContribution must be greater than 0"))]
+    #[serde(rename = "minContribution")]
+    pub min_contribution: Option<i64>,
+    #[validate(range(min = 1, message = "maxContribution must be greater than 0"))]
+    #[serde(rename = "maxContribution")]
+    pub max_contribution: Option<i64>,
+    #[validate(range(min = 1, message = "defaultContribution must be greater than 0"))]
+    #[serde(rename = "defaultContribution")]
+    pub default_contribution: Option<i64>,
+    #[serde(rename = "contributionFreq")]
+    pub contribution_freq: SavingsProductFreq,
+    #[validate(custom(function = "validate_percentage"))]
+    #[serde(rename = "interestRate")]
+    pub interest_rate: Option<Decimal>,
+    #[validate(range(min = 0, message = "lockPeriodDays cannot be < 0"))]
+    #[serde(rename = "lockPeriodDays")]
+    pub lock_period_days: Option<i32>,
+    #[validate(range(min = 0, message = "withdrawalRestrictionDays cannot be < 0"))]
+    #[serde(rename = "withdrawalRestrictionDays")]
+    pub withdrawal_restriction_days: Option<i32>,
+    #[serde(default, rename = "isEarlyWithdrawalAllowed")]
+    pub is_early_withdrawal_allowed: bool,
+    #[validate(custom(function = "validate_percentage"))]
+    #[serde(rename = "earlyWithdrawalPenaltyRate")]
+    pub early_withdrawal_penalty_rate: Option<Decimal>,
+    #[serde(default, rename = "isTargetAmountEnabled")]
+    pub is_target_amount_enabled: bool,
+    #[validate(range(min = 1, message = "minTargetAmount must be greater than 0"))]
+    #[serde(rename = "minTargetAmount")]
+    pub min_target_amount: Option<i64>,
+    #[validate(range(min = 1, message = "maxTargetAmount must be greater than 0"))]
+    #[serde(rename = "maxTargetAmount")]
+    pub max_target_amount: Option<i64>,
+    #[serde(default, rename = "isGroupSavingsAllowed")]
+    pub is_group_savings_allowed: bool,
+    #[validate(range(min = 2, message = "minGroupMembers cannot be < 2"))]
+    #[serde(rename = "minGroupMembers")]
+    pub min_group_members: Option<i32>,
+    #[validate(range(min = 2, message = "maxGroupMembers cannot be < 2"))]
+    #[serde(rename = "maxGroupMembers")]
+    pub max_group_members: Option<i32>,
+    #[validate(range(min = 0, message = "missedContributionPenalty cannot be < 0"))]
+    #[serde(rename = "missedContributionPenalty")]
+    pub missed_contribution_penalty: Option<i64>,
+    #[serde(rename = "glAccountId")]
+    pub gl_account_id: Option<String>,
+    #[serde(rename = "penaltyIncomeGlAccountId")]
+    pub penalty_income_gl_account_id: Option<String>,
+    #[serde(rename = "depositGlAccountId")]
+    pub deposit_gl_account_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct CreateGoalParams {
+    #[serde(rename = "customerId")]
+    pub customer_id: String,
+    #[serde(rename = "savingsProductId")]
+    pub savings_product_id: String,
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+    #[validate(length(min = 2, max = 100, message = "goalName cannot be < 2 and > 100"))]
+    #[serde(rename = "goalName")]
+    pub goal_name: String,
+    #[validate(range(min = 1, message = "targetAmount must be greater than 0"))]
+    #[serde(rename = "targetAmount")]
+    pub target_amount: Option<i64>,
+    #[serde(rename = "targetCompletionDate")]
+    pub target_completion_date: Option<NaiveDate>,
+    #[validate(range(min = 1, message = "contributionAmount must be greater than 0"))]
+    #[serde(rename = "contributionAmount")]
+    pub contribution_amount: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(deny_unknown_fields)]
+pub struct AmountParams {
+    #[validate(range(min = 1, message = "amount must be greater than 0"))]
+    pub amount: i64,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::savings_products::Entity")]
+pub struct ProductResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "institution_id")]
+    pub institution_id: i64,
+    #[sea_orm(from_col = "name")]
+    pub name: Option<String>,
+    #[sea_orm(from_col = "code")]
+    pub code: Option<String>,
+    #[sea_orm(from_col = "description")]
+    pub description: Option<String>,
+    #[sea_orm(from_col = "savings_type")]
+    pub savings_type: Option<SavingsProductType>,
+    #[sea_orm(from_col = "min_contribution")]
+    pub min_contribution: Option<i64>,
+    #[sea_orm(from_col = "max_contribution")]
+    pub max_contribution: Option<i64>,
+    #[sea_orm(from_col = "default_contribution")]
+    pub default_contribution: Option<i64>,
+    #[sea_orm(from_col = "contribution_freq")]
+    pub contribution_freq: Option<SavingsProductFreq>,
+    #[sea_orm(from_col = "interest_rate")]
+    pub interest_rate: Option<Decimal>,
+    #[sea_orm(from_col = "lock_period_days")]
+    pub lock_period_days: Option<i32>,
+    #[sea_orm(from_col = "withdrawal_restriction_days")]
+    pub withdrawal_restriction_days: Option<i32>,
+    #[sea_orm(from_col = "is_early_withdrawal_allowed")]
+    pub is_early_withdrawal_allowed: Option<bool>,
+    #[sea_orm(from_col = "early_withdrawal_penalty_rate")]
+    pub early_withdrawal_penalty_rate: Option<Decimal>,
+    #[sea_orm(from_col = "is_target_amount_enabled")]
+    pub is_target_amount_enabled: Option<bool>,
+    #[sea_orm(from_col = "min_target_amount")]
+    pub min_target_amount: Option<i64>,
+    #[sea_orm(from_col = "max_target_amount")]
+    pub max_target_amount: Option<i64>,
+    #[sea_orm(from_col = "is_group_savings_allowed")]
+    pub is_group_savings_allowed: Option<bool>,
+    #[sea_orm(from_col = "min_group_members")]
+    pub min_group_members: Option<i32>,
+    #[sea_orm(from_col = "max_group_members")]
+    pub max_group_members: Option<i32>,
+    #[sea_orm(from_col = "missed_contribution_penalty")]
+    pub missed_contribution_penalty: Option<i64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "gl_account_id")]
+    pub gl_account_id: Option<i64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "penalty_income_gl_account_id")]
+    pub penalty_income_gl_account_id: Option<i64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "deposit_gl_account_id")]
+    pub deposit_gl_account_id: Option<i64>,
+    #[sea_orm(from_col = "is_active")]
+    pub is_active: Option<bool>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::saving_goals::Entity")]
+pub struct GoalResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "customer_id")]
+    pub customer_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "savings_product_id")]
+    pub savings_product_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "account_id")]
+    pub account_id: i64,
+    #[sea_orm(from_col = "goal_name")]
+    pub goal_name: Option<String>,
+    #[sea_orm(from_col = "target_amount")]
+    pub target_amount: Option<i64>,
+    #[sea_orm(from_col = "current_amount")]
+    pub current_amount: Option<i64>,
+    #[sea_orm(from_col = "start_date")]
+    pub start_date: NaiveDate,
+    #[sea_orm(from_col = "target_completion_date")]
+    pub target_completion_date: Option<NaiveDate>,
+    #[sea_orm(from_col = "contribution_amount")]
+    pub contribution_amount: Option<i64>,
+    #[sea_orm(from_col = "contribution_freq")]
+    pub contribution_freq: Option<SavingsProductFreq>,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<SavingGoalsStatus>,
+    #[sea_orm(from_col = "completion_date")]
+    pub completion_date: Option<NaiveDate>,
+    #[sea_orm(from_col = "progress_percentage")]
+    pub progress_percentage: Option<i32>,
+    #[sea_orm(from_col = "is_group_savings")]
+    pub is_group_savings: Option<bool>,
+    #[sea_orm(from_col = "custom_fields")]
+    pub custom_fields: Option<Value>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::contributions::Entity")]
+pub struct ContributionResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "saving_goal_id")]
+    pub saving_goal_id: Option<i64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "account_id")]
+    pub account_id: Option<i64>,
+    #[sea_orm(from_col = "contribution_date")]
+    pub contribution_date: Option<NaiveDate>,
+    #[sea_orm(from_col = "amount")]
+    pub amount: i64,
+    #[sea_orm(from_col = "contribution_type")]
+    pub contribution_type: Option<ContributionType>,
+    #[sea_orm(from_col = "contribution_reference")]
+    pub contribution_reference: Option<String>,
+    #[sea_orm(from_col = "status")]
+    pub status: Option<ContributionStatus>,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, FromQueryResult, DerivePartialModel)]
+#[sea_orm(entity = "entity::saving_goal_withdrawals::Entity")]
+pub struct WithdrawalResponseModel {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "_id")]
+    #[sea_orm(from_col = "id")]
+    pub id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "saving_goal_id")]
+    pub saving_goal_id: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[sea_orm(from_col = "account_id")]
+    pub account_id: i64,
+    #[sea_orm(from_col = "amount")]
+    pub amount: i64,
+    #[sea_orm(from_col = "penalty_amount")]
+    pub penalty_amount: i64,
+    #[sea_orm(from_col = "net_amount")]
+    pub net_amount: i64,
+    #[sea_orm(from_col = "is_early_withdrawal")]
+    pub is_early_withdrawal: bool,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[sea_orm(from_col = "customer_id")]
+    pub customer_id: Option<i64>,
+    #[sea_orm(from_col = "reference_number")]
+    pub reference_number: String,
+    #[sea_orm(from_col = "created_at")]
+    pub created_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GoalDetailsModel {
+    pub goal: GoalResponseModel,
+    pub locked_until: NaiveDate,
+    pub is_locked: bool,
+    pub contributions: Vec<ContributionResponseModel>,
+    pub withdrawals: Vec<WithdrawalResponseModel>,
+}
@@ -0,0 +1,51 @@
+use actix_web::{middleware::from_fn, web};
+
+use crate::{AppState, app::savings::controllers, middlewares::jwt::jwt_auth};
+
+pub fn init(cfg: &mut web::ServiceConfig, _state: web::Data<AppState>) {
+    cfg.service(
+        web::scope("/v1/savings")
+            .route(
+                "/products",
+                web::post()
+                    .to(controllers::create_product)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/institutions/{id}/products",
+                web::get()
+                    .to(controllers::institution_products)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/goals",
+                web::post()
+                    .to(controllers::create_goal)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/goals/{id}",
+                web::get()
+                    .to(controllers::goal_details)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/goals/{id}/contributions",
+                web::post()
+                    .to(controllers::contribute)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/goals/{id}/withdrawals",
+                web::post()
+                    .to(controllers::withdraw)
+                    .wrap(from_fn(jwt_auth)),
+            )
+            .route(
+                "/customers/{id}/goals",
+                web::get()
+                    .to(controllers::customer_goals)
+                    .wrap(from_fn(jwt_auth)),
+            ),
+    );
+}
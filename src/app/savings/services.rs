@@ -0,0 +1,813 @@
+use actix_web::web;
+use chrono::{Duration, NaiveDate};
+use entity::sea_orm_active_enums::{
+    AccTypeStatus, ContributionStatus, ContributionType, SavingGoalsStatus,
+};
+use migration::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+
+use crate::{
+    AppState,
+    app::{
+        contribution_cycles::services::current_cycle_id,
+        ledger::{self, models::GlEntryModel},
+        savings::models::{
+            ContributeModel, ContributionResponseModel, CreateGoalModel, CreateProductModel,
+            GoalDetailsModel, GoalResponseModel, ProductResponseModel, WithdrawModel,
+            WithdrawalResponseModel,
+        },
+    },
+    utils::{
+        finance::{percent_of, progress_percentage},
+        gen_snow_ids::gen_snowflake_slug,
+    },
+};
+
+pub async fn create_product(
+    model: &CreateProductModel,
+    state: &web::Data<AppState>,
+) -> Result<ProductResponseModel, DbErr> {
+    let data = model.clone();
+
+    if let (Some(min), Some(max)) = (data.min_contribution, data.max_contribution)
+        && min > max
+    {
+        return Err(DbErr::Custom(
+            "minContribution cannot be greater than maxContribution".to_string(),
+        ));
+    }
+
+    if let (Some(min), Some(max)) = (data.min_target_amount, data.max_target_amount)
+        && min > max
+    {
+        return Err(DbErr::Custom(
+            "minTargetAmount cannot be greater than maxTargetAmount".to_string(),
+        ));
+    }
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::savings_products::ActiveModel {
+        id: Set(id),
+        institution_id: Set(data.institution_id),
+        name: Set(Some(data.name)),
+        code: Set(Some(data.code)),
+        description: Set(data.description),
+        savings_type: Set(Some(data.savings_type)),
+        min_contribution: Set(data.min_contribution),
+        max_contribution: Set(data.max_contribution),
+        default_contribution: Set(data.default_contribution),
+        contribution_freq: Set(Some(data.contribution_freq)),
+        interest_rate: Set(data.interest_rate),
+        lock_period_days: Set(data.lock_period_days),
+        withdrawal_restriction_days: Set(data.withdrawal_restriction_days),
+        is_early_withdrawal_allowed: Set(Some(data.is_early_withdrawal_allowed)),
+        early_withdrawal_penalty_rate: Set(data.early_withdrawal_penalty_rate),
+        is_target_amount_enabled: Set(Some(data.is_target_amount_enabled)),
+        min_target_amount: Set(data.min_target_amount),
+        max_target_amount: Set(data.max_target_amount),
+        is_group_savings_allowed: Set(Some(data.is_group_savings_allowed)),
+        min_group_members: Set(data.min_group_members),
+        max_group_members: Set(data.max_group_members),
+        missed_contribution_penalty: Set(data.missed_contribution_penalty),
+        gl_account_id: Set(data.gl_account_id),
+        penalty_income_gl_account_id: Set(data.penalty_income_gl_account_id),
+        deposit_gl_account_id: Set(data.deposit_gl_account_id),
+        is_active: Set(Some(true)),
+        created_by: Set(Some(data.created_by)),
+        ..Default::default()
+    }
+    .insert(state.pgdb.get_ref())
+    .await?;
+
+    entity::savings_products::Entity::find_by_id(id)
+        .into_model::<ProductResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Savings product not found".into()))
+}
+
+pub async fn get_products(
+    institution_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<ProductResponseModel>, DbErr> {
+    let products = entity::savings_products::Entity::find()
+        .filter(entity::savings_products::Column::InstitutionId.eq(*institution_id))
+        .order_by_asc(entity::savings_products::Column::Name)
+        .into_model::<ProductResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(products)
+}
+
+// Goal savings are held under the product's GL account, funded from the
+// deposit GL behind customer accounts; None until both are configured
+fn savings_gl_accounts(product: &entity::savings_products::Model) -> Option<(i64, i64)> {
+    product.deposit_gl_account_id.zip(product.gl_account_id)
+}
+
+// Moves a payout out of the savings GL back to customer deposits
+pub async fn post_payout<C: ConnectionTrait>(
+    conn: &C,
+    product: &entity::savings_products::Model,
+    amount: i64,
+    narration: String,
+    reference_number: String,
+    posted_by: Option<i64>,
+) -> Result<(), DbErr> {
+    if amount <= 0 {
+        return Ok(());
+    }
+
+    let Some((deposit_gl, savings_gl)) = savings_gl_accounts(product) else {
+        return Ok(());
+    };
+
+    ledger::services::post(
+        conn,
+        &GlEntryModel {
+            institution_id: product.institution_id,
+            debit_account_id: savings_gl,
+            credit_account_id: deposit_gl,
+            amount,
+            narration,
+            reference_number: Some(reference_number),
+            transaction_id: None,
+            value_date: chrono::Utc::now().date_naive(),
+            posted_by,
+        },
+    )
+    .await?;
+
+    Ok(())
+}
+
+// A penalty stays with the institution as income, out of the savings held
+// under the product's GL account; the product must post its contributions
+// and have a penalty income account to charge one
+pub async fn post_penalty<C: ConnectionTrait>(
+    conn: &C,
+    product: &entity::savings_products::Model,
+    amount: i64,
+    narration: String,
+    reference_number: String,
+    posted_by: Option<i64>,
+) -> Result<(), DbErr> {
+    if amount <= 0 {
+        return Ok(());
+    }
+
+    let (Some((_, savings_gl)), Some(income_gl)) = (
+        savings_gl_accounts(product),
+        product.penalty_income_gl_account_id,
+    ) else {
+        return Err(DbErr::Custom(
+            "Deposit, savings and penalty income GL accounts must be configured on the product to charge a penalty"
+                .to_string(),
+        ));
+    };
+
+    ledger::services::post(
+        conn,
+        &GlEntryModel {
+            institution_id: product.institution_id,
+            debit_account_id: savings_gl,
+            credit_account_id: income_gl,
+            amount,
+            narration,
+            reference_number: Some(reference_number),
+            transaction_id: None,
+            value_date: chrono::Utc::now().date_naive(),
+            posted_by,
+        },
+    )
+    .await?;
+
+    Ok(())
+}
+
+// Debits only when the account is active and has the funds available;
+// no row is updated otherwise
+pub async fn debit_account<C: ConnectionTrait>(
+    conn: &C,
+    account_id: i64,
+    amount: i64,
+) -> Result<(), DbErr> {
+    let result = entity::accounts::Entity::update_many()
+        .col_expr(
+            entity::accounts::Column::CurrentBalance,
+            Expr::col(entity::accounts::Column::CurrentBalance)
+                .if_null(0)
+                .sub(amount),
+        )
+        .col_expr(
+            entity::accounts::Column::AvailableBalance,
+            Expr::col(entity::accounts::Column::AvailableBalance)
+                .if_null(0)
+                .sub(amount),
+        )
+        .col_expr(
+            entity::accounts::Column::LedgerBalance,
+            Expr::col(entity::accounts::Column::LedgerBalance)
+                .if_null(0)
+                .sub(amount),
+        )
+        .col_expr(
+            entity::accounts::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(
+            Condition::all()
+                .add(entity::accounts::Column::Id.eq(account_id))
+                .add(entity::accounts::Column::Status.eq(AccTypeStatus::Active))
+                .add(entity::accounts::Column::AvailableBalance.gte(amount)),
+        )
+        .exec(conn)
+        .await?;
+
+    if result.rows_affected == 0 {
+        return Err(DbErr::Custom(
+            "Insufficient funds or account is not active".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+pub async fn credit_account<C: ConnectionTrait>(
+    conn: &C,
+    account_id: i64,
+    amount: i64,
+) -> Result<(), DbErr> {
+    let result = entity::accounts::Entity::update_many()
+        .col_expr(
+            entity::accounts::Column::CurrentBalance,
+            Expr::col(entity::accounts::Column::CurrentBalance)
+                .if_null(0)
+                .add(amount),
+        )
+        .col_expr(
+            entity::accounts::Column::AvailableBalance,
+            Expr::col(entity::accounts::Column::AvailableBalance)
+                .if_null(0)
+                .add(amount),
+        )
+        .col_expr(
+            entity::accounts::Column::LedgerBalance,
+            Expr::col(entity::accounts::Column::LedgerBalance)
+                .if_null(0)
+                .add(amount),
+        )
+        .col_expr(
+            entity::accounts::Column::UpdatedAt,
+            Expr::value(chrono::Utc::now()),
+        )
+        .filter(
+            Condition::all()
+                .add(entity::accounts::Column::Id.eq(account_id))
+                .add(entity::accounts::Column::Status.eq(AccTypeStatus::Active)),
+        )
+        .exec(conn)
+        .await?;
+
+    if result.rows_affected == 0 {
+        return Err(DbErr::Custom("Account is not active".to_string()));
+    }
+
+    Ok(())
+}
+
+// Savings stay locked for lock_period_days from the goal start date
+pub fn locked_until(
+    goal: &entity::saving_goals::Model,
+    product: &entity::savings_products::Model,
+) -> NaiveDate {
+    goal.start_date + Duration::days(i64::from(product.lock_period_days.unwrap_or(0).max(0)))
+}
+
+// Inside the lock period a withdrawal is refused unless the product allows
+// early withdrawal; the penalty is then a share of the amount withdrawn
+pub fn withdrawal_penalty(
+    goal: &entity::saving_goals::Model,
+    product: &entity::savings_products::Model,
+    amount: i64,
+    today: NaiveDate,
+) -> Result<(bool, i64), DbErr> {
+    let locked_until = locked_until(goal, product);
+
+    if today >= locked_until {
+        return Ok((false, 0));
+    }
+
+    if !product.is_early_withdrawal_allowed.unwrap_or(false) {
+        return Err(DbErr::Custom(format!(
+            "Savings are locked until {}",
+            locked_until
+        )));
+    }
+
+    let penalty_amount = product
+        .early_withdrawal_penalty_rate
+        .map(|rate| percent_of(amount, rate))
+        .unwrap_or(0)
+        .min(amount);
+
+    Ok((true, penalty_amount))
+}
+
+pub async fn find_product<C: ConnectionTrait>(
+    conn: &C,
+    savings_product_id: i64,
+) -> Result<entity::savings_products::Model, DbErr> {
+    entity::savings_products::Entity::find_by_id(savings_product_id)
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Savings product not found".into()))
+}
+
+// Holds the goal row for the rest of the transaction so contributions,
+// payouts and penalties on it apply one after another
+pub async fn lock_goal<C: ConnectionTrait>(
+    conn: &C,
+    saving_goal_id: i64,
+) -> Result<entity::saving_goals::Model, DbErr> {
+    entity::saving_goals::Entity::find_by_id(saving_goal_id)
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Saving goal not found".into()))
+}
+
+pub async fn ensure_customer_account<C: ConnectionTrait>(
+    conn: &C,
+    customer_id: i64,
+    account_id: i64,
+) -> Result<(), DbErr> {
+    entity::accounts::Entity::find_by_id(account_id)
+        .filter(
+            Condition::all()
+                .add(entity::accounts::Column::CustomerId.eq(customer_id))
+                .add(entity::accounts::Column::Status.eq(AccTypeStatus::Active)),
+        )
+        .one(conn)
+        .await?
+        .ok_or_else(|| DbErr::Custom("Customer has no active account with this id".to_string()))?;
+
+    Ok(())
+}
+
+pub async fn create_goal(
+    model: &CreateGoalModel,
+    state: &web::Data<AppState>,
+) -> Result<GoalResponseModel, DbErr> {
+    let data = model.clone();
+
+    let product = find_product(state.pgdb.get_ref(), data.savings_product_id).await?;
+
+    if !product.is_active.unwrap_or(true) {
+        return Err(DbErr::Custom("Savings product is not active".to_string()));
+    }
+
+    let customer = entity::customers::Entity::find_by_id(data.customer_id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Customer not found".into()))?;
+
+    if customer.institution_id != product.institution_id {
+        return Err(DbErr::Custom(
+            "Savings product is not offered to this customer".to_string(),
+        ));
+    }
+
+    ensure_customer_account(state.pgdb.get_ref(), customer.id, data.account_id).await?;
+
+    ensure_target_bounds(&product, data.target_amount)?;
+
+    let contribution_amount = data.contribution_amount.or(product.default_contribution);
+
+    if let Some(amount) = contribution_amount {
+        ensure_contribution_bounds(&product, amount)?;
+    }
+
+    let today = chrono::Utc::now().date_naive();
+
+    if data
+        .target_completion_date
+        .is_some_and(|date| date <= today)
+    {
+        return Err(DbErr::Custom(
+            "targetCompletionDate must be in the future".to_string(),
+        ));
+    }
+
+    let (id, _) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    entity::saving_goals::ActiveModel {
+        id: Set(id),
+        institution_id: Set(product.institution_id),
+        customer_id: Set(customer.id),
+        savings_product_id: Set(product.id),
+        account_id: Set(data.account_id),
+        goal_name: Set(Some(data.goal_name)),
+        target_amount: Set(data.target_amount),
+        current_amount: Set(Some(0)),
+        start_date: Set(today),
+        target_completion_date: Set(data.target_completion_date),
+        contribution_amount: Set(contribution_amount),
+        contribution_freq: Set(product.contribution_freq.clone()),
+        status: Set(Some(SavingGoalsStatus::Active)),
+        progress_percentage: Set(Some(0)),
+        is_group_savings: Set(Some(false)),
+        ..Default::default()
+    }
+    .insert(state.pgdb.get_ref())
+    .await?;
+
+    find_goal(&id, state).await
+}
+
+// Target-enabled products require a target inside the product bounds
+pub fn ensure_target_bounds(
+    product: &entity::savings_products::Model,
+    target_amount: Option<i64>,
+) -> Result<(), DbErr> {
+    if product.is_target_amount_enabled.unwrap_or(false) {
+        let Some(target) = target_amount else {
+            return Err(DbErr::Custom(
+                "targetAmount is required for this savings product".to_string(),
+            ));
+        };
+
+        if product.min_target_amount.is_some_and(|min| target < min)
+            || product.max_target_amount.is_some_and(|max| target > max)
+        {
+            return Err(DbErr::Custom(format!(
+                "targetAmount must be between {} and {}",
+                product.min_target_amount.unwrap_or(1),
+                product
+                    .max_target_amount
+                    .map_or("no limit".to_string(), |max| max.to_string())
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn ensure_contribution_bounds(
+    product: &entity::savings_products::Model,
+    amount: i64,
+) -> Result<(), DbErr> {
+    if product.min_contribution.is_some_and(|min| amount < min) {
+        return Err(DbErr::Custom(format!(
+            "Contribution cannot be less than {}",
+            product.min_contribution.unwrap_or(0)
+        )));
+    }
+
+    if product.max_contribution.is_some_and(|max| amount > max) {
+        return Err(DbErr::Custom(format!(
+            "Contribution cannot be more than {}",
+            product.max_contribution.unwrap_or(0)
+        )));
+    }
+
+    Ok(())
+}
+
+// Debits the paying account into the goal, tags the contribution with the
+// product's open cycle and moves the goal's progress on
+pub async fn record_contribution<C: ConnectionTrait>(
+    conn: &C,
+    saving_goal_id: i64,
+    customer_id: i64,
+    account_id: i64,
+    amount: i64,
+) -> Result<i64, DbErr> {
+    let goal = lock_goal(conn, saving_goal_id).await?;
+
+    if goal.status != Some(SavingGoalsStatus::Active) {
+        return Err(DbErr::Custom(
+            "Contributions can only be made to active goals".to_string(),
+        ));
+    }
+
+    let (contribution_id, slug) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    let today = chrono::Utc::now().date_naive();
+
+    let cycle_id = current_cycle_id(conn, goal.savings_product_id, today).await?;
+
+    debit_account(conn, account_id, amount).await?;
+
+    let product = find_product(conn, goal.savings_product_id).await?;
+
+    if let Some((deposit_gl, savings_gl)) = savings_gl_accounts(&product) {
+        ledger::services::post(
+            conn,
+            &GlEntryModel {
+                institution_id: goal.institution_id,
+                debit_account_id: deposit_gl,
+                credit_account_id: savings_gl,
+                amount,
+                narration: format!("Contribution to saving goal {}", goal.id),
+                reference_number: Some(slug.clone()),
+                transaction_id: None,
+                value_date: today,
+                posted_by: None,
+            },
+        )
+        .await?;
+    }
+
+    entity::contributions::ActiveModel {
+        id: Set(contribution_id),
+        institution_id: Set(goal.institution_id),
+        saving_goal_id: Set(Some(goal.id)),
+        customer_id: Set(customer_id),
+        contribution_cycle_id: Set(cycle_id),
+        account_id: Set(Some(account_id)),
+        contribution_date: Set(Some(today)),
+        amount: Set(amount),
+        contribution_type: Set(Some(ContributionType::Regular)),
+        contribution_reference: Set(Some(slug)),
+        is_missed_contribution: Set(Some(false)),
+        status: Set(Some(ContributionStatus::Completed)),
+        ..Default::default()
+    }
+    .insert(conn)
+    .await?;
+
+    let current_amount = goal.current_amount.unwrap_or(0) + amount;
+    let target_amount = goal.target_amount;
+
+    let mut active_goal: entity::saving_goals::ActiveModel = goal.into();
+
+    active_goal.current_amount = Set(Some(current_amount));
+
+    if let Some(target) = target_amount {
+        active_goal.progress_percentage = Set(Some(progress_percentage(current_amount, target)));
+
+        if current_amount >= target {
+            active_goal.status = Set(Some(SavingGoalsStatus::Completed));
+            active_goal.completion_date = Set(Some(today));
+        }
+    }
+
+    active_goal.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_goal.update(conn).await?;
+
+    Ok(contribution_id)
+}
+
+// Moves funds from the customer's account into the goal and records the
+// contribution; the goal completes once the target is reached
+pub async fn contribute(
+    id: &i64,
+    model: &ContributeModel,
+    state: &web::Data<AppState>,
+) -> Result<ContributionResponseModel, DbErr> {
+    let data = model.clone();
+
+    let goal = entity::saving_goals::Entity::find_by_id(*id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Saving goal not found".into()))?;
+
+    if goal.is_group_savings.unwrap_or(false) {
+        return Err(DbErr::Custom(
+            "Group goals take contributions through their members".to_string(),
+        ));
+    }
+
+    if goal.status != Some(SavingGoalsStatus::Active) {
+        return Err(DbErr::Custom(
+            "Contributions can only be made to active goals".to_string(),
+        ));
+    }
+
+    let product = find_product(state.pgdb.get_ref(), goal.savings_product_id).await?;
+
+    ensure_contribution_bounds(&product, data.amount)?;
+
+    let txn = state.pgdb.begin().await?;
+
+    let contribution_id = record_contribution(
+        &txn,
+        goal.id,
+        goal.customer_id,
+        goal.account_id,
+        data.amount,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    entity::contributions::Entity::find_by_id(contribution_id)
+        .into_model::<ContributionResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Contribution not found".into()))
+}
+
+// Pays out of a personal goal into its linked account; any early withdrawal
+// penalty is kept from the payout
+pub async fn withdraw(
+    id: &i64,
+    model: &WithdrawModel,
+    state: &web::Data<AppState>,
+) -> Result<WithdrawalResponseModel, DbErr> {
+    let data = model.clone();
+
+    let txn = state.pgdb.begin().await?;
+
+    let goal = lock_goal(&txn, *id).await?;
+
+    if goal.is_group_savings.unwrap_or(false) {
+        return Err(DbErr::Custom(
+            "Group goals pay out through member exits".to_string(),
+        ));
+    }
+
+    if !matches!(
+        goal.status,
+        Some(SavingGoalsStatus::Active)
+            | Some(SavingGoalsStatus::Paused)
+            | Some(SavingGoalsStatus::Completed)
+    ) {
+        return Err(DbErr::Custom(
+            "Withdrawals cannot be made from this goal".to_string(),
+        ));
+    }
+
+    let current_amount = goal.current_amount.unwrap_or(0);
+
+    if data.amount > current_amount {
+        return Err(DbErr::Custom(format!(
+            "Withdrawal cannot exceed the saved amount of {}",
+            current_amount
+        )));
+    }
+
+    let product = find_product(&txn, goal.savings_product_id).await?;
+
+    let now = chrono::Utc::now();
+    let today = now.date_naive();
+
+    // Consecutive withdrawals must be withdrawal_restriction_days apart
+    if let Some(days) = product.withdrawal_restriction_days.filter(|days| *days > 0) {
+        let last = entity::saving_goal_withdrawals::Entity::find()
+            .filter(entity::saving_goal_withdrawals::Column::SavingGoalId.eq(goal.id))
+            .order_by_desc(entity::saving_goal_withdrawals::Column::CreatedAt)
+            .one(&txn)
+            .await?;
+
+        if let Some(created_at) = last.and_then(|withdrawal| withdrawal.created_at)
+            && (now - created_at.to_utc()).num_days() < i64::from(days)
+        {
+            return Err(DbErr::Custom(format!(
+                "Only one withdrawal is allowed every {} days",
+                days
+            )));
+        }
+    }
+
+    let (is_early_withdrawal, penalty_amount) =
+        withdrawal_penalty(&goal, &product, data.amount, today)?;
+    let net_amount = data.amount - penalty_amount;
+
+    let (withdrawal_id, slug) = match gen_snowflake_slug() {
+        Ok(res) => res,
+        Err(_) => return Err(DbErr::Custom("Failed to generate ID's".to_string())),
+    };
+
+    if net_amount > 0 {
+        credit_account(&txn, goal.account_id, net_amount).await?;
+    }
+
+    post_payout(
+        &txn,
+        &product,
+        net_amount,
+        format!("Withdrawal from saving goal {}", goal.id),
+        slug.clone(),
+        Some(data.withdrawn_by),
+    )
+    .await?;
+
+    post_penalty(
+        &txn,
+        &product,
+        penalty_amount,
+        format!("Early withdrawal penalty on saving goal {}", goal.id),
+        format!("SWP-{}", slug),
+        Some(data.withdrawn_by),
+    )
+    .await?;
+
+    entity::saving_goal_withdrawals::ActiveModel {
+        id: Set(withdrawal_id),
+        saving_goal_id: Set(goal.id),
+        account_id: Set(goal.account_id),
+        amount: Set(data.amount),
+        penalty_amount: Set(penalty_amount),
+        net_amount: Set(net_amount),
+        is_early_withdrawal: Set(is_early_withdrawal),
+        reference_number: Set(slug),
+        withdrawn_by: Set(Some(data.withdrawn_by)),
+        customer_id: Set(Some(goal.customer_id)),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await?;
+
+    let remaining = current_amount - data.amount;
+    let target_amount = goal.target_amount;
+
+    let mut active_goal: entity::saving_goals::ActiveModel = goal.into();
+
+    active_goal.current_amount = Set(Some(remaining));
+
+    if let Some(target) = target_amount {
+        active_goal.progress_percentage = Set(Some(progress_percentage(remaining, target)));
+    }
+
+    active_goal.updated_at = Set(Some(chrono::Utc::now().into()));
+    active_goal.update(&txn).await?;
+
+    txn.commit().await?;
+
+    entity::saving_goal_withdrawals::Entity::find_by_id(withdrawal_id)
+        .into_model::<WithdrawalResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Withdrawal not found".into()))
+}
+
+async fn find_goal(id: &i64, state: &web::Data<AppState>) -> Result<GoalResponseModel, DbErr> {
+    entity::saving_goals::Entity::find_by_id(*id)
+        .into_model::<GoalResponseModel>()
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Saving goal not found".into()))
+}
+
+pub async fn get_goal(id: &i64, state: &web::Data<AppState>) -> Result<GoalDetailsModel, DbErr> {
+    let goal = entity::saving_goals::Entity::find_by_id(*id)
+        .one(state.pgdb.get_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Saving goal not found".into()))?;
+
+    let product = find_product(state.pgdb.get_ref(), goal.savings_product_id).await?;
+
+    let locked_until = locked_until(&goal, &product);
+
+    let contributions = entity::contributions::Entity::find()
+        .filter(entity::contributions::Column::SavingGoalId.eq(*id))
+        .order_by_desc(entity::contributions::Column::CreatedAt)
+        .into_model::<ContributionResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    let withdrawals = entity::saving_goal_withdrawals::Entity::find()
+        .filter(entity::saving_goal_withdrawals::Column::SavingGoalId.eq(*id))
+        .order_by_desc(entity::saving_goal_withdrawals::Column::CreatedAt)
+        .into_model::<WithdrawalResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(GoalDetailsModel {
+        goal: find_goal(id, state).await?,
+        locked_until,
+        is_locked: chrono::Utc::now().date_naive() < locked_until,
+        contributions,
+        withdrawals,
+    })
+}
+
+pub async fn get_customer_goals(
+    customer_id: &i64,
+    state: &web::Data<AppState>,
+) -> Result<Vec<GoalResponseModel>, DbErr> {
+    let goals = entity::saving_goals::Entity::find()
+        .filter(entity::saving_goals::Column::CustomerId.eq(*customer_id))
+        .order_by_desc(entity::saving_goals::Column::CreatedAt)
+        .into_model::<GoalResponseModel>()
+        .all(state.pgdb.get_ref())
+        .await?;
+
+    Ok(goals)
+}
@@ -14,7 +14,7 @@ pub mod setup;
 pub mod utils;
 
 use crate::middlewares::request_id::request_id;
-use crate::setup::init_system;
+use crate::setup::{cron, init_system};
 use crate::setup::postgres::pgdb;
 use crate::{app::app_routes, middlewares::helmet::security_headers};
 
@@ -54,6 +54,8 @@ pub async fn start_server() -> Result<(), std::io::Error> {
 
     log::info!("🚀 Server starting at {}", addr);
 
+    cron::init(state.clone());
+
     let server = HttpServer::new(move || {
         let cors = init_system::configure_cors();
 
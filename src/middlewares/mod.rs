@@ -1,4 +1,5 @@
 pub mod cors;
 pub mod helmet;
 pub mod jwt;
+pub mod rate_limit;
 pub mod request_id;
\ No newline at end of file
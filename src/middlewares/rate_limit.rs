@@ -0,0 +1,75 @@
+use actix_web::{
+    Error,
+    dev::{ServiceRequest, ServiceResponse},
+    middleware::Next,
+    web::Data,
+};
+use once_cell::sync::Lazy;
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{AppState, utils::errors::ApiError};
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+// Fixed one-minute window per client address, shared by all public routes
+static HITS: Lazy<Mutex<HashMap<String, (Instant, u32)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+pub async fn rate_limit(
+    req: ServiceRequest,
+    next: Next<impl actix_web::body::MessageBody>,
+) -> Result<ServiceResponse<impl actix_web::body::MessageBody>, Error> {
+    let (limit, behind_proxy) = match req.app_data::<Data<AppState>>() {
+        Some(state) => (
+            state
+                .config
+                .get::<u32>("rate_limit.public_requests_per_minute")
+                .unwrap_or(30),
+            state
+                .config
+                .get::<bool>("network.behind_proxy")
+                .unwrap_or(false),
+        ),
+        None => (30, false),
+    };
+
+    let client = {
+        let info = req.connection_info();
+
+        if behind_proxy {
+            info.realip_remote_addr().map(str::to_string)
+        } else {
+            info.peer_addr().map(str::to_string)
+        }
+    }
+    .unwrap_or_else(|| "unknown".to_string());
+
+    {
+        let mut hits = HITS.lock().map_err(|_| ApiError::InternalServerError)?;
+        let now = Instant::now();
+
+        if hits.len() > 10_000 {
+            hits.retain(|_, (started, _)| now.duration_since(*started) < WINDOW);
+        }
+
+        let entry = hits.entry(client).or_insert((now, 0));
+
+        if now.duration_since(entry.0) >= WINDOW {
+            *entry = (now, 0);
+        }
+
+        if entry.1 >= limit {
+            return Err(ApiError::TooManyRequests.into());
+        }
+
+        entry.1 += 1;
+    }
+
+    let res = next.call(req).await?;
+
+    Ok(res)
+}
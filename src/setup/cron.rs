@@ -0,0 +1,84 @@
+use std::{future::Future, pin::Pin};
+
+use actix_web::web;
+use chrono::{NaiveTime, Utc};
+use sea_orm::DbErr;
+
+use crate::{
+    AppState,
+    app::{
+        agent_performance, agent_settlements, agent_transactions, contribution_cycles,
+        loan_provisioning, loans,
+    },
+};
+
+type JobFuture = Pin<Box<dyn Future<Output = Result<u64, DbErr>> + Send>>;
+type Job = fn(web::Data<AppState>) -> JobFuture;
+
+// Run sequentially, in this order, once a day
+const DAILY_JOBS: &[(&str, Job)] = &[
+    ("loan_penalties", |state| {
+        Box::pin(async move { loans::services::apply_late_penalties(&state).await })
+    }),
+    ("loan_ageing", |state| {
+        Box::pin(async move { loan_provisioning::services::age_loans(&state).await })
+    }),
+    ("loan_interest_accrual", |state| {
+        Box::pin(async move { loans::services::accrue_interest(&state).await })
+    }),
+    ("savings_contribution_cycles", |state| {
+        Box::pin(async move { contribution_cycles::services::run_cycles(&state).await })
+    }),
+    ("agent_daily_counters", |state| {
+        Box::pin(async move { agent_transactions::services::reset_daily_counters(&state).await })
+    }),
+    ("agent_settlements", |state| {
+        Box::pin(async move { agent_settlements::services::run_daily_settlements(&state).await })
+    }),
+    ("agent_performance", |state| {
+        Box::pin(async move { agent_performance::services::run_daily_performance(&state).await })
+    }),
+];
+
+pub fn init(state: web::Data<AppState>) {
+    if !state
+        .config
+        .get::<bool>("cron.enable_cron")
+        .unwrap_or(false)
+    {
+        return;
+    }
+
+    let run_at = state
+        .config
+        .get_string("cron.daily_run_at")
+        .ok()
+        .and_then(|time| NaiveTime::parse_from_str(&time, "%H:%M").ok())
+        .unwrap_or(NaiveTime::MIN);
+
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(until_next(run_at)).await;
+
+            for (name, job) in DAILY_JOBS {
+                match job(state.clone()).await {
+                    Ok(count) => {
+                        log::info!("⏱️ {} job completed, {} records processed", name, count)
+                    }
+                    Err(err) => log::error!("{} job failed: {}", name, err),
+                }
+            }
+        }
+    });
+}
+
+fn until_next(run_at: NaiveTime) -> std::time::Duration {
+    let now = Utc::now().naive_utc();
+    let mut next = now.date().and_time(run_at);
+
+    if next <= now {
+        next += chrono::Duration::days(1);
+    }
+
+    (next - now).to_std().unwrap_or_default()
+}
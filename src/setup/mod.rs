@@ -1,2 +1,3 @@
+pub mod cron;
 pub mod init_system;
 pub(crate) mod postgres;
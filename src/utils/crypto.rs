@@ -0,0 +1,60 @@
+use aes_gcm::{
+    Aes256Gcm, KeyInit, Nonce,
+    aead::{Aead, AeadCore, OsRng},
+};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use dotenvy::dotenv;
+
+const NONCE_LEN: usize = 12;
+
+fn encryption_key() -> Result<Vec<u8>, String> {
+    dotenv().ok();
+    let key = std::env::var("ENCRYPTION_KEY").map_err(|_| "ENCRYPTION_KEY not set".to_string())?;
+
+    hex::decode(key).map_err(|_| "ENCRYPTION_KEY must be hex encoded".to_string())
+}
+
+// Secrets are stored as base64(nonce || ciphertext) sealed with AES-256-GCM
+pub fn encrypt_with_key(key: &[u8], plaintext: &str) -> Result<String, String> {
+    let cipher =
+        Aes256Gcm::new_from_slice(key).map_err(|_| "Invalid encryption key".to_string())?;
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_bytes())
+        .map_err(|_| "Failed to encrypt secret".to_string())?;
+
+    let mut sealed = nonce.to_vec();
+    sealed.extend_from_slice(&ciphertext);
+
+    Ok(STANDARD.encode(sealed))
+}
+
+pub fn decrypt_with_key(key: &[u8], sealed: &str) -> Result<String, String> {
+    let cipher =
+        Aes256Gcm::new_from_slice(key).map_err(|_| "Invalid encryption key".to_string())?;
+
+    let bytes = STANDARD
+        .decode(sealed)
+        .map_err(|_| "Secret is not base64 encoded".to_string())?;
+
+    if bytes.len() <= NONCE_LEN {
+        return Err("Secret is too short".to_string());
+    }
+
+    let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Failed to decrypt secret".to_string())?;
+
+    String::from_utf8(plaintext).map_err(|_| "Secret is not valid UTF-8".to_string())
+}
+
+pub fn encrypt_secret(plaintext: &str) -> Result<String, String> {
+    encrypt_with_key(&encryption_key()?, plaintext)
+}
+
+pub fn decrypt_secret(sealed: &str) -> Result<String, String> {
+    decrypt_with_key(&encryption_key()?, sealed)
+}
@@ -0,0 +1,304 @@
+use chrono::{Duration, Months, NaiveDate};
+use entity::sea_orm_active_enums::{
+    AgentCommissionRuleCommType, LoanProductCalcMethod, LoanProductFreq, LoanRepaymentFreq,
+    SavingsProductFreq,
+};
+use sea_orm::prelude::Decimal;
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScheduledInstallment {
+    pub installment_number: i32,
+    pub due_date: NaiveDate,
+    pub principal_due: i64,
+    pub interest_due: i64,
+    pub total_due: i64,
+}
+
+// Rates on products and loans are stored as percentages (5.5 == 5.5%)
+pub fn percent_of(amount: i64, rate: Decimal) -> i64 {
+    let value = Decimal::from(amount) * rate / Decimal::ONE_HUNDRED;
+
+    i64::try_from(value.round()).unwrap_or(0)
+}
+
+// Product fees combine a percentage of the amount with a flat charge
+pub fn fee_amount(amount: i64, rate: Option<Decimal>, flat: Option<i64>) -> i64 {
+    rate.map(|rate| percent_of(amount, rate)).unwrap_or(0) + flat.unwrap_or(0)
+}
+
+// Whole-percent progress towards a target, capped at 100
+pub fn progress_percentage(current: i64, target: i64) -> i32 {
+    if target <= 0 {
+        return 0;
+    }
+
+    i32::try_from((current.max(0) as i128 * 100 / target as i128).min(100)).unwrap_or(100)
+}
+
+pub fn period_days(freq: &LoanRepaymentFreq, tenure_days: i32) -> i32 {
+    match freq {
+        LoanRepaymentFreq::Daily => 1,
+        LoanRepaymentFreq::Weekly => 7,
+        LoanRepaymentFreq::Monthly => 30,
+        LoanRepaymentFreq::Bullet => tenure_days.max(1),
+    }
+}
+
+// Monthly loans count calendar months from `start`, so a one-year tenure
+// is twelve instalments whatever the month lengths
+pub fn installment_count(freq: &LoanRepaymentFreq, start: NaiveDate, tenure_days: i32) -> i32 {
+    let tenure_days = tenure_days.max(1);
+
+    if *freq == LoanRepaymentFreq::Monthly {
+        let maturity = start + Duration::days(i64::from(tenure_days));
+
+        return (1..=tenure_days)
+            .find(|months| due_date(start, freq, tenure_days, *months) >= maturity)
+            .unwrap_or(1);
+    }
+
+    let period = period_days(freq, tenure_days);
+
+    ((tenure_days + period - 1) / period).max(1)
+}
+
+fn due_date(
+    start: NaiveDate,
+    freq: &LoanRepaymentFreq,
+    tenure_days: i32,
+    period: i32,
+) -> NaiveDate {
+    match freq {
+        LoanRepaymentFreq::Monthly => start
+            .checked_add_months(Months::new(period as u32))
+            .unwrap_or(start),
+        _ => start + Duration::days(i64::from(period_days(freq, tenure_days) * period)),
+    }
+}
+
+// Builds an amortised schedule starting one period after `start`.
+// Interest rates are annual; flat loans charge interest on the original
+// principal, reducing/declining balance loans use equal instalments.
+pub fn repayment_schedule(
+    principal: i64,
+    annual_rate: Decimal,
+    method: &LoanProductCalcMethod,
+    freq: &LoanRepaymentFreq,
+    start: NaiveDate,
+    tenure_days: i32,
+) -> Vec<ScheduledInstallment> {
+    let count = installment_count(freq, start, tenure_days);
+
+    let period_rate = match freq {
+        LoanRepaymentFreq::Monthly => annual_rate / Decimal::from(12),
+        _ => annual_rate * Decimal::from(period_days(freq, tenure_days)) / Decimal::from(365),
+    } / Decimal::ONE_HUNDRED;
+
+    let principal_parts = match method {
+        LoanProductCalcMethod::Flat => split_evenly(principal, count),
+        _ => amortise(principal, period_rate, count),
+    };
+
+    let mut balance = principal;
+    let mut schedule = Vec::with_capacity(count as usize);
+
+    for (index, principal_due) in principal_parts.into_iter().enumerate() {
+        let base = match method {
+            LoanProductCalcMethod::Flat => principal,
+            _ => balance,
+        };
+
+        let interest_due = i64::try_from((Decimal::from(base) * period_rate).round()).unwrap_or(0);
+
+        balance -= principal_due;
+
+        schedule.push(ScheduledInstallment {
+            installment_number: index as i32 + 1,
+            due_date: due_date(start, freq, tenure_days, index as i32 + 1),
+            principal_due,
+            interest_due,
+            total_due: principal_due + interest_due,
+        });
+    }
+
+    schedule
+}
+
+// Interest earned on one instalment by `as_of`, spread evenly over the days
+// between the previous due date and this one
+pub fn accrued_interest(
+    interest_due: i64,
+    period_start: NaiveDate,
+    due_date: NaiveDate,
+    as_of: NaiveDate,
+) -> i64 {
+    if as_of >= due_date {
+        return interest_due;
+    }
+
+    if as_of <= period_start {
+        return 0;
+    }
+
+    let elapsed = (as_of - period_start).num_days();
+    let period = (due_date - period_start).num_days().max(1);
+
+    let value = Decimal::from(interest_due) * Decimal::from(elapsed) / Decimal::from(period);
+
+    i64::try_from(value.round()).unwrap_or(0)
+}
+
+// Actual/365 simple interest on `principal` for `days`
+pub fn simple_interest(principal: i64, annual_rate: Decimal, days: i64) -> i64 {
+    if days <= 0 {
+        return 0;
+    }
+
+    let value = Decimal::from(principal) * annual_rate * Decimal::from(days)
+        / Decimal::from(365)
+        / Decimal::ONE_HUNDRED;
+
+    i64::try_from(value.round()).unwrap_or(0)
+}
+
+// When interest accrued up to `last` is next due to be recognised; bullet
+// products recognise everything at maturity
+pub fn next_accrual_date(
+    freq: &LoanProductFreq,
+    last: NaiveDate,
+    maturity: NaiveDate,
+) -> NaiveDate {
+    let next = match freq {
+        LoanProductFreq::Daily => last + Duration::days(1),
+        LoanProductFreq::Weekly => last + Duration::days(7),
+        LoanProductFreq::Monthly => last.checked_add_months(Months::new(1)).unwrap_or(last),
+        LoanProductFreq::Bullet => maturity,
+    };
+
+    next.min(maturity)
+}
+
+// Last day of a contribution cycle opened on `start`
+pub fn cycle_end_date(freq: &SavingsProductFreq, start: NaiveDate) -> NaiveDate {
+    match freq {
+        SavingsProductFreq::Daily => start,
+        SavingsProductFreq::Weekly => start + Duration::days(6),
+        SavingsProductFreq::Monthly => start
+            .checked_add_months(Months::new(1))
+            .map(|next| next - Duration::days(1))
+            .unwrap_or(start),
+    }
+}
+
+// Share of the expected contributions actually collected, as a percentage
+// to two decimal places
+pub fn collection_rate(actual: i64, expected: i64) -> Decimal {
+    if expected <= 0 {
+        return Decimal::ZERO;
+    }
+
+    (Decimal::from(actual) * Decimal::ONE_HUNDRED / Decimal::from(expected)).round_dp(2)
+}
+
+// Weighted score out of 100. Each component is (actual, target, weight) and
+// earns its full weight once actual reaches target; components without a
+// positive target and weight are left out
+pub fn weighted_score(components: &[(i64, i64, i64)]) -> Decimal {
+    let scored: Vec<&(i64, i64, i64)> = components
+        .iter()
+        .filter(|(_, target, weight)| *target > 0 && *weight > 0)
+        .collect();
+
+    let total_weight: i64 = scored.iter().map(|(_, _, weight)| weight).sum();
+
+    if total_weight <= 0 {
+        return Decimal::ZERO;
+    }
+
+    let earned: Decimal = scored
+        .iter()
+        .map(|(actual, target, weight)| {
+            let achieved =
+                (Decimal::from((*actual).max(0)) / Decimal::from(*target)).min(Decimal::ONE);
+
+            Decimal::from(*weight) * achieved
+        })
+        .sum();
+
+    (earned * Decimal::ONE_HUNDRED / Decimal::from(total_weight)).round_dp(2)
+}
+
+// Agent commission on one transaction. Percentage values are in basis
+// points (150 == 1.5%); the result is clamped to the rule's min and max
+pub fn agent_commission(
+    amount: i64,
+    commission_type: &AgentCommissionRuleCommType,
+    value: i64,
+    min: Option<i64>,
+    max: Option<i64>,
+) -> i64 {
+    let commission = match commission_type {
+        AgentCommissionRuleCommType::Flat => value,
+        AgentCommissionRuleCommType::Percentage => percent_of(amount, Decimal::new(value, 2)),
+    };
+
+    let commission = commission.max(min.unwrap_or(0));
+
+    match max {
+        Some(max) => commission.min(max),
+        None => commission,
+    }
+}
+
+pub fn split_evenly(amount: i64, count: i32) -> Vec<i64> {
+    let count = i64::from(count.max(1));
+    let part = amount / count;
+
+    (0..count)
+        .map(|index| {
+            if index == count - 1 {
+                amount - part * (count - 1)
+            } else {
+                part
+            }
+        })
+        .collect()
+}
+
+// Principal component of an equal (annuity) instalment for each period
+fn amortise(principal: i64, period_rate: Decimal, count: i32) -> Vec<i64> {
+    if period_rate.is_zero() {
+        return split_evenly(principal, count);
+    }
+
+    let mut growth = Decimal::ONE;
+
+    for _ in 0..count {
+        growth = growth
+            .checked_mul(Decimal::ONE + period_rate)
+            .unwrap_or(growth);
+    }
+
+    let payment = Decimal::from(principal) * period_rate * growth / (growth - Decimal::ONE);
+
+    let mut balance = principal;
+    let mut parts = Vec::with_capacity(count as usize);
+
+    for index in 0..count {
+        let interest = (Decimal::from(balance) * period_rate).round();
+
+        let part = if index == count - 1 {
+            balance
+        } else {
+            i64::try_from((payment - interest).round())
+                .unwrap_or(0)
+                .clamp(0, balance)
+        };
+
+        balance -= part;
+        parts.push(part);
+    }
+
+    parts
+}
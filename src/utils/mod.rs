@@ -1,6 +1,10 @@
+pub mod crypto;
 pub mod errors;
+pub mod finance;
 pub mod gen_snow_ids;
+pub mod geo;
 pub mod models;
+pub mod permissions;
 pub mod validators;
 pub mod password;
 pub mod tokens;
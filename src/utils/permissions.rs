@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use actix_web::{HttpMessage, HttpRequest, web};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use serde_json::Value;
+
+use crate::{
+    AppState,
+    utils::{errors::ApiError, tokens::Claims},
+};
+
+fn grants(permissions: Option<&Value>, permission: &str) -> bool {
+    match permissions {
+        Some(Value::Array(items)) => items
+            .iter()
+            .any(|item| item.as_str() == Some(permission) || item.as_str() == Some("*")),
+        Some(Value::Object(map)) => map
+            .get(permission)
+            .and_then(Value::as_bool)
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+// Like `grants`, without the `*` wildcard
+fn lists(permissions: Option<&Value>, permission: &str) -> bool {
+    match permissions {
+        Some(Value::Array(items)) => items.iter().any(|item| item.as_str() == Some(permission)),
+        Some(Value::Object(map)) => map
+            .get(permission)
+            .and_then(Value::as_bool)
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+pub fn holds_permission(
+    staff: &entity::staff::Model,
+    role: Option<&entity::staff_roles::Model>,
+    permission: &str,
+) -> bool {
+    if grants(staff.permissions.as_ref(), permission) {
+        return true;
+    }
+
+    role.is_some_and(|role| {
+        role.is_active.unwrap_or(true)
+            && (role.is_admin.unwrap_or(false) || grants(Some(&role.permissions), permission))
+    })
+}
+
+// Permission named explicitly on the staff member or their active role; `*`
+// and admin roles do not count, for permissions that mark a job function
+pub fn holds_explicit_permission(
+    staff: &entity::staff::Model,
+    role: Option<&entity::staff_roles::Model>,
+    permission: &str,
+) -> bool {
+    if lists(staff.permissions.as_ref(), permission) {
+        return true;
+    }
+
+    role.is_some_and(|role| {
+        role.is_active.unwrap_or(true) && lists(Some(&role.permissions), permission)
+    })
+}
+
+pub async fn current_staff(
+    req: &HttpRequest,
+    state: &web::Data<AppState>,
+) -> Result<entity::staff::Model, ApiError> {
+    let claims = req
+        .extensions()
+        .get::<Arc<Claims>>()
+        .cloned()
+        .ok_or(ApiError::Unauthorized)?;
+
+    let session = uuid::Uuid::parse_str(&claims.sub).map_err(|_| ApiError::Unauthorized)?;
+
+    entity::staff::Entity::find()
+        .filter(entity::staff::Column::Session.eq(session))
+        .one(state.pgdb.get_ref())
+        .await
+        .map_err(|_| ApiError::InternalServerError)?
+        .ok_or(ApiError::Unauthorized)
+}
+
+pub async fn require_permission(
+    req: &HttpRequest,
+    permission: &str,
+    state: &web::Data<AppState>,
+) -> Result<entity::staff::Model, ApiError> {
+    let staff = current_staff(req, state).await?;
+
+    if grants(staff.permissions.as_ref(), permission) {
+        return Ok(staff);
+    }
+
+    let role = match staff.role_id {
+        Some(role_id) => entity::staff_roles::Entity::find_by_id(role_id)
+            .one(state.pgdb.get_ref())
+            .await
+            .map_err(|_| ApiError::InternalServerError)?,
+        None => None,
+    };
+
+    if holds_permission(&staff, role.as_ref(), permission) {
+        Ok(staff)
+    } else {
+        Err(ApiError::Forbidden)
+    }
+}
@@ -77,3 +77,12 @@ pub fn validate_income(value: &Decimal) -> Result<(), ValidationError> {
 
     Ok(())
 }
+
+pub fn validate_percentage(value: &Decimal) -> Result<(), ValidationError> {
+    if *value < Decimal::ZERO || *value > Decimal::ONE_HUNDRED {
+        return Err(ValidationError::new("percentage_range")
+            .with_message("Rate cannot be < 0 and > 100".into()));
+    }
+
+    Ok(())
+}
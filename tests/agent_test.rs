@@ -0,0 +1,195 @@
+use cbs_jevek::app::agent_audits::{
+    models::{RecordAuditModel, ScheduleAuditModel},
+    services::{close_follow_up, record_audit, schedule_audit},
+};
+use cbs_jevek::app::agent_settlements::{
+    models::{RunSettlementModel, SettlementGlAccountsModel},
+    services::{run_settlement, set_gl_accounts},
+};
+use entity::sea_orm_active_enums::{
+    AgentCommissionsStatus, AgentStatus, AgentWalletStatus, AgentWalletType, RiskLevelEnum,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, prelude::Decimal,
+};
+
+mod common;
+use common::{
+    gl_balance, migrated_db, next_id, seed_account, seed_branch, seed_customer, seed_gl_account,
+    seed_institution, seed_staff,
+};
+
+// An active agent with a float wallet and a settlement account
+async fn seed_agent(db: &DatabaseConnection) -> entity::agents::Model {
+    let institution_id = seed_institution(db).await;
+    let branch_id = seed_branch(db, institution_id).await;
+    let customer_id = seed_customer(db, institution_id).await;
+    let account = seed_account(db, institution_id, customer_id, 0).await;
+
+    let agent_id = next_id();
+
+    let agent = entity::agents::ActiveModel {
+        id: Set(agent_id),
+        institution_id: Set(institution_id),
+        branch_id: Set(branch_id),
+        agent_number: Set(format!("AGT{agent_id}")),
+        settlement_account_id: Set(Some(account.id)),
+        status: Set(Some(AgentStatus::Active)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+
+    entity::agent_wallets::ActiveModel {
+        id: Set(next_id()),
+        institution_id: Set(institution_id),
+        agent_id: Set(agent.id),
+        wallet_type: Set(Some(AgentWalletType::Float)),
+        current_balance: Set(Some(1_000)),
+        available_balance: Set(Some(1_000)),
+        min_balance: Set(Some(0)),
+        status: Set(Some(AgentWalletStatus::Active)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+
+    agent
+}
+
+#[actix_web::test]
+async fn rerunning_a_settlement_cycle_pays_once() {
+    let Some(test_db) = migrated_db().await else {
+        return;
+    };
+    let (state, db) = (&test_db.state, test_db.db());
+
+    let agent = seed_agent(db).await;
+
+    let expense_gl = seed_gl_account(db, agent.institution_id).await;
+    let settlement_gl = seed_gl_account(db, agent.institution_id).await;
+
+    set_gl_accounts(
+        &agent.institution_id,
+        &SettlementGlAccountsModel {
+            commission_expense_gl_account_id: expense_gl,
+            settlement_gl_account_id: settlement_gl,
+        },
+        state,
+    )
+    .await
+    .unwrap();
+
+    let rule = entity::agent_commission_rules::ActiveModel {
+        id: Set(next_id()),
+        institution_id: Set(agent.institution_id),
+        rule_name: Set("Cash-in".to_string()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+
+    entity::agent_commissions::ActiveModel {
+        id: Set(next_id()),
+        institution_id: Set(agent.institution_id),
+        agent_id: Set(agent.id),
+        commission_rule_id: Set(rule.id),
+        transaction_amount: Set(10_000),
+        commission_rate: Set(Decimal::ONE),
+        commission_amount: Set(100),
+        status: Set(Some(AgentCommissionsStatus::Pending)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+
+    let run = RunSettlementModel {
+        institution_id: agent.institution_id,
+        settlement_date: chrono::Utc::now().date_naive(),
+        overrides: Vec::new(),
+        initiated_by: None,
+    };
+
+    let first = run_settlement(&run, state).await.unwrap();
+    let second = run_settlement(&run, state).await.unwrap();
+
+    assert_eq!(first.settlements.len(), 1);
+    assert_eq!(second.settlements.len(), 1);
+    assert_eq!(first.settlements[0].id, second.settlements[0].id);
+
+    let settlements = entity::agent_settlements::Entity::find()
+        .filter(entity::agent_settlements::Column::AgentId.eq(agent.id))
+        .count(db)
+        .await
+        .unwrap();
+    assert_eq!(settlements, 1);
+
+    let account = entity::accounts::Entity::find_by_id(agent.settlement_account_id.unwrap())
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.current_balance, Some(100));
+    assert_eq!(gl_balance(db, expense_gl).await, 100);
+    assert_eq!(gl_balance(db, settlement_gl).await, -100);
+}
+
+#[actix_web::test]
+async fn an_audit_suspends_the_agent_until_its_follow_up_is_closed() {
+    let Some(test_db) = migrated_db().await else {
+        return;
+    };
+    let (state, db) = (&test_db.state, test_db.db());
+
+    let agent = seed_agent(db).await;
+
+    let auditor_id = seed_staff(db, agent.institution_id, None).await;
+
+    let audit = schedule_audit(
+        &ScheduleAuditModel {
+            agent_id: agent.id,
+            audit_date: chrono::Utc::now().date_naive(),
+            auditor_id,
+        },
+        state,
+    )
+    .await
+    .unwrap();
+
+    // The float wallet holds 1_000, so this is well past the default variance
+    let recorded = RecordAuditModel {
+        actual_float_balance: 1_000_000,
+        findings: None,
+        compliance_score: None,
+        risk_level: RiskLevelEnum::Low,
+        actions_taken: None,
+        is_follow_up_required: false,
+    };
+
+    record_audit(&audit.id, &recorded, state).await.unwrap();
+    assert!(record_audit(&audit.id, &recorded, state).await.is_err());
+
+    let suspended = entity::agents::Entity::find_by_id(agent.id)
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(suspended.status, Some(AgentStatus::Suspended));
+
+    close_follow_up(&audit.id, "Float recounted", state)
+        .await
+        .unwrap();
+
+    let reinstated = entity::agents::Entity::find_by_id(agent.id)
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(reinstated.status, Some(AgentStatus::Active));
+    assert_eq!(reinstated.suspension_reason, None);
+}
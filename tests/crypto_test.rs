@@ -0,0 +1,13 @@
+use cbs_jevek::utils::crypto::{decrypt_with_key, encrypt_with_key};
+
+#[test]
+fn secrets_round_trip_and_reject_the_wrong_key() {
+    let key = [7u8; 32];
+
+    let sealed = encrypt_with_key(&key, "bureau-api-key").unwrap();
+    assert_ne!(sealed, "bureau-api-key");
+    assert_eq!(decrypt_with_key(&key, &sealed).unwrap(), "bureau-api-key");
+
+    assert!(decrypt_with_key(&[8u8; 32], &sealed).is_err());
+    assert!(decrypt_with_key(&key, "bureau-api-key").is_err());
+}
@@ -0,0 +1,223 @@
+use cbs_jevek::utils::finance::{
+    accrued_interest, agent_commission, collection_rate, cycle_end_date, fee_amount,
+    installment_count, next_accrual_date, percent_of, progress_percentage, repayment_schedule,
+    simple_interest, weighted_score,
+};
+use chrono::NaiveDate;
+use entity::sea_orm_active_enums::{
+    AgentCommissionRuleCommType, LoanProductCalcMethod, LoanProductFreq, LoanRepaymentFreq,
+    SavingsProductFreq,
+};
+use sea_orm::prelude::Decimal;
+
+fn start() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 1, 15).unwrap()
+}
+
+#[test]
+fn progress_percentage_is_capped_at_target() {
+    assert_eq!(progress_percentage(2_500, 10_000), 25);
+    assert_eq!(progress_percentage(9_999, 10_000), 99);
+    assert_eq!(progress_percentage(15_000, 10_000), 100);
+    assert_eq!(progress_percentage(500, 0), 0);
+}
+
+#[test]
+fn percent_of_rounds_to_minor_units() {
+    assert_eq!(percent_of(10_000, Decimal::new(55, 1)), 550);
+    assert_eq!(percent_of(333, Decimal::new(10, 0)), 33);
+    assert_eq!(percent_of(0, Decimal::new(10, 0)), 0);
+}
+
+#[test]
+fn fee_amount_adds_rate_and_flat_charges() {
+    assert_eq!(fee_amount(10_000, Some(Decimal::new(2, 0)), Some(150)), 350);
+    assert_eq!(fee_amount(10_000, None, Some(150)), 150);
+    assert_eq!(fee_amount(10_000, None, None), 0);
+}
+
+#[test]
+fn accrued_interest_is_pro_rata_within_the_period() {
+    let due = NaiveDate::from_ymd_opt(2026, 2, 14).unwrap();
+
+    assert_eq!(accrued_interest(3_000, start(), due, start()), 0);
+    assert_eq!(
+        accrued_interest(3_000, start(), due, start() + chrono::Duration::days(10)),
+        1_000
+    );
+    assert_eq!(accrued_interest(3_000, start(), due, due), 3_000);
+}
+
+#[test]
+fn simple_interest_uses_actual_over_365() {
+    assert_eq!(simple_interest(365_000, Decimal::new(10, 0), 1), 100);
+    assert_eq!(simple_interest(365_000, Decimal::new(10, 0), 30), 3_000);
+    assert_eq!(simple_interest(365_000, Decimal::new(10, 0), 0), 0);
+}
+
+#[test]
+fn next_accrual_date_follows_frequency_and_stops_at_maturity() {
+    let maturity = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+
+    assert_eq!(
+        next_accrual_date(&LoanProductFreq::Daily, start(), maturity),
+        NaiveDate::from_ymd_opt(2026, 1, 16).unwrap()
+    );
+    assert_eq!(
+        next_accrual_date(&LoanProductFreq::Monthly, start(), maturity),
+        NaiveDate::from_ymd_opt(2026, 2, 15).unwrap()
+    );
+    assert_eq!(
+        next_accrual_date(&LoanProductFreq::Bullet, start(), maturity),
+        maturity
+    );
+}
+
+#[test]
+fn cycle_end_date_covers_one_contribution_period() {
+    assert_eq!(cycle_end_date(&SavingsProductFreq::Daily, start()), start());
+    assert_eq!(
+        cycle_end_date(&SavingsProductFreq::Weekly, start()),
+        NaiveDate::from_ymd_opt(2026, 1, 21).unwrap()
+    );
+    assert_eq!(
+        cycle_end_date(&SavingsProductFreq::Monthly, start()),
+        NaiveDate::from_ymd_opt(2026, 2, 14).unwrap()
+    );
+}
+
+#[test]
+fn collection_rate_is_a_rounded_percentage() {
+    assert_eq!(collection_rate(2_000, 3_000), Decimal::new(6667, 2));
+    assert_eq!(collection_rate(3_000, 3_000), Decimal::ONE_HUNDRED);
+    assert_eq!(collection_rate(500, 0), Decimal::ZERO);
+}
+
+#[test]
+fn agent_commission_is_clamped_to_rule_bounds() {
+    let percentage = AgentCommissionRuleCommType::Percentage;
+
+    assert_eq!(agent_commission(10_000, &percentage, 150, None, None), 150);
+    assert_eq!(
+        agent_commission(1_000, &percentage, 150, Some(50), None),
+        50
+    );
+    assert_eq!(
+        agent_commission(1_000_000, &percentage, 150, None, Some(5_000)),
+        5_000
+    );
+    assert_eq!(
+        agent_commission(10_000, &AgentCommissionRuleCommType::Flat, 75, None, None),
+        75
+    );
+}
+
+#[test]
+fn weighted_score_caps_each_component_at_its_target() {
+    assert_eq!(
+        weighted_score(&[
+            (25, 50, 30),
+            (5_000_000, 5_000_000, 40),
+            (60, 30, 20),
+            (0, 50_000, 10),
+        ]),
+        Decimal::new(7500, 2)
+    );
+    assert_eq!(
+        weighted_score(&[(10, 0, 50), (5, 10, 50)]),
+        Decimal::new(5000, 2)
+    );
+    assert_eq!(weighted_score(&[]), Decimal::ZERO);
+}
+
+#[test]
+fn installment_count_rounds_up_partial_periods() {
+    assert_eq!(
+        installment_count(&LoanRepaymentFreq::Monthly, start(), 360),
+        12
+    );
+    assert_eq!(
+        installment_count(&LoanRepaymentFreq::Monthly, start(), 45),
+        2
+    );
+    assert_eq!(
+        installment_count(&LoanRepaymentFreq::Weekly, start(), 30),
+        5
+    );
+    assert_eq!(
+        installment_count(&LoanRepaymentFreq::Bullet, start(), 90),
+        1
+    );
+}
+
+#[test]
+fn monthly_installments_follow_calendar_months() {
+    assert_eq!(
+        installment_count(&LoanRepaymentFreq::Monthly, start(), 365),
+        12
+    );
+
+    let schedule = repayment_schedule(
+        120_000,
+        Decimal::new(12, 0),
+        &LoanProductCalcMethod::Flat,
+        &LoanRepaymentFreq::Monthly,
+        start(),
+        365,
+    );
+
+    assert_eq!(schedule.len(), 12);
+    assert_eq!(
+        schedule.last().map(|installment| installment.due_date),
+        NaiveDate::from_ymd_opt(2027, 1, 15)
+    );
+}
+
+#[test]
+fn flat_schedule_repays_principal_with_level_interest() {
+    let schedule = repayment_schedule(
+        100_000,
+        Decimal::new(12, 0),
+        &LoanProductCalcMethod::Flat,
+        &LoanRepaymentFreq::Monthly,
+        start(),
+        90,
+    );
+
+    assert_eq!(schedule.len(), 3);
+    assert_eq!(
+        schedule.iter().map(|i| i.principal_due).sum::<i64>(),
+        100_000
+    );
+    assert!(schedule.iter().all(|i| i.interest_due == 1_000));
+    assert_eq!(
+        schedule[0].due_date,
+        NaiveDate::from_ymd_opt(2026, 2, 15).unwrap()
+    );
+}
+
+#[test]
+fn reducing_balance_schedule_has_level_instalments() {
+    let schedule = repayment_schedule(
+        120_000,
+        Decimal::new(24, 0),
+        &LoanProductCalcMethod::ReducingBalance,
+        &LoanRepaymentFreq::Monthly,
+        start(),
+        360,
+    );
+
+    assert_eq!(schedule.len(), 12);
+    assert_eq!(
+        schedule.iter().map(|i| i.principal_due).sum::<i64>(),
+        120_000
+    );
+    assert!(schedule[0].interest_due > schedule[11].interest_due);
+
+    let first = schedule[0].total_due;
+    assert!(
+        schedule[..11]
+            .iter()
+            .all(|i| (i.total_due - first).abs() <= 1)
+    );
+}
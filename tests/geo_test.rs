@@ -0,0 +1,14 @@
+use cbs_jevek::utils::geo::distance_meters;
+
+#[test]
+fn distance_between_coordinates_is_in_metres() {
+    assert_eq!(distance_meters((5.6037, -0.1870), (5.6037, -0.1870)), 0.0);
+
+    // One degree of latitude is roughly 111.2km
+    let degree = distance_meters((0.0, 0.0), (1.0, 0.0));
+    assert!((degree - 111_195.0).abs() < 10.0);
+
+    // Accra to Kumasi is roughly 200km
+    let accra_kumasi = distance_meters((5.6037, -0.1870), (6.6885, -1.6244));
+    assert!((accra_kumasi - 200_000.0).abs() < 5_000.0);
+}
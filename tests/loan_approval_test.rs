@@ -0,0 +1,141 @@
+use cbs_jevek::app::loan_applications::{
+    models::ApproveApplicationModel, services::approve_application,
+};
+use cbs_jevek::app::loan_approvals::services::approval_authority;
+use entity::sea_orm_active_enums::LoanApplicationStatus;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+use serde_json::json;
+
+mod common;
+use common::{
+    migrated_db, next_id, seed_customer, seed_institution, seed_loan_product, seed_staff,
+};
+
+async fn seed_approver(
+    db: &DatabaseConnection,
+    institution_id: i64,
+    approval_limit: Option<i64>,
+    has_unlimited_loan_approval: bool,
+) -> i64 {
+    let role_id = next_id();
+
+    entity::staff_roles::ActiveModel {
+        id: Set(role_id),
+        institution_id: Set(institution_id),
+        role_name: Set(format!("Approver {role_id}")),
+        role_code: Set(format!("APPROVER_{role_id}")),
+        permissions: Set(json!([])),
+        is_active: Set(Some(true)),
+        loan_approval_limit: Set(approval_limit),
+        has_unlimited_loan_approval: Set(has_unlimited_loan_approval),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+
+    seed_staff(db, institution_id, Some(role_id)).await
+}
+
+#[actix_web::test]
+async fn approvers_are_held_to_their_role_limit() {
+    let Some(test_db) = migrated_db().await else {
+        return;
+    };
+    let db = test_db.db();
+
+    let institution_id = seed_institution(db).await;
+
+    let limited = seed_approver(db, institution_id, Some(10_000), false).await;
+    let without_limit = seed_approver(db, institution_id, None, false).await;
+    let unlimited = seed_approver(db, institution_id, None, true).await;
+
+    let authority = approval_authority(db, limited, 10_000).await.unwrap();
+    assert_eq!(authority.approval_limit, Some(10_000));
+
+    let over_limit = approval_authority(db, limited, 10_001).await.unwrap_err();
+    assert!(over_limit.to_string().contains("exceeds"));
+
+    assert!(approval_authority(db, without_limit, 1).await.is_err());
+
+    let authority = approval_authority(db, unlimited, 5_000_000).await.unwrap();
+    assert_eq!(authority.approval_limit, None);
+}
+
+#[actix_web::test]
+async fn concurrent_approvers_each_get_their_own_level() {
+    let Some(test_db) = migrated_db().await else {
+        return;
+    };
+    let (state, db) = (&test_db.state, test_db.db());
+
+    let institution_id = seed_institution(db).await;
+    let customer_id = seed_customer(db, institution_id).await;
+    let product = seed_loan_product(db, institution_id).await;
+
+    entity::loan_approval_levels::ActiveModel {
+        id: Set(next_id()),
+        institution_id: Set(institution_id),
+        min_principal: Set(0),
+        required_approvals: Set(2),
+        is_active: Set(Some(true)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+
+    let first_approver = seed_approver(db, institution_id, None, true).await;
+    let second_approver = seed_approver(db, institution_id, None, true).await;
+
+    let application = entity::loan_applications::ActiveModel {
+        id: Set(next_id()),
+        institution_id: Set(institution_id),
+        loan_product_id: Set(product.id),
+        customer_id: Set(customer_id),
+        status: Set(Some(LoanApplicationStatus::Pending)),
+        requested_principal: Set(10_000),
+        requested_tenure_days: Set(90),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+
+    let approval = |approved_by| ApproveApplicationModel {
+        approved_principal: None,
+        approved_tenure_days: None,
+        approved_interest_rate: None,
+        conditions: None,
+        approved_by,
+    };
+    let (first_approval, second_approval) = (approval(first_approver), approval(second_approver));
+
+    let (first, second) = futures::join!(
+        approve_application(&application.id, &first_approval, state),
+        approve_application(&application.id, &second_approval, state)
+    );
+    first.unwrap();
+    second.unwrap();
+
+    let levels: Vec<i32> = entity::loan_application_approvals::Entity::find()
+        .filter(entity::loan_application_approvals::Column::LoanApplicationId.eq(application.id))
+        .order_by_asc(entity::loan_application_approvals::Column::ApprovalLevel)
+        .all(db)
+        .await
+        .unwrap()
+        .iter()
+        .map(|approval| approval.approval_level)
+        .collect();
+    assert_eq!(levels, vec![1, 2]);
+
+    let application = entity::loan_applications::Entity::find_by_id(application.id)
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(application.status, Some(LoanApplicationStatus::Approved));
+}
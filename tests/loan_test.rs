@@ -0,0 +1,327 @@
+use cbs_jevek::app::loan_rescheduling::{
+    models::{ProposeReschedulingModel, ReviewReschedulingModel},
+    services::{approve, propose},
+};
+use cbs_jevek::app::loan_top_ups::{models::DisburseTopUpModel, services::disburse_top_up};
+use cbs_jevek::app::loan_write_offs::{
+    models::{RecordRecoveryModel, RequestWriteOffModel, ReviewWriteOffModel},
+    services::{approve_write_off, record_recovery, request_write_off},
+};
+use cbs_jevek::app::loans::{models::WaivePenaltyModel, services::waive_penalty};
+use entity::sea_orm_active_enums::{
+    LoanApplicationStatus, LoanClassification, LoanPenaltyStatus, LoanPenaltyType,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter,
+    prelude::Decimal,
+};
+
+mod common;
+use common::{
+    gl_balance, migrated_db, next_id, seed_account, seed_customer, seed_gl_account,
+    seed_institution, seed_loan, seed_loan_product, seed_staff,
+};
+
+#[actix_web::test]
+async fn concurrent_write_off_approvals_post_once_and_recoveries_stay_capped() {
+    let Some(test_db) = migrated_db().await else {
+        return;
+    };
+    let (state, db) = (&test_db.state, test_db.db());
+
+    let institution_id = seed_institution(db).await;
+    let customer_id = seed_customer(db, institution_id).await;
+    let account = seed_account(db, institution_id, customer_id, 0).await;
+    let maker_id = seed_staff(db, institution_id, None).await;
+    let checker_id = seed_staff(db, institution_id, None).await;
+
+    let loan_gl = seed_gl_account(db, institution_id).await;
+    let expense_gl = seed_gl_account(db, institution_id).await;
+    let reserve_gl = seed_gl_account(db, institution_id).await;
+    let recovery_gl = seed_gl_account(db, institution_id).await;
+    let cash_gl = seed_gl_account(db, institution_id).await;
+
+    let mut product: entity::loan_products::ActiveModel =
+        seed_loan_product(db, institution_id).await.into();
+    product.loan_gl_account_id = Set(Some(loan_gl));
+    product.recovery_gl_account_id = Set(Some(recovery_gl));
+    let product = product.update(db).await.unwrap();
+
+    entity::loan_classification_rules::ActiveModel {
+        id: Set(next_id()),
+        institution_id: Set(institution_id),
+        classification: Set(LoanClassification::Loss),
+        min_days_in_arrears: Set(360),
+        provision_rate: Set(Decimal::ONE_HUNDRED),
+        is_npa: Set(true),
+        provision_expense_gl_account_id: Set(Some(expense_gl)),
+        provision_reserve_gl_account_id: Set(Some(reserve_gl)),
+        is_active: Set(Some(true)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+
+    let mut loan: entity::loans::ActiveModel =
+        seed_loan(db, &product, customer_id, account.id, 10_000)
+            .await
+            .into();
+    loan.days_in_arrears = Set(Some(400));
+    loan.classification = Set(Some(LoanClassification::Loss));
+    let loan = loan.update(db).await.unwrap();
+
+    let request = request_write_off(
+        &RequestWriteOffModel {
+            loan_id: loan.id,
+            reason: "Customer unreachable".to_string(),
+            requested_by: maker_id,
+        },
+        state,
+    )
+    .await
+    .unwrap();
+
+    let review = ReviewWriteOffModel {
+        notes: None,
+        reviewed_by: checker_id,
+    };
+
+    let (first, second) = futures::join!(
+        approve_write_off(&request.id, &review, state),
+        approve_write_off(&request.id, &review, state)
+    );
+
+    assert!(first.is_ok() != second.is_ok());
+
+    let write_off = first.or(second).unwrap();
+
+    let write_offs = entity::loan_write_offs::Entity::find()
+        .filter(entity::loan_write_offs::Column::LoanId.eq(loan.id))
+        .count(db)
+        .await
+        .unwrap();
+    assert_eq!(write_offs, 1);
+    assert_eq!(gl_balance(db, loan_gl).await, -10_000);
+
+    let loan = entity::loans::Entity::find_by_id(loan.id)
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(loan.status, Some(LoanApplicationStatus::WrittenOff));
+
+    let recovery = RecordRecoveryModel {
+        amount: 6_000,
+        debit_gl_account_id: cash_gl,
+        reference_number: None,
+        notes: None,
+        recorded_by: checker_id,
+    };
+
+    let (first, second) = futures::join!(
+        record_recovery(&write_off.id, &recovery, state),
+        record_recovery(&write_off.id, &recovery, state)
+    );
+
+    assert!(first.is_ok() != second.is_ok());
+
+    let write_off = entity::loan_write_offs::Entity::find_by_id(write_off.id)
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(write_off.recovered_amount, Some(6_000));
+    assert_eq!(gl_balance(db, recovery_gl).await, -6_000);
+}
+
+#[actix_web::test]
+async fn concurrent_penalty_waivers_apply_once() {
+    let Some(test_db) = migrated_db().await else {
+        return;
+    };
+    let (state, db) = (&test_db.state, test_db.db());
+
+    let institution_id = seed_institution(db).await;
+    let customer_id = seed_customer(db, institution_id).await;
+    let account = seed_account(db, institution_id, customer_id, 0).await;
+    let staff_id = seed_staff(db, institution_id, None).await;
+
+    let loan_gl = seed_gl_account(db, institution_id).await;
+    let penalty_gl = seed_gl_account(db, institution_id).await;
+
+    let mut product: entity::loan_products::ActiveModel =
+        seed_loan_product(db, institution_id).await.into();
+    product.loan_gl_account_id = Set(Some(loan_gl));
+    product.penalty_gl_account_id = Set(Some(penalty_gl));
+    let product = product.update(db).await.unwrap();
+
+    let mut loan: entity::loans::ActiveModel =
+        seed_loan(db, &product, customer_id, account.id, 10_000)
+            .await
+            .into();
+    loan.outstanding_penalty = Set(Some(500));
+    let loan = loan.update(db).await.unwrap();
+
+    let penalty = entity::loan_penalties::ActiveModel {
+        id: Set(next_id()),
+        loan_id: Set(loan.id),
+        installment_number: Set(1),
+        penalty_type: Set(Some(LoanPenaltyType::LatePayment)),
+        penalty_amount: Set(Some(500)),
+        waived_amount: Set(Some(0)),
+        status: Set(Some(LoanPenaltyStatus::Unpaid)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+
+    let request = WaivePenaltyModel {
+        amount: None,
+        reason: "Goodwill".to_string(),
+        waived_by: staff_id,
+    };
+
+    let (first, second) = futures::join!(
+        waive_penalty(&penalty.id, &request, state),
+        waive_penalty(&penalty.id, &request, state)
+    );
+
+    assert!(first.is_ok() != second.is_ok());
+
+    let loan = entity::loans::Entity::find_by_id(loan.id)
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(loan.outstanding_penalty, Some(0));
+    assert_eq!(gl_balance(db, penalty_gl).await, 500);
+}
+
+#[actix_web::test]
+async fn concurrent_top_up_disbursements_refinance_once() {
+    let Some(test_db) = migrated_db().await else {
+        return;
+    };
+    let (state, db) = (&test_db.state, test_db.db());
+
+    let institution_id = seed_institution(db).await;
+    let customer_id = seed_customer(db, institution_id).await;
+    let account = seed_account(db, institution_id, customer_id, 0).await;
+    let staff_id = seed_staff(db, institution_id, None).await;
+
+    let loan_gl = seed_gl_account(db, institution_id).await;
+    let payout_gl = seed_gl_account(db, institution_id).await;
+
+    let mut product: entity::loan_products::ActiveModel =
+        seed_loan_product(db, institution_id).await.into();
+    product.loan_gl_account_id = Set(Some(loan_gl));
+    let product = product.update(db).await.unwrap();
+
+    let old_loan = seed_loan(db, &product, customer_id, account.id, 10_000).await;
+
+    let application = entity::loan_applications::ActiveModel {
+        id: Set(next_id()),
+        institution_id: Set(institution_id),
+        loan_product_id: Set(product.id),
+        customer_id: Set(customer_id),
+        status: Set(Some(LoanApplicationStatus::Approved)),
+        requested_principal: Set(15_000),
+        requested_tenure_days: Set(365),
+        approved_principal: Set(Some(15_000)),
+        approved_tenure_days: Set(Some(365)),
+        refinanced_loan_id: Set(Some(old_loan.id)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+
+    let request = DisburseTopUpModel {
+        payout_gl_account_id: payout_gl,
+        reference_number: None,
+        disbursed_by: staff_id,
+    };
+
+    let (first, second) = futures::join!(
+        disburse_top_up(&application.id, &request, state),
+        disburse_top_up(&application.id, &request, state)
+    );
+
+    assert!(first.is_ok() != second.is_ok());
+
+    let top_ups = entity::loans::Entity::find()
+        .filter(entity::loans::Column::RefinancedLoanId.eq(old_loan.id))
+        .count(db)
+        .await
+        .unwrap();
+    assert_eq!(top_ups, 1);
+    assert_eq!(gl_balance(db, payout_gl).await, -5_000);
+
+    let old_loan = entity::loans::Entity::find_by_id(old_loan.id)
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(old_loan.status, Some(LoanApplicationStatus::Refinanced));
+}
+
+#[actix_web::test]
+async fn concurrent_rescheduling_approvals_replace_the_schedule_once() {
+    let Some(test_db) = migrated_db().await else {
+        return;
+    };
+    let (state, db) = (&test_db.state, test_db.db());
+
+    let institution_id = seed_institution(db).await;
+    let customer_id = seed_customer(db, institution_id).await;
+    let account = seed_account(db, institution_id, customer_id, 0).await;
+    let maker_id = seed_staff(db, institution_id, None).await;
+    let checker_id = seed_staff(db, institution_id, None).await;
+
+    let product = seed_loan_product(db, institution_id).await;
+    let loan = seed_loan(db, &product, customer_id, account.id, 10_000).await;
+
+    let proposal = propose(
+        &ProposeReschedulingModel {
+            loan_id: loan.id,
+            new_tenure_days: Some(180),
+            new_installment_amount: None,
+            reason: "Reduced income".to_string(),
+            supporting_documents: None,
+            requested_by: maker_id,
+        },
+        state,
+    )
+    .await
+    .unwrap();
+
+    let review = ReviewReschedulingModel {
+        notes: None,
+        reviewed_by: checker_id,
+    };
+
+    let (first, second) = futures::join!(
+        approve(&proposal.rescheduling.id, &review, state),
+        approve(&proposal.rescheduling.id, &review, state)
+    );
+
+    assert!(first.is_ok() != second.is_ok());
+
+    let approved = first.or(second).unwrap();
+
+    let rows = entity::loan_repayment_schedules::Entity::find()
+        .filter(entity::loan_repayment_schedules::Column::LoanId.eq(loan.id))
+        .count(db)
+        .await
+        .unwrap();
+    assert_eq!(rows, approved.schedule.len() as u64);
+
+    let loan = entity::loans::Entity::find_by_id(loan.id)
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(loan.status, Some(LoanApplicationStatus::Rescheduled));
+}
@@ -0,0 +1,298 @@
+use cbs_jevek::app::contribution_cycles::services::run_cycles;
+use cbs_jevek::app::group_savings::{models::MemberExitModel, services::exit_member};
+use cbs_jevek::app::savings::{
+    models::{ContributeModel, WithdrawModel},
+    services::{contribute, withdraw},
+};
+use chrono::Duration;
+use entity::sea_orm_active_enums::{
+    ContributionCyclesStatus, GroupSavingMemberRole, SavingGoalsStatus, SavingsProductFreq,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, DatabaseConnection, EntityTrait, prelude::Decimal,
+};
+
+mod common;
+use common::{
+    gl_balance, migrated_db, next_id, seed_account, seed_customer, seed_gl_account,
+    seed_institution, seed_staff,
+};
+
+async fn seed_product(
+    db: &DatabaseConnection,
+    institution_id: i64,
+) -> entity::savings_products::Model {
+    let id = next_id();
+
+    entity::savings_products::ActiveModel {
+        id: Set(id),
+        institution_id: Set(institution_id),
+        name: Set(Some(format!("Savings {id}"))),
+        code: Set(Some(format!("SAV{id}"))),
+        lock_period_days: Set(Some(0)),
+        is_active: Set(Some(true)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap()
+}
+
+async fn seed_goal(
+    db: &DatabaseConnection,
+    product: &entity::savings_products::Model,
+    customer_id: i64,
+    account_id: i64,
+    current_amount: i64,
+) -> entity::saving_goals::Model {
+    entity::saving_goals::ActiveModel {
+        id: Set(next_id()),
+        institution_id: Set(product.institution_id),
+        customer_id: Set(customer_id),
+        savings_product_id: Set(product.id),
+        account_id: Set(account_id),
+        current_amount: Set(Some(current_amount)),
+        start_date: Set(chrono::Utc::now().date_naive() - Duration::days(30)),
+        status: Set(Some(SavingGoalsStatus::Active)),
+        is_group_savings: Set(Some(false)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap()
+}
+
+#[actix_web::test]
+async fn concurrent_full_withdrawals_pay_out_once() {
+    let Some(test_db) = migrated_db().await else {
+        return;
+    };
+    let (state, db) = (&test_db.state, test_db.db());
+
+    let institution_id = seed_institution(db).await;
+    let customer_id = seed_customer(db, institution_id).await;
+    let account = seed_account(db, institution_id, customer_id, 0).await;
+    let staff_id = seed_staff(db, institution_id, None).await;
+    let product = seed_product(db, institution_id).await;
+    let goal = seed_goal(db, &product, customer_id, account.id, 1_000).await;
+
+    let request = WithdrawModel {
+        amount: 1_000,
+        withdrawn_by: staff_id,
+    };
+
+    let (first, second) = futures::join!(
+        withdraw(&goal.id, &request, state),
+        withdraw(&goal.id, &request, state)
+    );
+
+    assert!(first.is_ok() != second.is_ok());
+
+    let account = entity::accounts::Entity::find_by_id(account.id)
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.current_balance, Some(1_000));
+
+    let goal = entity::saving_goals::Entity::find_by_id(goal.id)
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(goal.current_amount, Some(0));
+}
+
+#[actix_web::test]
+async fn early_withdrawal_penalties_come_out_of_posted_savings() {
+    let Some(test_db) = migrated_db().await else {
+        return;
+    };
+    let (state, db) = (&test_db.state, test_db.db());
+
+    let institution_id = seed_institution(db).await;
+    let customer_id = seed_customer(db, institution_id).await;
+    let account = seed_account(db, institution_id, customer_id, 1_000).await;
+    let staff_id = seed_staff(db, institution_id, None).await;
+
+    let deposit_gl = seed_gl_account(db, institution_id).await;
+    let savings_gl = seed_gl_account(db, institution_id).await;
+    let income_gl = seed_gl_account(db, institution_id).await;
+
+    let mut product: entity::savings_products::ActiveModel =
+        seed_product(db, institution_id).await.into();
+    product.lock_period_days = Set(Some(365));
+    product.is_early_withdrawal_allowed = Set(Some(true));
+    product.early_withdrawal_penalty_rate = Set(Some(Decimal::TEN));
+    product.gl_account_id = Set(Some(savings_gl));
+    product.deposit_gl_account_id = Set(Some(deposit_gl));
+    product.penalty_income_gl_account_id = Set(Some(income_gl));
+    let product = product.update(db).await.unwrap();
+
+    let goal = seed_goal(db, &product, customer_id, account.id, 0).await;
+
+    contribute(&goal.id, &ContributeModel { amount: 1_000 }, state)
+        .await
+        .unwrap();
+    assert_eq!(gl_balance(db, savings_gl).await, -1_000);
+
+    let withdrawal = withdraw(
+        &goal.id,
+        &WithdrawModel {
+            amount: 1_000,
+            withdrawn_by: staff_id,
+        },
+        state,
+    )
+    .await
+    .unwrap();
+    assert_eq!(withdrawal.penalty_amount, 100);
+
+    assert_eq!(gl_balance(db, savings_gl).await, 0);
+    assert_eq!(gl_balance(db, deposit_gl).await, 100);
+    assert_eq!(gl_balance(db, income_gl).await, -100);
+}
+
+async fn seed_member(
+    db: &DatabaseConnection,
+    group_goal_id: i64,
+    customer_id: i64,
+    member_role: GroupSavingMemberRole,
+    actual_contributed: i64,
+) -> entity::group_saving_members::Model {
+    entity::group_saving_members::ActiveModel {
+        id: Set(next_id()),
+        group_goal_id: Set(group_goal_id),
+        customer_id: Set(customer_id),
+        member_role: Set(Some(member_role)),
+        joined_date: Set(Some(chrono::Utc::now().date_naive() - Duration::days(30))),
+        committed_amount: Set(Some(100)),
+        actual_contributed: Set(Some(actual_contributed)),
+        is_active: Set(Some(true)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap()
+}
+
+#[actix_web::test]
+async fn concurrent_exits_pay_a_member_out_once() {
+    let Some(test_db) = migrated_db().await else {
+        return;
+    };
+    let (state, db) = (&test_db.state, test_db.db());
+
+    let institution_id = seed_institution(db).await;
+    let owner_id = seed_customer(db, institution_id).await;
+    let owner_account = seed_account(db, institution_id, owner_id, 0).await;
+    let customer_id = seed_customer(db, institution_id).await;
+    let account = seed_account(db, institution_id, customer_id, 0).await;
+    let staff_id = seed_staff(db, institution_id, None).await;
+    let product = seed_product(db, institution_id).await;
+
+    let mut group: entity::saving_goals::ActiveModel =
+        seed_goal(db, &product, owner_id, owner_account.id, 1_000)
+            .await
+            .into();
+    group.is_group_savings = Set(Some(true));
+    group.group_owner_id = Set(Some(owner_id));
+    let group = group.update(db).await.unwrap();
+
+    seed_member(db, group.id, owner_id, GroupSavingMemberRole::Admin, 500).await;
+    let member = seed_member(
+        db,
+        group.id,
+        customer_id,
+        GroupSavingMemberRole::Member,
+        500,
+    )
+    .await;
+
+    let request = MemberExitModel {
+        requested_by: member.id,
+        account_id: account.id,
+        reason: "Relocating".to_string(),
+        exited_by: staff_id,
+    };
+
+    let (first, second) = futures::join!(
+        exit_member(&member.id, &request, state),
+        exit_member(&member.id, &request, state)
+    );
+
+    assert!(first.is_ok() != second.is_ok());
+
+    let account = entity::accounts::Entity::find_by_id(account.id)
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.current_balance, Some(500));
+
+    let group = entity::saving_goals::Entity::find_by_id(group.id)
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(group.current_amount, Some(500));
+}
+
+#[actix_web::test]
+async fn concurrent_cycle_runs_charge_a_missed_contribution_once() {
+    let Some(test_db) = migrated_db().await else {
+        return;
+    };
+    let (state, db) = (&test_db.state, test_db.db());
+
+    let institution_id = seed_institution(db).await;
+    let customer_id = seed_customer(db, institution_id).await;
+    let account = seed_account(db, institution_id, customer_id, 0).await;
+
+    let deposit_gl = seed_gl_account(db, institution_id).await;
+    let savings_gl = seed_gl_account(db, institution_id).await;
+    let income_gl = seed_gl_account(db, institution_id).await;
+
+    let mut product: entity::savings_products::ActiveModel =
+        seed_product(db, institution_id).await.into();
+    product.contribution_freq = Set(Some(SavingsProductFreq::Weekly));
+    product.missed_contribution_penalty = Set(Some(50));
+    product.gl_account_id = Set(Some(savings_gl));
+    product.deposit_gl_account_id = Set(Some(deposit_gl));
+    product.penalty_income_gl_account_id = Set(Some(income_gl));
+    let product = product.update(db).await.unwrap();
+
+    let mut goal: entity::saving_goals::ActiveModel =
+        seed_goal(db, &product, customer_id, account.id, 1_000)
+            .await
+            .into();
+    goal.contribution_amount = Set(Some(100));
+    let goal = goal.update(db).await.unwrap();
+
+    let today = chrono::Utc::now().date_naive();
+
+    entity::contribution_cycles::ActiveModel {
+        id: Set(next_id()),
+        savings_product_id: Set(product.id),
+        institution_id: Set(institution_id),
+        start_date: Set(Some(today - Duration::days(8))),
+        end_date: Set(Some(today - Duration::days(1))),
+        status: Set(Some(ContributionCyclesStatus::Active)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .unwrap();
+
+    let (first, second) = futures::join!(run_cycles(state), run_cycles(state));
+    first.unwrap();
+    second.unwrap();
+
+    let goal = entity::saving_goals::Entity::find_by_id(goal.id)
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(goal.current_amount, Some(950));
+    assert_eq!(gl_balance(db, income_gl).await, -50);
+}